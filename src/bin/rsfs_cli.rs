@@ -4,5 +4,8 @@ use rs_file_sorter::cli::handle;
 
 fn main() {
     let input = env::args().skip(1).collect::<Vec<String>>().join(" ");
-    handle(input, None);
+    if let Err(err) = handle(input, None) {
+        eprintln!("[ERROR] {}", err);
+        std::process::exit(err.exit_code());
+    }
 }
@@ -1,10 +1,13 @@
-use crate::utils::logger::Logger;
+use crate::{cli::error::Error, utils::logger::Logger};
 
 use super::{
     compound_structs::{self, ArgBuilder, ParamBuilder},
     parser::{parse_cli, ParsedCommand},
 };
 
+/// Callback running a command once its arguments and parameters are validated.
+pub type CommandHandler = fn(&ParsedCommand, &Logger) -> Result<(), Error>;
+
 #[derive(Debug, Clone)]
 pub struct CliHandlerCommand {
     pub logger: Logger,
@@ -12,7 +15,7 @@ pub struct CliHandlerCommand {
     pub command_description: String,
     pub args: Vec<ArgBuilder>,
     pub params: Vec<ParamBuilder>,
-    pub handler: fn(&ParsedCommand, &Logger) -> (),
+    pub handler: CommandHandler,
 }
 
 impl CliHandlerCommand {
@@ -26,7 +29,11 @@ impl CliHandlerCommand {
         help_output.push_str("\n");
         help_output.push_str("PARAMETERS\n");
         for param in &self.params {
-            help_output.push_str(&format!("\t{}: {}\n", param.name, param.description));
+            let optional = if param.optional { " (optional)" } else { "" };
+            help_output.push_str(&format!(
+                "\t{}{}: {}\n",
+                param.name, optional, param.description
+            ));
         }
         help_output.push_str("\n");
         help_output.push_str("ARGUMENTS\n");
@@ -50,72 +57,55 @@ impl CliHandlerCommand {
 
 pub struct CliHandler {
     pub command_handlers: Vec<CliHandlerCommand>,
-    pub logger: Logger,
 }
 
 impl CliHandler {
-    pub fn handle(&self, input: String) {
+    pub fn handle(&self, input: String) -> Result<(), Error> {
         let arg_prefix = String::from("--");
         let command = CliHandler::extract_command_from_input(input);
         let command_name = match command.first() {
-            Some(value) => value,
-            None => {
-                self.logger.error("Please provide a valid command.");
-                return;
-            }
+            Some(value) if !value.is_empty() => value,
+            _ => return Err(Error::Usage("please provide a valid command.".to_string())),
         };
 
         // If command is help, we want to provide the associated help message.
         if command_name.to_string() == String::from("help") {
-            println!("{}", self.handle_help(&command));
-            return;
-        }
-
-        if command_name.is_empty() {
-            self.logger.error("please provide a valid command.");
+            println!("{}", self.handle_help(&command)?);
+            return Ok(());
         }
 
-        let command_handler = match self.command_handler_from(command_name) {
-            Some(value) => value,
-            None => {
-                self.logger
-                    .error(&format!("'{}' isn't a valid command. Please type 'help' to get the list of valid commands.", command_name));
-                return;
-            }
-        };
+        let command_handler =
+            self.command_handler_from(command_name)
+                .ok_or(Error::Usage(format!(
+            "'{}' isn't a valid command. Please type 'help' to get the list of valid commands.",
+            command_name
+        )))?;
 
         match parse_cli(command, &command_handler, arg_prefix) {
             Ok(parsed_command) => {
-                if !self.validate(command_handler.clone(), parsed_command.clone()) {
-                    return;
-                }
+                self.validate(command_handler.clone(), parsed_command.clone())?;
 
                 let command_handler_fn = command_handler.handler;
                 command_handler_fn(&parsed_command.clone(), &command_handler.logger)
             }
             Err(parser_error) => match parser_error {
-                super::parser::Error::UnkownArgument(parsed_args) => {
-                    command_handler.logger.error(&format!(
-                        "unknown argument: got '{}' when expecting {}. See 'help' to get more informations.",
-                        parsed_args.arg_name, command_handler.args.iter().map(|arg_spec| arg_spec.name.clone()).collect::<Vec<String>>().join(", ")
-                    ));
-                }
+                super::parser::Error::UnkownArgument(parsed_args) => Err(Error::Usage(format!(
+                    "unknown argument: got '{}' when expecting {}. See 'help' to get more informations.",
+                    parsed_args.arg_name, command_handler.args.iter().map(|arg_spec| arg_spec.name.clone()).collect::<Vec<String>>().join(", ")
+                ))),
             },
         }
     }
 
-    fn handle_help(&self, command: &Vec<String>) -> String {
+    fn handle_help(&self, command: &Vec<String>) -> Result<String, Error> {
         match command.get(1) {
-            None => self.help(),
+            None => Ok(self.help()),
             Some(specific_command_name) => match self.command_handler_from(specific_command_name) {
-                Some(specific_command_handler) => specific_command_handler.help(),
-                None => {
-                    self.logger.error(
-                        "command invalid. Please type 'help' to get the list of valid commands.",
-                    );
-
-                    return String::from("unknown command");
-                }
+                Some(specific_command_handler) => Ok(specific_command_handler.help()),
+                None => Err(Error::Usage(
+                    "command invalid. Please type 'help' to get the list of valid commands."
+                        .to_string(),
+                )),
             },
         }
     }
@@ -154,35 +144,36 @@ impl CliHandler {
         &self,
         command_specification: CliHandlerCommand,
         parsed_command: ParsedCommand,
-    ) -> bool {
-        if let Some(value) = self.validate_args(&command_specification, &parsed_command) {
-            return value;
-        }
-
+    ) -> Result<(), Error> {
+        self.validate_args(&command_specification, &parsed_command)?;
+
+        let required_params_len = command_specification
+            .params
+            .iter()
+            .filter(|param| !param.optional)
+            .count();
         if command_specification.params.len() < parsed_command.params.len() {
-            command_specification.logger.error(&format!(
+            return Err(Error::Usage(format!(
                 "too much parameters: expected {} parameters but received {}.",
                 command_specification.params.len(),
                 parsed_command.params.len()
-            ));
-            return false;
-        } else if command_specification.params.len() > parsed_command.params.len() {
-            command_specification.logger.error(&format!(
+            )));
+        } else if required_params_len > parsed_command.params.len() {
+            return Err(Error::Usage(format!(
                 "not enough parameters: expected {} parameters but received {}.",
-                command_specification.params.len(),
+                required_params_len,
                 parsed_command.params.len()
-            ));
-            return false;
+            )));
         }
 
-        true
+        Ok(())
     }
 
     fn validate_args(
         &self,
         command_specification: &CliHandlerCommand,
         parsed_command: &ParsedCommand,
-    ) -> Option<bool> {
+    ) -> Result<(), Error> {
         let arg_specification_names = command_specification
             .args
             .clone()
@@ -193,11 +184,10 @@ impl CliHandler {
         for arg in command_specification.args.clone().into_iter() {
             match arg.validate(parsed_command.clone()) {
                 Err(compound_structs::Error::UnknownArgument) => {
-                    command_specification.logger.error(&format!(
+                    return Err(Error::Usage(format!(
                         "unknown argument: got '{}' when expecting {}. See 'help' to get more informations.",
                         arg.name, arg_specification_names.join(", ")
-                    ));
-                    return Some(false);
+                    )));
                 }
                 Err(compound_structs::Error::UnexpectedValue(received_value)) => {
                     let possible_values = arg.clone().expected_value_type;
@@ -207,11 +197,10 @@ impl CliHandler {
                         .collect::<Vec<String>>()
                         .join(", ");
                     let received_value_string = Into::<String>::into(received_value);
-                    command_specification.logger.error(&format!(
+                    return Err(Error::Usage(format!(
                         "unexpected argument value for {}: expected {} but received {}.",
                         arg.name, possible_values_string, received_value_string
-                    ));
-                    return Some(false);
+                    )));
                 }
                 _ => (),
             }
@@ -224,14 +213,14 @@ impl CliHandler {
             .map(|arg| arg.arg_name)
         {
             if !arg_specification_names.contains(&arg_name) {
-                command_specification.logger.error(&format!(
-                "unknown argument: got '{}' when expecting {}. See 'help' to get more informations.",
-                arg_name, arg_specification_names.clone().join(", ")
-            ));
-                return Some(false);
+                return Err(Error::Usage(format!(
+                    "unknown argument: got '{}' when expecting {}. See 'help' to get more informations.",
+                    arg_name, arg_specification_names.clone().join(", ")
+                )));
             }
         }
-        None
+
+        Ok(())
     }
 }
 
@@ -260,17 +249,22 @@ mod cli_handler_help_tests {
                 String::from("desc for arg-1"),
                 vec![ArgValueTypes::Single],
             )
-            .parameter(String::from("params-1"), String::from("desc for params-1"))
-            .handler(|_, _| ())
+            .parameter(
+                String::from("params-1"),
+                String::from("desc for params-1"),
+            )
+            .handler(|_, _| Ok(()))
             .command(
                 String::from("command-2"),
                 String::from("command-2's description"),
                 Logger::new("my-command", true),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
-        let help_output = cli_handler.handle_help(&vec![String::from("help")]);
+        let help_output = cli_handler
+            .handle_help(&vec![String::from("help")])
+            .unwrap();
         let expected_output = String::from("COMMANDS\n\tmy-command: my-command's description (enter 'help my-command' for more informations)\n\tcommand-2: command-2's description (enter 'help command-2' for more informations)\n");
 
         assert_eq!(help_output, expected_output);
@@ -294,25 +288,28 @@ mod cli_handler_help_tests {
                 String::from("desc for arg-2."),
                 vec![ArgValueTypes::Single, ArgValueTypes::Multiple],
             )
-            .parameter(String::from("params-1"), String::from("desc for params-1"))
-            .handler(|_, _| ())
+            .parameter(
+                String::from("params-1"),
+                String::from("desc for params-1"),
+            )
+            .handler(|_, _| Ok(()))
             .command(
                 String::from("command-2"),
                 String::from("command-2's description"),
                 Logger::new("my-command", true),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
-        let help_output =
-            cli_handler.handle_help(&vec![String::from("help"), String::from("my-command")]);
+        let help_output = cli_handler
+            .handle_help(&vec![String::from("help"), String::from("my-command")])
+            .unwrap();
         let expected_output = String::from("COMMAND\n\tmy-command: my-command's description (enter 'help my-command' for more informations)\n\nPARAMETERS\n\tparams-1: desc for params-1\n\nARGUMENTS\n\t--arg-1: desc for arg-1. Accepted values: NoValue\n\t--arg-2: desc for arg-2. Accepted values: Single, Multiple\n");
 
         assert_eq!(help_output, expected_output);
     }
 
     #[test]
-    #[should_panic = "[ERROR] [TEST_COMMAND] command invalid. Please type 'help' to get the list of valid commands."]
     fn test_help_for_unknown_command() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("TEST_COMMAND", true))
             .command(
@@ -330,17 +327,24 @@ mod cli_handler_help_tests {
                 String::from("desc for arg-1"),
                 vec![ArgValueTypes::Single, ArgValueTypes::Multiple],
             )
-            .parameter(String::from("params-1"), String::from("desc for params-1"))
-            .handler(|_, _| ())
+            .parameter(
+                String::from("params-1"),
+                String::from("desc for params-1"),
+            )
+            .handler(|_, _| Ok(()))
             .command(
                 String::from("command-2"),
                 String::from("command-2's description"),
                 Logger::new("my-command", true),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
-        let _ =
+        let result =
             cli_handler.handle_help(&vec![String::from("help"), String::from("unknown-command")]);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "command invalid. Please type 'help' to get the list of valid commands."
+        );
     }
 }
@@ -1,9 +1,8 @@
 use crate::utils::logger::Logger;
 
 use super::{
-    cli_handler::{CliHandler, CliHandlerCommand},
+    cli_handler::{CliHandler, CliHandlerCommand, CommandHandler},
     compound_structs::{ArgBuilder, CommandBuilder, ParamBuilder},
-    parser::ParsedCommand,
 };
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -28,7 +27,7 @@ pub struct CliHandlerBuilder {
     current_command: Option<CommandBuilder>,
     current_args: Option<Vec<ArgBuilder>>,
     current_params: Option<Vec<ParamBuilder>>,
-    current_handler: Option<fn(&ParsedCommand, &Logger) -> ()>,
+    current_handler: Option<CommandHandler>,
     commands: Vec<CliHandlerCommand>,
     logger: Logger,
 }
@@ -164,10 +163,21 @@ impl CliHandlerBuilder {
         self
     }
 
-    pub fn parameter(mut self, name: String, description: String) -> Self {
+    #[allow(dead_code)]
+    pub fn parameter(self, name: String, description: String) -> Self {
+        self.push_parameter(name, description, false)
+    }
+
+    /// Declares a parameter that the command can run without, e.g. when another argument provides it.
+    pub fn optional_parameter(self, name: String, description: String) -> Self {
+        self.push_parameter(name, description, true)
+    }
+
+    fn push_parameter(mut self, name: String, description: String, optional: bool) -> Self {
         let param_builder = ParamBuilder {
             name: name.clone(),
             description,
+            optional,
         };
 
         if self.current_command.is_none() {
@@ -203,7 +213,7 @@ impl CliHandlerBuilder {
         self
     }
 
-    pub fn handler(mut self, handler: fn(&ParsedCommand, &Logger) -> ()) -> Self {
+    pub fn handler(mut self, handler: CommandHandler) -> Self {
         match self.current_handler {
             Some(_) => self
                 .logger
@@ -222,7 +232,6 @@ impl CliHandlerBuilder {
 
         CliHandler {
             command_handlers: self.commands.clone(),
-            logger: self.logger.clone(),
         }
     }
 
@@ -73,4 +73,6 @@ impl ArgBuilder {
 pub struct ParamBuilder {
     pub name: String,
     pub description: String,
+    /// Optional parameters can be omitted, as long as they come after every required one.
+    pub optional: bool,
 }
@@ -331,16 +331,18 @@ mod tests {
                 ParamBuilder {
                     name: String::from("from"),
                     description: String::from("the directory from which you need to extract the files."),
+                    optional: false,
                 },
                 ParamBuilder {
                     name: String::from("to"),
                     description: String::from("the directory to which you need to move the files."),
+                    optional: false,
                 },
             ],
             logger: Logger::new("Test Logger", false),
             command_name: String::from("sort"),
             command_description: String::from("Sort files."),
-            handler: |_, _| {},
+            handler: |_, _| Ok(()),
         };
         let parsed_command = parse_cli(command, &command_handler, String::from("--")).unwrap();
         assert_eq!(parsed_command.command_name, "sort");
@@ -374,7 +376,7 @@ mod tests {
                 logger: Logger::new("Test Logger", false),
                 command_name: String::from("sort"),
                 command_description: String::from("Sort files."),
-                handler: |_, _| {},
+                handler: |_, _| Ok(()),
             };
             let args: Vec<ParsedArgs> = vec![
                 ParsedArgs {
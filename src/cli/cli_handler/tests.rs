@@ -25,7 +25,7 @@ mod parser_tests {
                 })
                 .collect(),
             params: vec![],
-            handler: |_, _| (),
+            handler: |_, _| Ok(()),
         }
     }
 
@@ -342,8 +342,8 @@ mod cli_handler_builder_tests {
                 String::from("my description"),
                 Logger::new("my-command", true),
             )
-            .handler(|_, _| ())
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
+            .handler(|_, _| Ok(()))
             .build();
 
         // Should have fail before.
@@ -369,7 +369,7 @@ mod cli_handler_builder_tests {
                 String::from("my description"),
                 Logger::new("my-command", true),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert_eq!(cli_handler.command_handlers.len(), 1);
@@ -399,7 +399,7 @@ mod cli_handler_builder_tests {
                 String::from("description of arg-1"),
                 vec![ArgValueTypes::NoValue],
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert_eq!(cli_handler.command_handlers.len(), 1);
@@ -426,7 +426,7 @@ mod cli_handler_builder_tests {
                 String::from("description of arg-1"),
                 vec![ArgValueTypes::NoValue, ArgValueTypes::Single],
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert_eq!(cli_handler.command_handlers.len(), 1);
@@ -459,7 +459,7 @@ mod cli_handler_builder_tests {
                 String::from("description of arg-2"),
                 vec![ArgValueTypes::Single, ArgValueTypes::Multiple],
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert_eq!(cli_handler.command_handlers.len(), 1);
@@ -501,7 +501,7 @@ mod cli_handler_builder_tests {
                 String::from("This is a description of arg-1"),
                 vec![ArgValueTypes::NoValue],
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert!(false);
@@ -522,7 +522,7 @@ mod cli_handler_builder_tests {
                 String::from("This is a description of arg-1"),
                 vec![],
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert!(false);
@@ -556,7 +556,7 @@ mod cli_handler_builder_tests {
                 String::from("param-1"),
                 String::from("description of param-1"),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert_eq!(cli_handler.command_handlers.len(), 1);
@@ -587,7 +587,7 @@ mod cli_handler_builder_tests {
                 String::from("param-2"),
                 String::from("description of param-2"),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert_eq!(cli_handler.command_handlers.len(), 1);
@@ -624,7 +624,7 @@ mod cli_handler_builder_tests {
                 String::from("param-1"),
                 String::from("This is a description of param-1"),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert!(false);
@@ -653,13 +653,13 @@ mod cli_handler_builder_tests {
                 String::from("my description"),
                 Logger::new("my-command", true),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .command(
                 String::from("my-command-2"),
                 String::from("my description for my-command-2"),
                 Logger::new("my-command-2", true),
             )
-            .handler(|_, _| ())
+            .handler(|_, _| Ok(()))
             .build();
 
         assert_eq!(cli_handler.command_handlers.len(), 2);
@@ -677,7 +677,6 @@ mod cli_handler_tests {
     };
 
     #[test]
-    #[should_panic = "[ERROR] [TEST_COMMAND] please provide a valid command."]
     fn test_no_command_entered() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("TEST_COMMAND", true))
             .command(
@@ -685,13 +684,16 @@ mod cli_handler_tests {
                 String::from("description"),
                 Logger::new("command-1 log", false),
             )
-            .handler(|_, _| {})
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from(""));
+        let result = cli_handler.handle(String::from(""));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "please provide a valid command."
+        );
     }
 
     #[test]
-    #[should_panic = "[ERROR] [TEST_COMMAND] 'unknown-command' isn't a valid command. Please type 'help' to get the list of valid commands."]
     fn test_command_not_found() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("TEST_COMMAND", true))
             .command(
@@ -699,13 +701,13 @@ mod cli_handler_tests {
                 String::from("description"),
                 Logger::new("command-1 log", false),
             )
-            .handler(|_, _| {})
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("unknown-command"));
+        let result = cli_handler.handle(String::from("unknown-command"));
+        assert_eq!(result.unwrap_err().to_string(), "'unknown-command' isn't a valid command. Please type 'help' to get the list of valid commands.");
     }
 
     #[test]
-    #[should_panic = "[ERROR] [my-command] unknown argument: got 'arg-unknown' when expecting arg-1, arg-2. See 'help' to get more informations."]
     fn test_received_unknown_argument() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
             .command(
@@ -723,13 +725,13 @@ mod cli_handler_tests {
                 String::from("desc for arg-2"),
                 vec![ArgValueTypes::Single, ArgValueTypes::Multiple],
             )
-            .handler(|_, _| {})
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("my-command --arg-unknown"));
+        let result = cli_handler.handle(String::from("my-command --arg-unknown"));
+        assert_eq!(result.unwrap_err().to_string(), "unknown argument: got 'arg-unknown' when expecting arg-1, arg-2. See 'help' to get more informations.");
     }
 
     #[test]
-    #[should_panic = "[ERROR] [my-command] unexpected argument value for arg-1: expected Single, Multiple but received NoValue."]
     fn test_received_unexpected_argument_value_got_no_value() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
             .command(
@@ -742,13 +744,16 @@ mod cli_handler_tests {
                 String::from("desc for arg-1"),
                 vec![ArgValueTypes::Single, ArgValueTypes::Multiple],
             )
-            .handler(|_, _| {})
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("my-command --arg-1"));
+        let result = cli_handler.handle(String::from("my-command --arg-1"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unexpected argument value for arg-1: expected Single, Multiple but received NoValue."
+        );
     }
 
     #[test]
-    #[should_panic = "[ERROR] [my-command] unexpected argument value for arg-1: expected NoValue, Multiple but received Single."]
     fn test_received_unexpected_argument_value_got_single_value() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
             .command(
@@ -761,13 +766,16 @@ mod cli_handler_tests {
                 String::from("desc for arg-1"),
                 vec![ArgValueTypes::NoValue, ArgValueTypes::Multiple],
             )
-            .handler(|_, _| {})
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("my-command --arg-1 test"));
+        let result = cli_handler.handle(String::from("my-command --arg-1 test"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unexpected argument value for arg-1: expected NoValue, Multiple but received Single."
+        );
     }
 
     #[test]
-    #[should_panic = "[ERROR] [my-command] unexpected argument value for arg-1: expected NoValue, Single but received Multiple."]
     fn test_received_unexpected_argument_value_got_multiple_value() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
             .command(
@@ -780,13 +788,16 @@ mod cli_handler_tests {
                 String::from("desc for arg-1"),
                 vec![ArgValueTypes::NoValue, ArgValueTypes::Single],
             )
-            .handler(|_, _| {})
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("my-command --arg-1 test --arg-1 test2"));
+        let result = cli_handler.handle(String::from("my-command --arg-1 test --arg-1 test2"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unexpected argument value for arg-1: expected NoValue, Single but received Multiple."
+        );
     }
 
     #[test]
-    #[should_panic = "[ERROR] [my-command] too much parameters: expected 2 parameters but received 3."]
     fn test_error_too_much_parameters() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
             .command(
@@ -794,15 +805,24 @@ mod cli_handler_tests {
                 String::from("description"),
                 Logger::new("my-command", true),
             )
-            .parameter(String::from("param-1-name"), String::from("param-1-desc"))
-            .parameter(String::from("param-2-name"), String::from("param-2-desc"))
-            .handler(|_, _| {})
+            .parameter(
+                String::from("param-1-name"),
+                String::from("param-1-desc"),
+            )
+            .parameter(
+                String::from("param-2-name"),
+                String::from("param-2-desc"),
+            )
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("my-command param-1 param-2 param-3"));
+        let result = cli_handler.handle(String::from("my-command param-1 param-2 param-3"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "too much parameters: expected 2 parameters but received 3."
+        );
     }
 
     #[test]
-    #[should_panic = "[ERROR] [my-command] too much parameters: expected 0 parameters but received 1."]
     fn test_error_too_much_parameters_no_parameters_specified() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
             .command(
@@ -810,13 +830,16 @@ mod cli_handler_tests {
                 String::from("description"),
                 Logger::new("my-command", true),
             )
-            .handler(|_, _| {})
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("my-command param-1"));
+        let result = cli_handler.handle(String::from("my-command param-1"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "too much parameters: expected 0 parameters but received 1."
+        );
     }
 
     #[test]
-    #[should_panic = "[ERROR] [my-command] not enough parameters: expected 2 parameters but received 1."]
     fn test_error_not_enough_parameters_some_parameters_specified() {
         let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
             .command(
@@ -824,11 +847,45 @@ mod cli_handler_tests {
                 String::from("description"),
                 Logger::new("my-command", true),
             )
-            .parameter(String::from("param-1-name"), String::from("param-1-desc"))
-            .parameter(String::from("param-2-name"), String::from("param-2-desc"))
-            .handler(|_, _| {})
+            .parameter(
+                String::from("param-1-name"),
+                String::from("param-1-desc"),
+            )
+            .parameter(
+                String::from("param-2-name"),
+                String::from("param-2-desc"),
+            )
+            .handler(|_, _| Ok(()))
             .build();
-        cli_handler.handle(String::from("my-command param-1"));
+        let result = cli_handler.handle(String::from("my-command param-1"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "not enough parameters: expected 2 parameters but received 1."
+        );
+    }
+
+    #[test]
+    #[should_panic = "handler executed"]
+    fn test_optional_parameters_can_be_omitted() {
+        let cli_handler = CliHandlerBuilder::new(Logger::new("CLI LOG", true))
+            .command(
+                String::from("my-command"),
+                String::from("description"),
+                Logger::new("my-command", true),
+            )
+            .parameter(
+                String::from("param-1-name"),
+                String::from("param-1-desc"),
+            )
+            .optional_parameter(
+                String::from("param-2-name"),
+                String::from("param-2-desc"),
+            )
+            .handler(|_, _| panic!("handler executed"))
+            .build();
+        cli_handler
+            .handle(String::from("my-command param-1"))
+            .unwrap();
     }
 
     #[test]
@@ -851,7 +908,10 @@ mod cli_handler_tests {
                 String::from("desc for arg-1"),
                 vec![ArgValueTypes::Single],
             )
-            .parameter(String::from("params-1"), String::from("desc for params-1"))
+            .parameter(
+                String::from("params-1"),
+                String::from("desc for params-1"),
+            )
             // It's hard to test the fact that the handler will be called.
             // TODO: That's a futur me assignment.
             .handler(|parsed_command, _| {
@@ -882,9 +942,11 @@ mod cli_handler_tests {
             })
             .build();
 
-        cli_handler.handle(String::from(
-            "my-command --arg-1 --arg-2 arg-2-value param-1-value",
-        ));
+        cli_handler
+            .handle(String::from(
+                "my-command --arg-1 --arg-2 arg-2-value param-1-value",
+            ))
+            .unwrap();
     }
 
     #[test]
@@ -905,7 +967,10 @@ mod cli_handler_tests {
                 String::from("desc for arg-2"),
                 vec![ArgValueTypes::Single, ArgValueTypes::Multiple],
             )
-            .parameter(String::from("params-1"), String::from("desc for params-1"))
+            .parameter(
+                String::from("params-1"),
+                String::from("desc for params-1"),
+            )
             .handler(|parsed_command, _| {
                 assert_eq!(parsed_command.command_name, "my-command");
 
@@ -950,12 +1015,14 @@ mod cli_handler_tests {
                         }
                     ])
                 );
+
+                Ok(())
             })
             .build();
 
         let input = String::from(
             "my-command my-param --arg-1 --arg-2 value1 --arg-1 my-arg-1-value --arg-2 value2 --arg-2 value3",
         );
-        cli_handler.handle(input);
+        cli_handler.handle(input).unwrap();
     }
 }
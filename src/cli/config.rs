@@ -0,0 +1,633 @@
+//! Declarative sort description, given to the sort command with `--config`, as a TOML or JSON
+//! file. YAML is not supported.
+//!
+//! ```toml
+//! input = "downloads"
+//! output = "sorted"
+//!
+//! [options]
+//! dry_run = true
+//!
+//! [filters]
+//! exclude_extensions = ["tmp"]
+//!
+//! [[strategies]]
+//! name = "concat"
+//! parameters = { strategies = [{ name = "year" }, { name = "month" }] }
+//! ```
+//!
+//! Conditions of the `if` strategy are written the same way, with a `predicate` key instead of
+//! `name`, e.g. `{ predicate = "extension", parameters = { extensions = "jpg" } }`.
+//!
+//! Key and value parameters, such as the `table` of the `map` strategy, are plain tables whose
+//! entries keep their order, e.g. `{ "jpg|png" = "Photos", pdf = "Documents" }`.
+//!
+//! Strategies are built and validated while the file is parsed, so every error points to
+//! the line and column of the faulty value.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{de, Deserialize};
+
+use crate::{
+    core::{
+        options::{FileFilters, SortOptions},
+        parameter::{parse_map, StrategyParameter, StrategyParameterKind},
+        strategy::{AddParameter, ParameterDetails, Strategy, Validate},
+    },
+    sorting_strategies::{catalog::all_catalog, predicate::all_predicates},
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SortConfig {
+    /// Relative paths are resolved from the directory of the configuration file.
+    pub input: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+    #[serde(default)]
+    pub options: ConfigOptions,
+    #[serde(default)]
+    pub filters: FileFilters,
+    #[serde(default)]
+    pub strategies: Vec<ConfigStrategy>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigOptions {
+    pub dry_run: bool,
+    pub root_level_only: bool,
+}
+
+/// A strategy of the catalog, with its parameters already applied and validated.
+#[derive(Debug, Clone)]
+pub struct ConfigStrategy(pub Box<dyn Strategy>);
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawStrategy {
+    name: StrategyName,
+    #[serde(default)]
+    parameters: BTreeMap<String, ConfigParameter>,
+}
+
+/// Name of a strategy of the catalog, checked while parsing to locate typos precisely.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "String")]
+struct StrategyName(String);
+
+/// Name of a predicate, checked while parsing like [`StrategyName`].
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "String")]
+struct PredicateName(String);
+
+#[derive(Debug)]
+struct ConfigParameter(StrategyParameter);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Format given by the extension of `path`, or the reason why it can't be read.
+    pub fn from_path(path: &Path) -> Result<Self, String> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+
+        match extension.as_deref() {
+            Some("toml") => Ok(ConfigFormat::Toml),
+            Some("json") => Ok(ConfigFormat::Json),
+            Some("yml" | "yaml") => {
+                Err("YAML is not supported, use a .toml or .json file".to_string())
+            }
+            _ => Err("expected a .toml or .json file".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    /// 1-based position of the error, when the parser knows it.
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl SortConfig {
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<Self, ParseError> {
+        match format {
+            ConfigFormat::Toml => toml::from_str(content).map_err(|err| {
+                let (line, column) = err
+                    .span()
+                    .map(|span| line_column(content, span.start))
+                    .unwrap_or((0, 0));
+                ParseError {
+                    line,
+                    column,
+                    message: err.message().to_string(),
+                }
+            }),
+            ConfigFormat::Json => serde_json::from_str(content).map_err(|err| {
+                let location = format!(" at line {} column {}", err.line(), err.column());
+                ParseError {
+                    line: err.line(),
+                    column: err.column(),
+                    message: err.to_string().trim_end_matches(&location).to_string(),
+                }
+            }),
+        }
+    }
+
+    pub fn sort_options(&self) -> SortOptions {
+        SortOptions {
+            dry_run: self.options.dry_run,
+            root_level_only: self.options.root_level_only,
+            filters: self.filters.clone(),
+        }
+    }
+
+    pub fn strategies(&self) -> Vec<Box<dyn Strategy>> {
+        self.strategies
+            .iter()
+            .map(|strategy| strategy.0.clone())
+            .collect()
+    }
+
+    /// Input and output directories, resolved against `base_dir`.
+    pub fn directories(&self, base_dir: &Path) -> Vec<String> {
+        [&self.input, &self.output]
+            .into_iter()
+            .flatten()
+            .map(|path| base_dir.join(path).to_string_lossy().to_string())
+            .collect()
+    }
+}
+
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map(|last_line| last_line.chars().count())
+        .unwrap_or(0)
+        + 1;
+
+    (line, column)
+}
+
+impl TryFrom<String> for StrategyName {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        let names = all_catalog().get_names();
+        if names.contains(&name) {
+            Ok(StrategyName(name))
+        } else {
+            Err(format!(
+                "unknown strategy '{}', expected one of: {}",
+                name,
+                names.join(", ")
+            ))
+        }
+    }
+}
+
+impl TryFrom<String> for PredicateName {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        let names = all_predicates().get_names();
+        if names.contains(&name) {
+            Ok(PredicateName(name))
+        } else {
+            Err(format!(
+                "unknown predicate '{}', expected one of: {}",
+                name,
+                names.join(", ")
+            ))
+        }
+    }
+}
+
+impl TryFrom<RawStrategy> for ConfigStrategy {
+    type Error = String;
+
+    fn try_from(raw: RawStrategy) -> Result<Self, Self::Error> {
+        let StrategyName(name) = raw.name;
+        let mut strategy = all_catalog()
+            .get_strategy(&name)
+            .ok_or(format!("unknown strategy '{}'", name))?;
+
+        configure(strategy.as_mut(), "strategy", &name, raw.parameters)?;
+
+        Ok(ConfigStrategy(strategy))
+    }
+}
+
+/// Applies the parameters read from the file to a strategy or a predicate, then validates it.
+fn configure<T: AddParameter + ParameterDetails + Validate + ?Sized>(
+    target: &mut T,
+    what: &str,
+    name: &str,
+    parameters: BTreeMap<String, ConfigParameter>,
+) -> Result<(), String> {
+    for (parameter_name, ConfigParameter(value)) in parameters {
+        let detail = target
+            .parameter_details()
+            .into_iter()
+            .find(|detail| detail.name == parameter_name)
+            .ok_or(format!(
+                "unknown parameter '{}' for {} '{}'",
+                parameter_name, what, name
+            ))?;
+
+        let value = match (&detail.kind, value) {
+            (StrategyParameterKind::Number, StrategyParameter::SingleString(text)) => text
+                .parse::<usize>()
+                .map(StrategyParameter::Number)
+                .map_err(|_| format!("parameter '{}' expects a number", parameter_name))?,
+            (StrategyParameterKind::Size, StrategyParameter::Number(bytes)) => {
+                StrategyParameter::SingleString(bytes.to_string())
+            }
+            (
+                StrategyParameterKind::Strategy | StrategyParameterKind::Predicate,
+                StrategyParameter::Map(entries),
+            ) => named::<de::value::Error>(entries, BTreeMap::new())
+                .map_err(|err| err.to_string())?,
+            (StrategyParameterKind::Map, StrategyParameter::SingleString(entries)) => {
+                StrategyParameter::Map(parse_map(&entries))
+            }
+            (StrategyParameterKind::List, StrategyParameter::SingleString(item)) => {
+                StrategyParameter::List(vec![item])
+            }
+            (StrategyParameterKind::List, StrategyParameter::Strategy(strategies))
+                if strategies.is_empty() =>
+            {
+                StrategyParameter::List(vec![])
+            }
+            (StrategyParameterKind::Predicate, StrategyParameter::Strategy(strategies))
+                if strategies.is_empty() =>
+            {
+                StrategyParameter::Predicate(vec![])
+            }
+            (_, value) => value,
+        };
+        target.add_parameter(parameter_name, value);
+    }
+
+    target
+        .validate()
+        .map_err(|err| format!("invalid {} '{}': {}", what, name, err))
+}
+
+/// Keys of a table giving a strategy or a predicate.
+const TABLE_FIELDS: &[&str] = &["name", "predicate", "parameters"];
+
+/// Strategy or predicate given by a table, such as `{ name = "year" }`, built from the strings of
+/// the table and its parameters.
+fn named<E: de::Error>(
+    entries: Vec<(String, String)>,
+    parameters: BTreeMap<String, ConfigParameter>,
+) -> Result<StrategyParameter, E> {
+    let mut strategy_name = None;
+    let mut predicate_name = None;
+    for (key, value) in entries {
+        match key.as_str() {
+            "name" => strategy_name = Some(StrategyName::try_from(value).map_err(E::custom)?),
+            "predicate" => {
+                predicate_name = Some(PredicateName::try_from(value).map_err(E::custom)?)
+            }
+            _ => return Err(E::unknown_field(&key, TABLE_FIELDS)),
+        }
+    }
+
+    match (strategy_name, predicate_name) {
+        (Some(name), None) => {
+            let ConfigStrategy(strategy) =
+                ConfigStrategy::try_from(RawStrategy { name, parameters }).map_err(E::custom)?;
+
+            Ok(StrategyParameter::Strategy(vec![strategy]))
+        }
+        (None, Some(PredicateName(name))) => {
+            let mut predicate = all_predicates()
+                .get_predicate(&name)
+                .ok_or(E::custom(format!("unknown predicate '{}'", name)))?;
+            configure(predicate.as_mut(), "predicate", &name, parameters).map_err(E::custom)?;
+
+            Ok(StrategyParameter::Predicate(vec![predicate]))
+        }
+        (None, None) => Err(E::missing_field("name")),
+        (Some(_), Some(_)) => Err(E::custom(
+            "expected either a strategy `name` or a `predicate`, not both",
+        )),
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigStrategy {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawStrategy::deserialize(deserializer)?;
+        ConfigStrategy::try_from(raw).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigParameter {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ParameterVisitor;
+
+        impl<'de> de::Visitor<'de> for ParameterVisitor {
+            type Value = ConfigParameter;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(
+                    "a string, a positive integer, a strategy, a predicate, a list or a table",
+                )
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                usize::try_from(value)
+                    .map(|value| ConfigParameter(StrategyParameter::Number(value)))
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+                    .and_then(|value| self.visit_u64(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(ConfigParameter(StrategyParameter::SingleString(
+                    value.to_string(),
+                )))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut strategies = vec![];
+                let mut predicates = vec![];
+                let mut items = vec![];
+                while let Some(ConfigParameter(value)) = seq.next_element()? {
+                    match value {
+                        StrategyParameter::Strategy(mut value) => strategies.append(&mut value),
+                        StrategyParameter::Predicate(mut value) => predicates.append(&mut value),
+                        StrategyParameter::SingleString(value) => items.push(value),
+                        StrategyParameter::Map(entries) => match named(entries, BTreeMap::new())? {
+                            StrategyParameter::Predicate(mut value) => {
+                                predicates.append(&mut value)
+                            }
+                            StrategyParameter::Strategy(mut value) => strategies.append(&mut value),
+                            _ => unreachable!("tables only name strategies or predicates"),
+                        },
+                        _ => {
+                            return Err(de::Error::custom(
+                                "a list can only contain strategies, predicates or strings",
+                            ))
+                        }
+                    }
+                }
+
+                match (
+                    strategies.is_empty(),
+                    predicates.is_empty(),
+                    items.is_empty(),
+                ) {
+                    (_, true, true) => Ok(ConfigParameter(StrategyParameter::Strategy(strategies))),
+                    (true, false, true) => {
+                        Ok(ConfigParameter(StrategyParameter::Predicate(predicates)))
+                    }
+                    (true, true, false) => Ok(ConfigParameter(StrategyParameter::List(items))),
+                    _ => Err(de::Error::custom(
+                        "a list can't mix strategies, predicates and strings",
+                    )),
+                }
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut parameters = None;
+                let mut entries: Vec<(String, String)> = vec![];
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "parameters" {
+                        parameters = Some(map.next_value()?);
+                        continue;
+                    }
+                    match map.next_value()? {
+                        ConfigParameter(StrategyParameter::SingleString(value)) => {
+                            entries.push((key, value))
+                        }
+                        _ => return Err(de::Error::unknown_field(&key, TABLE_FIELDS)),
+                    }
+                }
+
+                // Without parameters, the table may as well be a map whose keys are `name` or
+                // `predicate`: it is read once the kind of the parameter is known.
+                match parameters {
+                    Some(parameters) => named(entries, parameters).map(ConfigParameter),
+                    None => Ok(ConfigParameter(StrategyParameter::Map(entries))),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(ParameterVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_build_nested_strategies_from_toml() {
+        let config = SortConfig::parse(
+            r#"
+input = "in"
+output = "out"
+
+[options]
+dry_run = true
+
+[filters]
+extensions = ["jpg"]
+
+[[strategies]]
+name = "concat"
+parameters = { strategies = [{ name = "year" }, { name = "month" }] }
+
+[[strategies]]
+name = "text"
+parameters = { value = "photos" }
+"#,
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        let strategies = config.strategies();
+        assert_eq!(strategies.len(), 2);
+        assert_eq!(strategies[0].name(), "concat");
+        assert_eq!(
+            strategies[0]
+                .parameters()
+                .get("strategies")
+                .map(|p| p.kind()),
+            Some(StrategyParameterKind::Strategy)
+        );
+        assert!(config.sort_options().dry_run);
+        assert_eq!(config.sort_options().filters.extensions, vec!["jpg"]);
+        assert_eq!(
+            config.directories(Path::new("/rules")),
+            vec!["/rules/in".to_string(), "/rules/out".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_locate_unknown_strategies() {
+        let err = SortConfig::parse(
+            "[[strategies]]\nname = \"year\"\n\n[[strategies]]\nname = \"unknown\"\n",
+            ConfigFormat::Toml,
+        )
+        .unwrap_err();
+
+        assert_eq!((err.line, err.column), (5, 8));
+        assert!(err.message.starts_with("unknown strategy 'unknown'"));
+    }
+
+    #[test]
+    fn should_locate_json_errors() {
+        let err = SortConfig::parse(
+            "{\n  \"strategies\": [\n    { \"name\": \"year\", \"parameters\": { \"unknown\": 1 } }]\n}",
+            ConfigFormat::Json,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.line, 3);
+        assert_eq!(
+            err.message,
+            "unknown parameter 'unknown' for strategy 'year'"
+        );
+    }
+
+    #[test]
+    fn should_build_nested_predicates() {
+        let config = SortConfig::parse(
+            r#"
+[[strategies]]
+name = "if"
+
+[strategies.parameters]
+condition = [
+    { predicate = "not", parameters = { predicate = { predicate = "size", parameters = { max = 10 } } } },
+    { predicate = "extension", parameters = { extensions = "pdf" } },
+]
+then = { name = "text", parameters = { value = "documents" } }
+"#,
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.strategies()[0]
+                .parameters()
+                .get("condition")
+                .map(|p| p.kind()),
+            Some(StrategyParameterKind::Predicate)
+        );
+
+        let err = SortConfig::parse(
+            "[[strategies]]\nname = \"if\"\nparameters = { condition = { predicate = \"size\", parameters = { min = 2, max = 1 } }, then = [] }\n",
+            ConfigFormat::Toml,
+        )
+        .unwrap_err();
+        assert!(err
+            .message
+            .starts_with("invalid predicate 'size': Invalid value for parameter min"));
+    }
+
+    #[test]
+    fn should_read_tables_in_order() {
+        let config = SortConfig::parse(
+            r#"
+[[strategies]]
+name = "map"
+
+[strategies.parameters]
+strategies = { name = "file extension" }
+table = { "jpg|jpeg|heic" = "Photos", pdf = "Documents/PDF", "*" = "Other" }
+"#,
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.strategies()[0].parameters().get("table"),
+            Some(&StrategyParameter::Map(vec![
+                ("jpg|jpeg|heic".to_string(), "Photos".to_string()),
+                ("pdf".to_string(), "Documents/PDF".to_string()),
+                ("*".to_string(), "Other".to_string()),
+            ]))
+        );
+
+        let err = SortConfig::parse(
+            "[[strategies]]\nname = \"concat\"\nparameters = { strategies = [{ name = \"year\", typo = \"x\" }] }\n",
+            ConfigFormat::Toml,
+        )
+        .unwrap_err();
+        assert!(err.message.starts_with("unknown field `typo`"));
+    }
+
+    #[test]
+    fn should_read_name_and_predicate_keys_of_map_tables() {
+        let config = SortConfig::parse(
+            r#"
+[[strategies]]
+name = "map"
+
+[strategies.parameters]
+strategies = { name = "file type" }
+table = { name = "Names", predicate = "Predicates" }
+"#,
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+
+        let parameters = config.strategies()[0].parameters();
+        assert_eq!(
+            parameters.get("table"),
+            Some(&StrategyParameter::Map(vec![
+                ("name".to_string(), "Names".to_string()),
+                ("predicate".to_string(), "Predicates".to_string()),
+            ]))
+        );
+        assert!(matches!(
+            parameters.get("strategies"),
+            Some(StrategyParameter::Strategy(strategies)) if strategies[0].name() == "file type"
+        ));
+    }
+
+    #[test]
+    fn should_guess_format_from_extension() {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("rules.TOML")),
+            Ok(ConfigFormat::Toml)
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("rules.yml")),
+            Err("YAML is not supported, use a .toml or .json file".to_string())
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("rules")),
+            Err("expected a .toml or .json file".to_string())
+        );
+    }
+}
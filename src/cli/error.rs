@@ -15,18 +15,58 @@ impl std::fmt::Display for DirectoryType {
 
 #[derive(Debug)]
 pub enum Error {
+    /// The command line doesn't match any command, argument or parameter.
+    Usage(String),
     WrongParamNumber(usize),
     DirectoryNotFound(DirectoryType, String),
     NotADirectory(String),
     NoStrategyProvided,
     MissingStrategyName,
     UnknownStrategy(String, String),
+    UnknownPredicate(String, String),
+    /// A predicate given on the command line can't be used without parameters.
+    PredicateNeedsParameters(String, crate::core::validation::error::Error),
     SorterError(crate::core::error::Error),
+    ExportError(String, std::io::Error),
+    ConfigNotReadable(String, std::io::Error),
+    UnsupportedConfigFormat(String, String),
+    InvalidConfig(String, super::config::ParseError),
+}
+
+/// Exit code of usage errors, such as unknown arguments or strategies.
+pub const USAGE_EXIT_CODE: i32 = 2;
+
+impl Error {
+    /// Process exit code matching the error, so scripts can tell failures apart.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Usage(_)
+            | Error::WrongParamNumber(_)
+            | Error::NoStrategyProvided
+            | Error::MissingStrategyName
+            | Error::UnknownStrategy(_, _)
+            | Error::UnknownPredicate(_, _)
+            | Error::PredicateNeedsParameters(_, _)
+            | Error::UnsupportedConfigFormat(_, _)
+            | Error::InvalidConfig(_, _) => USAGE_EXIT_CODE,
+            Error::DirectoryNotFound(_, _)
+            | Error::NotADirectory(_)
+            | Error::ConfigNotReadable(_, _) => 3,
+            Error::SorterError(err) => match err.kind() {
+                crate::core::error::ErrorKind::Validation => 4,
+                crate::core::error::ErrorKind::Strategy => 5,
+                crate::core::error::ErrorKind::IO => 6,
+                crate::core::error::ErrorKind::Pipeline => 70,
+            },
+            Error::ExportError(_, _) => 7,
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            super::error::Error::Usage(message) => f.write_str(message),
             super::error::Error::WrongParamNumber(len) => {
                 write!(f, "expected 2 params, got {}.", len)
             }
@@ -51,7 +91,80 @@ impl std::fmt::Display for Error {
                 "Unexpected stack value. Got '{}', expected one of: {}.",
                 name, all_strategy_names
             ),
-            super::error::Error::SorterError(err) => err.fmt(f),
+            super::error::Error::UnknownPredicate(name, all_predicate_names) => write!(
+                f,
+                "Unexpected predicate '{}', expected one of: {}.",
+                name, all_predicate_names
+            ),
+            super::error::Error::PredicateNeedsParameters(name, err) => write!(
+                f,
+                "The '{}' predicate can't be used from the command line ({}). Set it in a configuration file instead, see --config.",
+                name, err
+            ),
+            super::error::Error::SorterError(err) => match err.hint() {
+                Some(hint) => write!(f, "{} {}", err, hint),
+                None => err.fmt(f),
+            },
+            super::error::Error::ExportError(path, err) => {
+                write!(f, "Unable to export the report to '{}': {}", path, err)
+            }
+            super::error::Error::ConfigNotReadable(path, err) => {
+                write!(f, "Unable to read the configuration '{}': {}", path, err)
+            }
+            super::error::Error::UnsupportedConfigFormat(path, reason) => {
+                write!(f, "Unsupported configuration format for '{}': {}.", path, reason)
+            }
+            super::error::Error::InvalidConfig(path, err) => {
+                write!(f, "Invalid configuration {}:{}", path, err)
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_give_usage_errors_the_usage_exit_code() {
+        assert_eq!(Error::WrongParamNumber(1).exit_code(), USAGE_EXIT_CODE);
+        assert_eq!(
+            Error::Usage("unknown argument".to_string()).exit_code(),
+            USAGE_EXIT_CODE
+        );
+        assert_eq!(
+            Error::DirectoryNotFound(DirectoryType::Input, "input".to_string()).exit_code(),
+            3
+        );
+    }
+
+    #[test]
+    fn should_give_sorter_errors_the_exit_code_of_their_kind() {
+        let strategy = Error::SorterError(crate::core::error::Error::strategy("year", "failed"));
+        let io = Error::SorterError(crate::core::error::Error::io(
+            std::path::Path::new("/a"),
+            std::io::Error::from(std::io::ErrorKind::NotFound),
+        ));
+        let pipeline = Error::SorterError(crate::core::error::Error::Pipeline {
+            stage: "validation",
+            received: "paths".to_string(),
+        });
+
+        assert_eq!(strategy.exit_code(), 5);
+        assert_eq!(io.exit_code(), 6);
+        assert_eq!(pipeline.exit_code(), 70);
+    }
+
+    #[test]
+    fn should_append_the_hint_to_sorter_errors() {
+        let err = Error::SorterError(crate::core::error::Error::io(
+            std::path::Path::new("/a"),
+            std::io::Error::from(std::io::ErrorKind::NotFound),
+        ));
+
+        assert_eq!(
+            err.to_string(),
+            "[E301] '/a': entity not found Check that '/a' still exists."
+        );
+    }
+}
@@ -1,16 +1,17 @@
 use cli_handler::cli_handler_builder::{ArgValueTypes, CliHandlerBuilder};
 use sort_command::exec_sort_command;
 
-use crate::{cli::sort_command::{DRY_RUN, PARAMETER, ROOT_ONLY, STACK}, utils::logger::Logger};
+use crate::{cli::sort_command::{CONFIG, DRY_RUN, EXPORT, PARAMETER, ROOT_ONLY, STACK}, utils::logger::Logger};
 
 mod cli_handler;
+pub mod config;
 pub mod sort_command;
 pub mod error;
 
 /**
  * Use to write e2e tests.
  */
-pub fn handle(input: String, debug_mode: Option<bool>) {
+pub fn handle(input: String, debug_mode: Option<bool>) -> Result<(), error::Error> {
     let logger = Logger::new("Command Handler", debug_mode.unwrap_or(false));
     let sort_command_logger = Logger::new("Sort Command", debug_mode.unwrap_or(false));
     let command_handler = CliHandlerBuilder::new(logger)
@@ -22,7 +23,7 @@ pub fn handle(input: String, debug_mode: Option<bool>) {
     .argument(
         STACK.to_string(), 
         String::from("rule that specify where to put the files. first specified element will be the first directory that you will met and so."), 
-        vec![ArgValueTypes::Single, ArgValueTypes::Multiple]
+        vec![ArgValueTypes::NoValue, ArgValueTypes::Single, ArgValueTypes::Multiple]
     )
     .linked_arg(
         PARAMETER.to_string(), 
@@ -39,15 +40,25 @@ pub fn handle(input: String, debug_mode: Option<bool>) {
     "Only compute the report and move file at the input directory root level. Other files will stay in-place.".to_string(), 
     vec![ArgValueTypes::NoValue]
     )
-    .parameter(
+    .argument(
+        EXPORT.to_string(), 
+    "Write the reports and the run summary as JSON to the given file.".to_string(), 
+    vec![ArgValueTypes::NoValue, ArgValueTypes::Single]
+    )
+    .argument(
+        CONFIG.to_string(), 
+    "Read the directories, options, filters and strategies from a TOML or JSON file, YAML is not supported. Other arguments override it.".to_string(), 
+    vec![ArgValueTypes::NoValue, ArgValueTypes::Single]
+    )
+    .optional_parameter(
         String::from("from"), String::from("the directory from which you need to extract the files.")
     )
-    .parameter(
+    .optional_parameter(
         String::from("to"), 
         String::from("the directory to which you need to put the organized files.")
     )
-    .handler(|parsed_command, handler_logger| exec_sort_command(parsed_command.args.clone(), parsed_command.params.clone(), handler_logger.clone()))
+    .handler(|parsed_command, _| exec_sort_command(parsed_command.args.clone(), parsed_command.params.clone()))
     .build();
 
-    command_handler.handle(input);
+    command_handler.handle(input)
 }
@@ -1,19 +1,24 @@
-use std::{collections::HashMap, fs::metadata};
+use std::{collections::HashMap, fs::metadata, path::Path};
 
 use rsft_utils::common::file_or_dir_exists;
 
 use crate::{
-    cli::cli_handler::parser::ArgDatum,
+    cli::{
+        cli_handler::parser::ArgDatum,
+        config::{ConfigFormat, SortConfig},
+    },
     core::{
-        parameter::{StrategyParameter, StrategyParameterKind},
+        options::SortOptions,
+        parameter::{parse_map, StrategyParameter, StrategyParameterKind},
+        predicate::Predicate,
         strategy::Strategy,
         validation,
     },
-    sorting_strategies::catalog::{all_catalog, StrategyCatalog},
-    utils::{
-        file_manipulator::{to_absolute_path, to_relative_path},
-        logger::Logger,
+    sorting_strategies::{
+        catalog::{all_catalog, StrategyCatalog},
+        predicate::all_predicates,
     },
+    utils::file_manipulator::{to_absolute_path, to_relative_path},
 };
 
 use super::cli_handler::parser::{ArgValue, ParsedArgs};
@@ -22,9 +27,13 @@ pub static PARAMETER: &str = "parameter";
 pub static DRY_RUN: &str = "dry-run";
 pub static ROOT_ONLY: &str = "root-only";
 pub static STACK: &str = "stack";
+pub static EXPORT: &str = "export";
+pub static CONFIG: &str = "config";
 
 static PARAMETER_SEP: &'static str = "=";
 
+type SortInputs = (String, String, Vec<Box<dyn Strategy>>, SortOptions);
+
 impl std::fmt::Display for crate::core::report::Report {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.result {
@@ -34,7 +43,11 @@ impl std::fmt::Display for crate::core::report::Report {
                     "{} -> {}",
                     self.input_filename.display(),
                     target.display()
-                )
+                )?;
+                match &self.rule {
+                    Some(rule) => write!(f, " (rule: {})", rule),
+                    None => Ok(()),
+                }
             }
             Err(err) => {
                 write!(f, "{} -x {}", self.input_filename.display(), err)
@@ -53,33 +66,43 @@ fn get_strategy_validator(
         .find(|detail| detail.name == *name)
 }
 
-pub fn exec_sort_command(args: Vec<ParsedArgs>, params: Vec<String>, logger: Logger) {
-    let dry_run: bool = get_bool_arg_value(&args, DRY_RUN);
-    let root_level_only: bool = get_bool_arg_value(&args, ROOT_ONLY);
+pub fn exec_sort_command(
+    args: Vec<ParsedArgs>,
+    params: Vec<String>,
+) -> Result<(), super::error::Error> {
+    let export_path: Option<String> = get_single_arg_value(&args, EXPORT);
 
-    match get_cli_inputs(args, params, STACK, all_catalog()).and_then(
-        |(input_dir, output_dir, sorting_strategies)| {
-            crate::core::SortPipeline::new(
+    let (reports, summary, dry_run) = get_sort_inputs(args, params).and_then(
+        |(input_dir, output_dir, sorting_strategies, options)| {
+            let mut pipeline = crate::core::SortPipeline::new(
                 input_dir,
                 output_dir,
                 sorting_strategies,
-                crate::core::options::SortOptions {
-                    dry_run,
-                    root_level_only,
-                },
-            )
-            .process()
-            .map_err(super::error::Error::SorterError)
+                options.clone(),
+            );
+            let reports = pipeline
+                .process()
+                .map_err(super::error::Error::SorterError)?
+                .unwrap_or_default();
+            let summary = pipeline.summary(&reports);
+
+            Ok((reports, summary, options.dry_run))
         },
-    ) {
-        Err(err) => handle_errors(&logger, err),
-        Ok(reports) if dry_run => {
-            for report in reports.unwrap_or_default() {
-                println!("{}", report)
-            }
+    )?;
+
+    if dry_run {
+        for report in &reports {
+            println!("{}", report)
         }
-        _ => (),
-    };
+    }
+    println!("{}", summary);
+
+    if let Some(path) = export_path {
+        crate::core::export::write_json(Path::new(&path), &reports, &summary)
+            .map_err(|err| super::error::Error::ExportError(path, err))?;
+    }
+
+    Ok(())
 }
 
 fn get_bool_arg_value(args: &Vec<ParsedArgs>, arg_name: &str) -> bool {
@@ -87,6 +110,72 @@ fn get_bool_arg_value(args: &Vec<ParsedArgs>, arg_name: &str) -> bool {
         .any(|a| a.arg_name == arg_name.to_string() && a.arg_value != ArgValue::NotProvided)
 }
 
+fn get_single_arg_value(args: &[ParsedArgs], arg_name: &str) -> Option<String> {
+    args.iter()
+        .find(|a| a.arg_name == arg_name)
+        .and_then(|a| match &a.arg_value {
+            ArgValue::Single(datum) => datum.value.clone(),
+            ArgValue::Multiple(datums) => datums.last().and_then(|datum| datum.value.clone()),
+            ArgValue::NotProvided => None,
+        })
+}
+
+/// Merge the configuration file, if any, with the command line. Directories and stacks given
+/// on the command line replace the configured ones, and flags enable their option.
+fn get_sort_inputs(
+    args: Vec<ParsedArgs>,
+    params: Vec<String>,
+) -> Result<SortInputs, super::error::Error> {
+    let dry_run: bool = get_bool_arg_value(&args, DRY_RUN);
+    let root_level_only: bool = get_bool_arg_value(&args, ROOT_ONLY);
+
+    let config_path = match get_single_arg_value(&args, CONFIG) {
+        Some(path) => path,
+        None => {
+            let (input, output, strategies) = get_cli_inputs(args, params, STACK, all_catalog())?;
+            let options = SortOptions {
+                dry_run,
+                root_level_only,
+                ..Default::default()
+            };
+            return Ok((input, output, strategies, options));
+        }
+    };
+
+    let config = read_config(&config_path)?;
+    let config_dir = Path::new(&config_path).parent().unwrap_or(Path::new(""));
+    let params = if params.is_empty() {
+        config.directories(config_dir)
+    } else {
+        params
+    };
+    let (input, output) = get_directories(params)?;
+    let strategies = if get_bool_arg_value(&args, STACK) {
+        get_storting_strategies(get_stacks(args, STACK)?, all_catalog())?
+    } else {
+        config.strategies()
+    };
+    if strategies.is_empty() {
+        return Err(super::error::Error::NoStrategyProvided);
+    }
+
+    let mut options = config.sort_options();
+    options.dry_run |= dry_run;
+    options.root_level_only |= root_level_only;
+
+    Ok((input, output, strategies, options))
+}
+
+fn read_config(path: &str) -> Result<SortConfig, super::error::Error> {
+    let format = ConfigFormat::from_path(Path::new(path))
+        .map_err(|reason| super::error::Error::UnsupportedConfigFormat(path.to_string(), reason))?;
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| super::error::Error::ConfigNotReadable(path.to_string(), err))?;
+
+    SortConfig::parse(&content, format)
+        .map_err(|err| super::error::Error::InvalidConfig(path.to_string(), err))
+}
+
 fn get_cli_inputs(
     args: Vec<ParsedArgs>,
     params: Vec<String>,
@@ -114,10 +203,6 @@ fn get_stacks(
     }
 }
 
-fn handle_errors(logger: &Logger, err: super::error::Error) {
-    logger.error(&err.to_string());
-}
-
 fn get_directories(params: Vec<String>) -> Result<(String, String), super::error::Error> {
     fn validate_directory(
         path: &String,
@@ -169,10 +254,11 @@ fn get_storting_strategies(
 
             if let Some(parsed_arg) = arg_datum.get_child(PARAMETER) {
                 for (key, value) in &get_parameters_from_parsed_args(parsed_arg) {
-                    if let Some(parameter_value) = get_strategy_validator(strategy.clone(), key)
-                        .and_then(|validator| {
-                            get_parameter_value(&strategy_catalog, &validator, value)
-                        })
+                    let Some(validator) = get_strategy_validator(strategy.clone(), key) else {
+                        continue;
+                    };
+                    if let Some(parameter_value) =
+                        get_parameter_value(&strategy_catalog, &validator, value)?
                     {
                         strategy.add_parameter(key.clone(), parameter_value);
                     }
@@ -188,8 +274,8 @@ fn get_parameter_value(
     strategy_catalog: &StrategyCatalog,
     validator: &validation::ParameterDetail,
     value: &Vec<String>,
-) -> Option<StrategyParameter> {
-    match validator.kind {
+) -> Result<Option<StrategyParameter>, super::error::Error> {
+    let parameter = match validator.kind {
         StrategyParameterKind::Strategy => Some(StrategyParameter::Strategy(
             value
                 .iter()
@@ -197,7 +283,12 @@ fn get_parameter_value(
                 .flatten()
                 .collect(),
         )),
-        StrategyParameterKind::Choice(_) | StrategyParameterKind::SingleString => value
+        StrategyParameterKind::Predicate => Some(StrategyParameter::Predicate(
+            value.iter().map(get_predicate).collect::<Result<_, _>>()?,
+        )),
+        StrategyParameterKind::Choice(_)
+        | StrategyParameterKind::SingleString
+        | StrategyParameterKind::Size => value
             .iter()
             .last()
             .cloned()
@@ -207,7 +298,30 @@ fn get_parameter_value(
             .last()
             .and_then(|v| v.parse::<usize>().ok())
             .map(StrategyParameter::Number),
-    }
+        StrategyParameterKind::Map => Some(StrategyParameter::Map(
+            value.iter().flat_map(|v| parse_map(v)).collect(),
+        )),
+        StrategyParameterKind::List => Some(StrategyParameter::List(value.clone())),
+    };
+
+    Ok(parameter)
+}
+
+/// The command line can't give parameters to a predicate, so only the ones which are valid
+/// without any are accepted. The others must be set in a configuration file.
+fn get_predicate(name: &String) -> Result<Box<dyn Predicate>, super::error::Error> {
+    let predicates = all_predicates();
+    let predicate = predicates
+        .get_predicate(name)
+        .ok_or(super::error::Error::UnknownPredicate(
+            name.clone(),
+            predicates.get_names().join(", "),
+        ))?;
+    predicate
+        .validate()
+        .map_err(|err| super::error::Error::PredicateNeedsParameters(name.clone(), err))?;
+
+    Ok(predicate)
 }
 
 fn get_parameters_from_parsed_args(parsed_arg: &ParsedArgs) -> HashMap<String, Vec<String>> {
@@ -238,39 +352,33 @@ fn get_parameters_from_parsed_args(parsed_arg: &ParsedArgs) -> HashMap<String, V
 }
 
 #[cfg(test)]
-mod tests_exec_sort_command_panics {
+mod tests_exec_sort_command_errors {
     use crate::cli::cli_handler::parser::ArgDatum;
 
     use super::*;
 
     #[test]
-    #[should_panic(expected = "[ERROR] [Sort Command] expected 2 params, got 1.")]
     fn test_exec_sort_command_params_length_not_enought() {
-        exec_sort_command(
-            vec![],
-            vec![String::from("tests/rsc/files/output/")],
-            Logger::new("Sort Command", true),
-        );
+        let result = exec_sort_command(vec![], vec![String::from("tests/rsc/files/output/")]);
+        assert_eq!(result.unwrap_err().to_string(), "expected 2 params, got 1.");
     }
 
     #[test]
-    #[should_panic(expected = "[ERROR] [Sort Command] expected 2 params, got 3.")]
     fn test_exec_sort_command_params_length_too_many() {
-        exec_sort_command(
+        let result = exec_sort_command(
             vec![],
             vec![
                 String::from("tests/rsc/files/output/"),
                 String::from("tests/rsc/files/output/"),
                 String::from("tests/rsc/files/output/"),
             ],
-            Logger::new("Sort Command", true),
         );
+        assert_eq!(result.unwrap_err().to_string(), "expected 2 params, got 3.");
     }
 
     #[test]
-    #[should_panic(expected = "[ERROR] [Sort Command] stack argument haven't been provided.")]
     fn test_exec_sort_command_missing_stack_argument() {
-        exec_sort_command(
+        let result = exec_sort_command(
             vec![ParsedArgs {
                 arg_name: String::from("stack"),
                 arg_value: ArgValue::NotProvided,
@@ -279,16 +387,16 @@ mod tests_exec_sort_command_panics {
                 String::from("tests/rsc/files/output/"),
                 String::from("tests/rsc/files/output/"),
             ],
-            Logger::new("Sort Command", true),
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "stack argument haven't been provided."
         );
     }
 
     #[test]
-    #[should_panic(
-        expected = "[ERROR] [Sort Command] A value needs to be assigned to the stack argument."
-    )]
     fn test_exec_sort_command_stack_argument_has_no_value() {
-        exec_sort_command(
+        let result = exec_sort_command(
             vec![ParsedArgs {
                 arg_name: String::from("stack"),
                 arg_value: ArgValue::Single(ArgDatum::new()),
@@ -297,16 +405,16 @@ mod tests_exec_sort_command_panics {
                 String::from("tests/rsc/files/output/"),
                 String::from("tests/rsc/files/output/"),
             ],
-            Logger::new("Sort Command", true),
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A value needs to be assigned to the stack argument."
         );
     }
 
     #[test]
-    #[should_panic(
-        expected = "[ERROR] [Sort Command] Unexpected stack value. Got 'unknown_stack', expected one of: month, year, file extension, file type, concat, text, or, text semantic."
-    )]
     fn test_exec_sort_command_unexpected_stack_value() {
-        exec_sort_command(
+        let result = exec_sort_command(
             vec![ParsedArgs {
                 arg_name: String::from("stack"),
                 arg_value: ArgValue::Single(ArgDatum {
@@ -318,7 +426,54 @@ mod tests_exec_sort_command_panics {
                 String::from("tests/rsc/files/output/"),
                 String::from("tests/rsc/files/output/"),
             ],
-            Logger::new("Sort Command", true),
         );
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected stack value. Got 'unknown_stack', expected one of: month, year, date, file extension, file type, mime type, filename date, exif date, camera, music tag, document metadata, size, relative path, letter, shard, concat, text, or, rule, if, regex, map, template, transform, batch, text semantic.");
+        assert_eq!(err.exit_code(), super::super::error::USAGE_EXIT_CODE);
+    }
+
+    fn if_stack(condition: &str) -> Vec<ParsedArgs> {
+        vec![ParsedArgs {
+            arg_name: String::from("stack"),
+            arg_value: ArgValue::Single(ArgDatum {
+                value: Some(String::from("if")),
+                child_args: vec![ParsedArgs {
+                    arg_name: String::from("parameter"),
+                    arg_value: ArgValue::Single(ArgDatum {
+                        value: Some(format!("condition={}", condition)),
+                        child_args: vec![],
+                    }),
+                }],
+            }),
+        }]
+    }
+
+    #[test]
+    fn test_exec_sort_command_unknown_predicate() {
+        let result = exec_sort_command(
+            if_stack("unknown_predicate"),
+            vec![
+                String::from("tests/rsc/files/output/"),
+                String::from("tests/rsc/files/output/"),
+            ],
+        );
+        let err = result.unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected predicate 'unknown_predicate', expected one of: extension, file type, name, size, modified, path contains, and, or, not.");
+        assert_eq!(err.exit_code(), super::super::error::USAGE_EXIT_CODE);
+    }
+
+    #[test]
+    fn test_exec_sort_command_predicate_needs_parameters() {
+        let result = exec_sort_command(
+            if_stack("extension"),
+            vec![
+                String::from("tests/rsc/files/output/"),
+                String::from("tests/rsc/files/output/"),
+            ],
+        );
+        assert!(matches!(
+            result,
+            Err(super::super::error::Error::PredicateNeedsParameters(name, _)) if name == "extension"
+        ));
     }
 }
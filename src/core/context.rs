@@ -1,20 +1,73 @@
 use std::path::PathBuf;
 
-use crate::core::error;
+use crate::core::{error, strategy::Strategy};
 
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct StrategyContext {
     files: Vec<PathBuf>,
+    input_dir: PathBuf,
+    output_dir: PathBuf,
+    parents: Vec<Box<dyn Strategy>>,
 }
 
 impl StrategyContext {
     pub fn new(files: Vec<PathBuf>) -> Self {
-        StrategyContext { files }
+        StrategyContext {
+            files,
+            input_dir: PathBuf::new(),
+            output_dir: PathBuf::new(),
+            parents: vec![],
+        }
+    }
+
+    pub fn with_input_dir(mut self, input_dir: PathBuf) -> Self {
+        self.input_dir = input_dir;
+        self
+    }
+
+    pub fn with_output_dir(mut self, output_dir: PathBuf) -> Self {
+        self.output_dir = output_dir;
+        self
+    }
+
+    /// Strategies stacked before the one given this context, already given the context.
+    pub fn with_parents(mut self, parents: Vec<Box<dyn Strategy>>) -> Self {
+        self.parents = parents;
+        self
     }
 
     pub fn files(&self) -> Vec<PathBuf> {
         self.files.clone()
     }
+
+    /// Directory the files are sorted from.
+    pub fn input_dir(&self) -> PathBuf {
+        self.input_dir.clone()
+    }
+
+    /// Directory the files are sorted into.
+    pub fn output_dir(&self) -> PathBuf {
+        self.output_dir.clone()
+    }
+
+    /// Folder given to the file by the strategies stacked before, relative to the output
+    /// directory.
+    pub fn parent_folder(&self, file_path: &PathBuf) -> PathBuf {
+        let mut folder = PathBuf::new();
+        if self.parents.is_empty() {
+            return folder;
+        }
+        let Ok(file) = std::fs::File::open(file_path) else {
+            return folder;
+        };
+
+        self.parents
+            .iter()
+            .filter_map(|strategy| strategy.apply(file_path, &file))
+            .for_each(|segment| folder.push(segment));
+
+        folder
+    }
 }
 
 pub trait ProcessContext {
@@ -1,13 +1,31 @@
+use std::path::PathBuf;
+
 #[derive(Debug)]
 pub enum Error {
-    Validation(super::validation::error::Error),
-    Strategy(String),
-    IO(std::io::Error),
-    Pipeline,
+    /// A strategy has been configured with invalid parameters.
+    Validation {
+        strategy: String,
+        source: super::validation::error::Error,
+    },
+    /// A strategy failed to process the context, or a given file.
+    Strategy {
+        strategy: String,
+        path: Option<PathBuf>,
+        cause: String,
+    },
+    /// A filesystem operation failed on `path`.
+    IO {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A stage received data it can't handle. Always a bug of the sorter itself.
+    Pipeline {
+        stage: &'static str,
+        received: String,
+    },
 }
 
-#[cfg(test)]
-#[derive(PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum ErrorKind {
     Validation,
     Strategy,
@@ -15,30 +33,126 @@ pub enum ErrorKind {
     Pipeline,
 }
 
-#[cfg(test)]
 impl Error {
+    pub fn strategy(strategy: &str, cause: &str) -> Self {
+        Error::Strategy {
+            strategy: strategy.to_string(),
+            path: None,
+            cause: cause.to_string(),
+        }
+    }
+
+    /// Attach the file being sorted to a strategy error.
+    pub fn with_path(self, file_path: &std::path::Path) -> Self {
+        match self {
+            Error::Strategy {
+                strategy, cause, ..
+            } => Error::Strategy {
+                strategy,
+                path: Some(file_path.to_path_buf()),
+                cause,
+            },
+            other => other,
+        }
+    }
+
+    pub fn io(path: &std::path::Path, source: std::io::Error) -> Self {
+        Error::IO {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+
     pub fn kind(&self) -> ErrorKind {
         match self {
-            Error::Validation(_) => ErrorKind::Validation,
-            Error::Strategy(_) => ErrorKind::Strategy,
-            Error::IO(_) => ErrorKind::IO,
-            Error::Pipeline => ErrorKind::Pipeline,
+            Error::Validation { .. } => ErrorKind::Validation,
+            Error::Strategy { .. } => ErrorKind::Strategy,
+            Error::IO { .. } => ErrorKind::IO,
+            Error::Pipeline { .. } => ErrorKind::Pipeline,
+        }
+    }
+
+    /// Stable identifier of the error, safe to match on in scripts and bug reports.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::Validation { .. } => "E100",
+            Error::Strategy { .. } => "E200",
+            Error::IO { source, .. } => match source.kind() {
+                std::io::ErrorKind::NotFound => "E301",
+                std::io::ErrorKind::PermissionDenied => "E302",
+                std::io::ErrorKind::AlreadyExists => "E303",
+                _ => "E300",
+            },
+            Error::Pipeline { .. } => "E400",
+        }
+    }
+
+    /// What the user can do to get rid of the error, if anything.
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Error::Validation { strategy, .. } => Some(format!(
+                "Check the parameters given to the '{strategy}' strategy."
+            )),
+            Error::Strategy { .. } => None,
+            Error::IO { path, source } => match source.kind() {
+                std::io::ErrorKind::NotFound => {
+                    Some(format!("Check that '{}' still exists.", path.display()))
+                }
+                std::io::ErrorKind::PermissionDenied => Some(format!(
+                    "Check that you are allowed to read and write '{}'.",
+                    path.display()
+                )),
+                std::io::ErrorKind::AlreadyExists => Some(format!(
+                    "Remove or rename '{}' before sorting again.",
+                    path.display()
+                )),
+                _ => None,
+            },
+            Error::Pipeline { .. } => {
+                Some("Please report this error with steps to reproduce.".to_string())
+            }
         }
     }
 }
 
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ErrorKind::Validation => "validation",
+            ErrorKind::Strategy => "strategy",
+            ErrorKind::IO => "io",
+            ErrorKind::Pipeline => "pipeline",
+        })
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] ", self.code())?;
         match self {
-            Error::Validation(err) => write!(f, "Validation Error: {err}"),
-            Error::Strategy(message) => write!(f, "Strategy Error: {message}"),
-            Error::IO(err) => err.fmt(f),
-            Error::Pipeline => write!(
+            Error::Validation { strategy, source } => {
+                write!(f, "Invalid strategy '{strategy}': {source}")
+            }
+            Error::Strategy {
+                strategy,
+                path: Some(path),
+                cause,
+            } => write!(
                 f,
-                "A pipeline error occurred. Please report this error with steps to reproduce."
+                "Strategy '{strategy}' failed on '{}': {cause}",
+                path.display()
             ),
+            Error::Strategy {
+                strategy,
+                path: None,
+                cause,
+            } => write!(f, "Strategy '{strategy}' failed: {cause}"),
+            Error::IO { path, source } => write!(f, "'{}': {source}", path.display()),
+            Error::Pipeline { stage, received } => {
+                write!(f, "Stage '{stage}' received unexpected {received} data.")
+            }
         }
     }
 }
@@ -46,10 +160,86 @@ impl std::fmt::Display for Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Error::Validation(e) => Some(e),
-            Error::IO(e) => Some(e),
-            Error::Strategy(_) => None,
-            Error::Pipeline => None,
+            Error::Validation { source, .. } => Some(source),
+            Error::IO { source, .. } => Some(source),
+            Error::Strategy { .. } => None,
+            Error::Pipeline { .. } => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_give_distinct_codes_to_io_errors() {
+        let not_found = Error::io(
+            std::path::Path::new("/a"),
+            std::io::Error::from(std::io::ErrorKind::NotFound),
+        );
+        let denied = Error::io(
+            std::path::Path::new("/a"),
+            std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+        );
+
+        assert_eq!(not_found.code(), "E301");
+        assert_eq!(denied.code(), "E302");
+        assert_eq!(not_found.kind(), ErrorKind::IO);
+    }
+
+    #[test]
+    fn should_display_the_strategy_and_the_file() {
+        let err = Error::Strategy {
+            strategy: "text semantic".to_string(),
+            path: Some(PathBuf::from("/input/a.txt")),
+            cause: "NotEnoughPoints".to_string(),
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "[E200] Strategy 'text semantic' failed on '/input/a.txt': NotEnoughPoints"
+        );
+    }
+
+    #[test]
+    fn should_display_the_strategy_without_file() {
+        let err = Error::strategy("text semantic", "NotEnoughPoints");
+
+        assert_eq!(
+            err.to_string(),
+            "[E200] Strategy 'text semantic' failed: NotEnoughPoints"
+        );
+        assert_eq!(err.kind(), ErrorKind::Strategy);
+    }
+
+    #[test]
+    fn should_attach_the_file_to_strategy_errors_only() {
+        let err = Error::strategy("year", "failed").with_path(std::path::Path::new("/input/a"));
+        let io = Error::io(
+            std::path::Path::new("/a"),
+            std::io::Error::from(std::io::ErrorKind::NotFound),
+        )
+        .with_path(std::path::Path::new("/input/a"));
+
+        assert_eq!(
+            err.to_string(),
+            "[E200] Strategy 'year' failed on '/input/a': failed"
+        );
+        assert_eq!(io.to_string(), "[E301] '/a': entity not found");
+    }
+
+    #[test]
+    fn should_display_the_stage_of_pipeline_errors() {
+        let err = Error::Pipeline {
+            stage: "validation",
+            received: "paths".to_string(),
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "[E400] Stage 'validation' received unexpected paths data."
+        );
+        assert!(err.hint().is_some());
+    }
+}
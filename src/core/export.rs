@@ -0,0 +1,96 @@
+use serde::Serialize;
+
+use crate::core::{report::FullReport, summary::Summary};
+
+#[derive(Debug, Serialize)]
+struct ReportExport {
+    input: String,
+    target: Option<String>,
+    status: String,
+    error: Option<String>,
+    size: u64,
+    rule: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Export {
+    reports: Vec<ReportExport>,
+    summary: Summary,
+}
+
+/// Serialize the reports of a sort, along with its summary, as a JSON document.
+pub fn to_json(reports: &FullReport, summary: &Summary) -> serde_json::Result<String> {
+    let export = Export {
+        reports: reports
+            .iter()
+            .map(|report| ReportExport {
+                input: report.input_filename.display().to_string(),
+                target: report
+                    .result
+                    .as_ref()
+                    .ok()
+                    .map(|target| target.display().to_string()),
+                status: report.status().to_string(),
+                error: report.result.as_ref().err().map(|err| err.to_string()),
+                size: report.size,
+                rule: report.rule.clone(),
+            })
+            .collect(),
+        summary: summary.clone(),
+    };
+
+    serde_json::to_string_pretty(&export)
+}
+
+pub fn write_json(
+    path: &std::path::Path,
+    reports: &FullReport,
+    summary: &Summary,
+) -> std::io::Result<()> {
+    let content = to_json(reports, summary).map_err(std::io::Error::other)?;
+    std::fs::write(path, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, rc::Rc};
+
+    use super::*;
+    use crate::core::{error, report::Report};
+
+    #[test]
+    fn should_export_reports_and_summary() {
+        let reports = vec![
+            Report {
+                input_filename: PathBuf::from("/input/a.txt"),
+                result: Ok(PathBuf::from("/output/txt/a.txt")),
+                size: 3,
+                strategy_timings: vec![],
+                rule: None,
+                moved: true,
+            },
+            Report {
+                input_filename: PathBuf::from("/input/b.txt"),
+                result: Err(Rc::new(error::Error::Pipeline {
+                    stage: "apply strategies",
+                    received: "empty".to_string(),
+                })),
+                size: 0,
+                strategy_timings: vec![],
+                rule: None,
+                moved: false,
+            },
+        ];
+        let summary = Summary::new(&reports, &PathBuf::from("/output"), vec![]);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&to_json(&reports, &summary).unwrap()).unwrap();
+
+        assert_eq!(json["reports"][0]["target"], "/output/txt/a.txt");
+        assert_eq!(json["reports"][0]["status"], "moved");
+        assert_eq!(json["reports"][1]["target"], serde_json::Value::Null);
+        assert_eq!(json["reports"][1]["status"], "failed");
+        assert_eq!(json["summary"]["folders"][0]["name"], "txt");
+        assert_eq!(json["summary"]["failures"]["pipeline"], 1);
+    }
+}
@@ -1,10 +1,13 @@
 pub mod context;
 pub mod error;
+pub mod export;
 pub mod options;
 pub mod parameter;
 mod pipeline;
+pub mod predicate;
 pub mod report;
 pub mod strategy;
+pub mod summary;
 pub mod validation;
 
 pub use pipeline::SortPipeline;
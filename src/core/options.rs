@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 pub struct SortOptions {
     pub dry_run: bool,
     pub root_level_only: bool,
+    #[serde(default)]
+    pub filters: FileFilters,
 }
 
 impl Default for SortOptions {
@@ -11,6 +13,72 @@ impl Default for SortOptions {
         SortOptions {
             dry_run: false,
             root_level_only: false,
+            filters: FileFilters::default(),
         }
     }
 }
+
+/// Restrict the files picked up from the input directory. Default filters keep every file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FileFilters {
+    /// Only keep files having one of these extensions, if any is given.
+    pub extensions: Vec<String>,
+    pub exclude_extensions: Vec<String>,
+    /// Skip files whose name starts with a dot.
+    pub exclude_hidden: bool,
+}
+
+impl FileFilters {
+    pub fn matches(&self, path: &std::path::Path) -> bool {
+        let is_hidden = path
+            .file_name()
+            .map(|name| name.to_string_lossy().starts_with('.'))
+            .unwrap_or(false);
+        if self.exclude_hidden && is_hidden {
+            return false;
+        }
+
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        let is_listed = |extensions: &Vec<String>| {
+            extension.as_ref().is_some_and(|extension| {
+                extensions
+                    .iter()
+                    .any(|e| e.trim_start_matches('.').to_lowercase() == *extension)
+            })
+        };
+
+        (self.extensions.is_empty() || is_listed(&self.extensions))
+            && !is_listed(&self.exclude_extensions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn should_keep_every_file_by_default() {
+        let filters = FileFilters::default();
+        assert!(filters.matches(Path::new("/input/.hidden")));
+        assert!(filters.matches(Path::new("/input/no_extension")));
+    }
+
+    #[test]
+    fn should_filter_extensions_ignoring_case_and_dot() {
+        let filters = FileFilters {
+            extensions: vec![".JPG".to_string(), "png".to_string()],
+            exclude_extensions: vec!["png".to_string()],
+            exclude_hidden: true,
+        };
+
+        assert!(filters.matches(Path::new("/input/a.jpg")));
+        assert!(!filters.matches(Path::new("/input/a.png")));
+        assert!(!filters.matches(Path::new("/input/a.txt")));
+        assert!(!filters.matches(Path::new("/input/.a.jpg")));
+    }
+}
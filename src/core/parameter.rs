@@ -5,6 +5,11 @@ pub enum StrategyParameter {
     Strategy(Vec<Box<dyn super::strategy::Strategy>>),
     SingleString(String),
     Number(usize),
+    /// Conditions that must all match.
+    Predicate(Vec<Box<dyn super::predicate::Predicate>>),
+    /// Key and value pairs, in the order they were given.
+    Map(Vec<(String, String)>),
+    List(Vec<String>),
 }
 
 impl PartialEq for StrategyParameter {
@@ -14,6 +19,11 @@ impl PartialEq for StrategyParameter {
             (StrategyParameter::Strategy(v1), StrategyParameter::Strategy(v2)) => {
                 v1.len() == v2.len()
             }
+            (StrategyParameter::Predicate(v1), StrategyParameter::Predicate(v2)) => {
+                v1.len() == v2.len()
+            }
+            (StrategyParameter::Map(m1), StrategyParameter::Map(m2)) => m1 == m2,
+            (StrategyParameter::List(l1), StrategyParameter::List(l2)) => l1 == l2,
             _ => false,
         }
     }
@@ -25,6 +35,13 @@ pub enum StrategyParameterKind {
     SingleString,
     Number,
     Choice(Vec<String>),
+    Predicate,
+    /// Sizes written with their unit, such as `10MB` or `1.5 GiB`, separated by commas.
+    Size,
+    /// Entries with a key and a value, such as `jpg|jpeg -> Photos`.
+    Map,
+    /// Texts in a given order.
+    List,
 }
 
 impl Display for StrategyParameterKind {
@@ -37,6 +54,10 @@ impl Display for StrategyParameterKind {
                 return write!(f, "choice: ({})", list_str);
             }
             StrategyParameterKind::Number => "number",
+            StrategyParameterKind::Predicate => "predicate",
+            StrategyParameterKind::Size => "size",
+            StrategyParameterKind::Map => "map",
+            StrategyParameterKind::List => "list",
         })
     }
 }
@@ -47,7 +68,9 @@ impl StrategyParameterKind {
         match self {
             StrategyParameterKind::Strategy
             | StrategyParameterKind::SingleString
-            | StrategyParameterKind::Number => value.kind() == *self,
+            | StrategyParameterKind::Number
+            | StrategyParameterKind::Predicate
+            | StrategyParameterKind::List => value.kind() == *self,
             StrategyParameterKind::Choice(items) => {
                 if let StrategyParameter::SingleString(single_string) = value {
                     items.iter().any(|item| item == single_string)
@@ -55,6 +78,16 @@ impl StrategyParameterKind {
                     false
                 }
             }
+            StrategyParameterKind::Size => match value {
+                StrategyParameter::SingleString(sizes) => parse_sizes(sizes).is_ok(),
+                _ => false,
+            },
+            StrategyParameterKind::Map => match value {
+                StrategyParameter::Map(entries) => entries
+                    .iter()
+                    .all(|(key, value)| !key.is_empty() && !value.is_empty()),
+                _ => false,
+            },
         }
     }
 }
@@ -65,6 +98,123 @@ impl StrategyParameter {
             StrategyParameter::SingleString(_) => StrategyParameterKind::SingleString,
             StrategyParameter::Strategy(_) => StrategyParameterKind::Strategy,
             StrategyParameter::Number(_) => StrategyParameterKind::Number,
+            StrategyParameter::Predicate(_) => StrategyParameterKind::Predicate,
+            StrategyParameter::Map(_) => StrategyParameterKind::Map,
+            StrategyParameter::List(_) => StrategyParameterKind::List,
+        }
+    }
+}
+
+/// Multiples of the units of a size, decimal ones (`KB`) and binary ones (`KiB`).
+const SIZE_UNITS: [(&str, u64); 9] = [
+    ("b", 1),
+    ("kb", 1_000),
+    ("mb", 1_000_000),
+    ("gb", 1_000_000_000),
+    ("tb", 1_000_000_000_000),
+    ("kib", 1 << 10),
+    ("mib", 1 << 20),
+    ("gib", 1 << 30),
+    ("tib", 1 << 40),
+];
+
+/// Number of bytes of a size such as `10MB`, `1.5 GiB` or `512`, in bytes without unit.
+pub fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let unit_start = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(unit_start);
+    let invalid = || format!("'{value}' is not a size such as '10MB' or '1.5GiB'");
+
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    let unit = unit.trim().to_lowercase();
+    let multiple = match unit.as_str() {
+        "" => 1,
+        unit => SIZE_UNITS
+            .iter()
+            .find(|(name, _)| *name == unit)
+            .map(|(_, multiple)| *multiple)
+            .ok_or_else(invalid)?,
+    };
+
+    Ok((number * multiple as f64).round() as u64)
+}
+
+/// Sizes of a comma separated list, such as `10MB,1GB`.
+pub fn parse_sizes(value: &str) -> Result<Vec<u64>, String> {
+    value.split(',').map(parse_size).collect()
+}
+
+/// Separator of the key and the value of a map entry.
+pub const MAP_ENTRY_SEPARATOR: &str = "->";
+/// Separator of the entries of a map written on a single line.
+pub const MAP_ENTRIES_SEPARATOR: char = ';';
+
+/// Entries of a map written as `key -> value`, separated by semicolons. An entry without
+/// separator has an empty value, which the [`StrategyParameterKind::Map`] kind rejects.
+pub fn parse_map(value: &str) -> Vec<(String, String)> {
+    value
+        .split(MAP_ENTRIES_SEPARATOR)
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (key, value) = entry.split_once(MAP_ENTRY_SEPARATOR).unwrap_or((entry, ""));
+            (key.trim().to_string(), value.trim().to_string())
+        })
+        .collect()
+}
+
+/// Entries of a map written the way [`parse_map`] reads them.
+pub fn format_map(entries: &[(String, String)]) -> String {
+    entries
+        .iter()
+        .map(|(key, value)| format!("{key} {MAP_ENTRY_SEPARATOR} {value}"))
+        .collect::<Vec<_>>()
+        .join(&format!("{MAP_ENTRIES_SEPARATOR} "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_human_sizes() {
+        assert_eq!(parse_size("512"), Ok(512));
+        assert_eq!(parse_size("10MB"), Ok(10_000_000));
+        assert_eq!(parse_size("1.5 KiB"), Ok(1536));
+        assert_eq!(parse_size("2gib"), Ok(2 * 1024 * 1024 * 1024));
+        assert_eq!(
+            parse_sizes("10MB, 1GB"),
+            Ok(vec![10_000_000, 1_000_000_000])
+        );
+
+        for invalid in ["", "MB", "10 parsecs", "1.2.3KB", "10MB,"] {
+            assert!(parse_sizes(invalid).is_err(), "{invalid} should be invalid");
         }
     }
+
+    #[test]
+    fn should_match_size_kind() {
+        assert!(StrategyParameterKind::Size
+            .is_matching(&StrategyParameter::SingleString("1KB,1MB".to_string())));
+        assert!(!StrategyParameterKind::Size
+            .is_matching(&StrategyParameter::SingleString("big".to_string())));
+        assert!(!StrategyParameterKind::Size.is_matching(&StrategyParameter::Number(10)));
+    }
+
+    #[test]
+    fn should_parse_map_entries() {
+        let entries = parse_map("jpg|jpeg|heic -> Photos; pdf->Documents/PDF;");
+
+        assert_eq!(
+            entries,
+            vec![
+                ("jpg|jpeg|heic".to_string(), "Photos".to_string()),
+                ("pdf".to_string(), "Documents/PDF".to_string()),
+            ]
+        );
+        assert_eq!(parse_map(&format_map(&entries)), entries);
+        assert!(StrategyParameterKind::Map.is_matching(&StrategyParameter::Map(entries)));
+        assert!(!StrategyParameterKind::Map.is_matching(&StrategyParameter::Map(parse_map("pdf"))));
+    }
 }
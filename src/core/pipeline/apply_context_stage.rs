@@ -16,23 +16,30 @@ impl PipelineStage<PipelineData, error::Error> for ApplyContextStage {
     ) -> Result<PipelineData, error::Error> {
         let files = match &data {
             PipelineData::Paths(paths) => paths.clone(),
-            _ => return Err(error::Error::Pipeline),
+            other => return Err(other.unexpected_in(self.name())),
         };
-        let strategy_context = StrategyContext::new(files);
+        let strategy_context = StrategyContext::new(files)
+            .with_input_dir(context.input_dir().into())
+            .with_output_dir(context.output_dir().into());
         let mut new_context = context.clone();
-        let new_strategies = new_context
-            .strategies()
-            .iter()
-            .map(|processor| {
-                let mut p = processor.clone();
-                p.process_context(strategy_context.clone())?;
-                Ok(p)
-            })
-            .collect::<Result<Vec<_>, error::Error>>()?;
+        let mut new_strategies = vec![];
+        for processor in new_context.strategies().iter() {
+            let mut p = processor.clone();
+            p.process_context(
+                strategy_context
+                    .clone()
+                    .with_parents(new_strategies.clone()),
+            )?;
+            new_strategies.push(p);
+        }
         new_context.set_strategies(new_strategies);
 
         Ok(PipelineData::Context(new_context))
     }
+
+    fn name(&self) -> &'static str {
+        "apply context"
+    }
 }
 
 impl std::fmt::Display for ApplyContextStage {
@@ -104,10 +111,11 @@ mod tests {
     #[derive(Debug, Clone)]
     struct TestErrorContext;
     impl context::ProcessContext for TestErrorContext {
-        fn process_context(&mut self, _: StrategyContext) -> Result<(), error::Error> {
-            Err(error::Error::Strategy(
-                "Failed processing context".to_string(),
-            ))
+        fn process_context(&mut self, context: StrategyContext) -> Result<(), error::Error> {
+            Err(
+                error::Error::strategy("TestErrorContext", "Failed processing context")
+                    .with_path(&context.files()[0]),
+            )
         }
     }
 
@@ -185,7 +193,7 @@ mod tests {
 
     #[test]
     fn test_should_forward_any_error() {
-        let data = PipelineData::Paths(vec![PathBuf::new()]);
+        let data = PipelineData::Paths(vec![PathBuf::from("input_dir/a.txt")]);
         let pipeline_context = PipelineContext::new(
             vec![Box::new(TestErrorContext)],
             crate::core::options::SortOptions::default(),
@@ -194,7 +202,11 @@ mod tests {
         );
 
         let result = ApplyContextStage.execute(pipeline_context, data);
-        assert!(result.is_err());
-        assert_eq!(result.err().unwrap().kind(), error::ErrorKind::Strategy);
+        let err = result.err().unwrap();
+        assert_eq!(err.kind(), error::ErrorKind::Strategy);
+        assert_eq!(
+            err.to_string(),
+            "[E200] Strategy 'TestErrorContext' failed on 'input_dir/a.txt': Failed processing context"
+        );
     }
 }
@@ -1,7 +1,7 @@
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, time::Instant};
 
 use super::super::error;
-use crate::core::pipeline::pipeline_data::PipelineContext;
+use crate::core::{pipeline::pipeline_data::PipelineContext, report::Timing};
 
 use super::{stage::PipelineStage, PipelineData};
 
@@ -12,15 +12,26 @@ impl ApplyStrategiesStage {
         context: &PipelineContext,
         file_name: &std::ffi::OsStr,
         full_filename: &PathBuf,
+        timings: &mut Vec<Timing>,
+        rule: &mut Option<String>,
     ) -> Result<PathBuf, error::Error> {
-        let file = fs::File::open(full_filename.clone()).map_err(error::Error::IO)?;
+        let file = fs::File::open(full_filename)
+            .map_err(|err| error::Error::io(full_filename, err))?;
 
         let mut new_output = PathBuf::from(context.output_dir());
-        context
-            .strategies()
-            .iter()
-            .filter_map(|strategy| strategy.apply(full_filename, &file))
-            .for_each(|path| new_output.push(path));
+        for strategy in context.strategies().iter() {
+            let start = Instant::now();
+            let (segment, matched_rule) = strategy.apply_with_rule(full_filename, &file);
+            timings.push(Timing::new(&strategy.name(), start.elapsed()));
+
+            if rule.is_none() {
+                *rule = matched_rule;
+            }
+
+            if let Some(path) = segment {
+                new_output.push(path);
+            }
+        }
 
         Ok(new_output.join(file_name))
     }
@@ -38,18 +49,34 @@ impl PipelineStage<PipelineData, error::Error> for ApplyStrategiesStage {
                 for file in path_bufs {
                     let file_name = file.file_name().unwrap_or(&std::ffi::OsStr::new("/"));
 
-                    let result = self.apply_strategies(&context, file_name, &file);
+                    let mut strategy_timings = vec![];
+                    let mut rule = None;
+                    let result = self.apply_strategies(
+                        &context,
+                        file_name,
+                        &file,
+                        &mut strategy_timings,
+                        &mut rule,
+                    );
                     reports.push(crate::core::report::Report {
                         input_filename: file.clone(),
                         result: result.map_err(std::rc::Rc::new),
+                        size: fs::metadata(&file).map(|m| m.len()).unwrap_or(0),
+                        strategy_timings,
+                        rule,
+                        moved: false,
                     });
                 }
 
                 Ok(PipelineData::Report(reports))
             }
-            _ => Err(error::Error::Pipeline),
+            other => Err(other.unexpected_in(self.name())),
         }
     }
+
+    fn name(&self) -> &'static str {
+        "apply strategies"
+    }
 }
 
 impl std::fmt::Display for ApplyStrategiesStage {
@@ -16,17 +16,20 @@ impl PipelineStage<PipelineData, error::Error> for ApplyTransformationStage {
                 let reports = move_files_from_report(sorter_reports);
 
                 if !context.options().root_level_only {
-                    remove_empty_directories(
-                        &std::path::PathBuf::from(context.input_dir()).as_path(),
-                    )
-                    .map_err(error::Error::IO)?;
+                    let input_dir = std::path::PathBuf::from(context.input_dir());
+                    remove_empty_directories(&input_dir)
+                        .map_err(|err| error::Error::io(&input_dir, err))?;
                 }
 
                 Ok(PipelineData::Report(reports))
             }
-            _ => Err(error::Error::Pipeline),
+            other => Err(other.unexpected_in(self.name())),
         }
     }
+
+    fn name(&self) -> &'static str {
+        "apply transformation"
+    }
 }
 
 impl std::fmt::Display for ApplyTransformationStage {
@@ -42,9 +45,10 @@ fn move_files_from_report(
         if let Ok(target) = &mut report.result {
             report.result =
                 crate::utils::file_manipulator::move_file(&report.input_filename, target, true)
-                    .map_err(error::Error::IO)
+                    .map_err(|err| error::Error::io(&report.input_filename, err))
                     .map_err(std::rc::Rc::new)
                     .map(|_| target.clone());
+            report.moved = report.result.is_ok();
         }
     }
 
@@ -17,6 +17,10 @@ impl PipelineStage<PipelineData, error::Error> for CheckDryRunStage {
             Ok(data)
         }
     }
+
+    fn name(&self) -> &'static str {
+        "check dry run"
+    }
 }
 
 impl std::fmt::Display for CheckDryRunStage {
@@ -42,6 +46,7 @@ mod tests {
         context.set_options(SortOptions {
             dry_run: true,
             root_level_only: false,
+            ..Default::default()
         });
 
         let result = CheckDryRunStage.execute(context, PipelineData::Report(vec![]));
@@ -55,6 +60,7 @@ mod tests {
         context.set_options(SortOptions {
             dry_run: false,
             root_level_only: false,
+            ..Default::default()
         });
 
         let result = CheckDryRunStage.execute(context, PipelineData::Report(vec![]));
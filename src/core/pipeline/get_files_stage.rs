@@ -13,10 +13,11 @@ impl PipelineStage<PipelineData, error::Error> for GetFilesStage {
     ) -> Result<PipelineData, error::Error> {
         match data {
             PipelineData::Empty => {
+                let input_dir = std::path::PathBuf::from(context.input_dir());
                 let files_list: Result<Vec<std::path::PathBuf>, error::Error> =
                     if context.options().root_level_only {
-                        std::fs::read_dir(&context.input_dir())
-                            .map_err(error::Error::IO)?
+                        std::fs::read_dir(&input_dir)
+                            .map_err(|err| error::Error::io(&input_dir, err))?
                             .filter_map(|entry| match entry {
                                 Ok(e) => match e.file_type() {
                                     Ok(file_type) if file_type.is_file() => Some(Ok(e)),
@@ -26,17 +27,27 @@ impl PipelineStage<PipelineData, error::Error> for GetFilesStage {
                             })
                             .map(|entry| entry.map(|e| e.path()))
                             .collect::<Result<_, _>>()
-                            .map_err(error::Error::IO)
+                            .map_err(|err| error::Error::io(&input_dir, err))
                     } else {
-                        read_recursively(std::path::Path::new(&context.input_dir()))
-                            .map_err(error::Error::IO)
+                        read_recursively(&input_dir)
+                            .map_err(|err| error::Error::io(&input_dir, err))
                     };
 
-                Ok(PipelineData::Paths(files_list?))
+                let filters = context.options().filters;
+                Ok(PipelineData::Paths(
+                    files_list?
+                        .into_iter()
+                        .filter(|path| filters.matches(path))
+                        .collect(),
+                ))
             }
-            _ => Err(error::Error::Pipeline),
+            other => Err(other.unexpected_in(self.name())),
         }
     }
+
+    fn name(&self) -> &'static str {
+        "get files"
+    }
 }
 
 impl std::fmt::Display for GetFilesStage {
@@ -14,9 +14,13 @@ use get_files_stage::GetFilesStage;
 use pipeline_data::PipelineData;
 use validation_stage::ValidationStage;
 
+use std::time::Instant;
+
 use crate::core::{
     pipeline::{apply_context_stage::ApplyContextStage, pipeline_data::PipelineContext},
+    report::Timing,
     strategy::Strategy,
+    summary::Summary,
 };
 
 trait PipelineStageDisplay:
@@ -34,6 +38,7 @@ pub struct SortPipeline {
     stages: Vec<Box<dyn PipelineStageDisplay>>,
     data: PipelineData,
     context: PipelineContext,
+    timings: Vec<Timing>,
 }
 
 impl SortPipeline {
@@ -58,6 +63,7 @@ impl SortPipeline {
             stages,
             data: PipelineData::Empty,
             context,
+            timings: vec![],
         }
     }
 
@@ -69,7 +75,11 @@ impl SortPipeline {
                 let stage: &dyn PipelineStageDisplay = &**boxed_stage;
 
                 self.curr = self.curr + 1;
-                match stage.execute(self.context.clone(), self.data.clone())? {
+                let start = Instant::now();
+                let result = stage.execute(self.context.clone(), self.data.clone());
+                self.timings.push(Timing::new(stage.name(), start.elapsed()));
+
+                match result? {
                     PipelineData::Pause => break,
                     PipelineData::Context(context) => self.context = context,
                     data => self.data = data,
@@ -89,4 +99,17 @@ impl SortPipeline {
     pub fn has_next(&self) -> bool {
         self.curr < self.stages.len()
     }
+
+    /// Time spent in each stage executed so far, in execution order.
+    pub fn stage_timings(&self) -> Vec<Timing> {
+        self.timings.clone()
+    }
+
+    pub fn summary(&self, reports: &super::report::FullReport) -> Summary {
+        Summary::new(
+            reports,
+            std::path::Path::new(&self.context.output_dir()),
+            self.stage_timings(),
+        )
+    }
 }
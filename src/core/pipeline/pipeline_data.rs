@@ -65,7 +65,6 @@ pub enum PipelineData {
     Context(PipelineContext),
 }
 
-#[cfg(test)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum PipelineDataKind {
     Empty,
@@ -75,7 +74,6 @@ pub enum PipelineDataKind {
     Context,
 }
 
-#[cfg(test)]
 impl PipelineData {
     pub fn kind(&self) -> PipelineDataKind {
         match self {
@@ -86,4 +84,24 @@ impl PipelineData {
             PipelineData::Context(_) => PipelineDataKind::Context,
         }
     }
+
+    /// Error raised by a stage that doesn't know what to do with this data.
+    pub fn unexpected_in(&self, stage: &'static str) -> crate::core::error::Error {
+        crate::core::error::Error::Pipeline {
+            stage,
+            received: self.kind().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for PipelineDataKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PipelineDataKind::Empty => "empty",
+            PipelineDataKind::Pause => "pause",
+            PipelineDataKind::Paths => "paths",
+            PipelineDataKind::Report => "report",
+            PipelineDataKind::Context => "context",
+        })
+    }
 }
@@ -5,4 +5,7 @@ where
     E: std::error::Error,
 {
     fn execute(&self, context: PipelineContext, data: T) -> Result<T, E>;
+
+    /// Short identifier used to label the stage in reports and summaries.
+    fn name(&self) -> &'static str;
 }
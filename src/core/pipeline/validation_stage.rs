@@ -14,14 +14,23 @@ impl PipelineStage<PipelineData, error::Error> for ValidationStage {
             PipelineData::Empty => {
                 // validate the strategies
                 for strategy in &context.strategies() {
-                    strategy.validate().map_err(error::Error::Validation)?;
+                    strategy
+                        .validate()
+                        .map_err(|source| error::Error::Validation {
+                            strategy: strategy.name(),
+                            source,
+                        })?;
                 }
 
                 Ok(PipelineData::Empty)
             }
-            _ => Err(error::Error::Pipeline),
+            other => Err(other.unexpected_in(self.name())),
         }
     }
+
+    fn name(&self) -> &'static str {
+        "validation"
+    }
 }
 
 impl std::fmt::Display for ValidationStage {
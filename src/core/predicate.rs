@@ -0,0 +1,37 @@
+use std::fs::File;
+
+use crate::core::strategy::{AddParameter, Name, ParameterDetails, Parameters, Validate};
+
+pub trait PredicateCloneBox {
+    fn clone_box(&self) -> Box<dyn Predicate>;
+}
+impl<T> PredicateCloneBox for T
+where
+    T: 'static + Predicate + Clone,
+{
+    fn clone_box(&self) -> Box<dyn Predicate> {
+        Box::new(self.clone())
+    }
+}
+
+/// A condition on a file, configured and validated the same way as a strategy.
+pub trait Predicate:
+    Matches + Validate + AddParameter + ParameterDetails + Parameters + Name + PredicateCloneBox
+{
+}
+
+impl<
+        T: Matches + Validate + AddParameter + ParameterDetails + Parameters + Name + Clone + 'static,
+    > Predicate for T
+{
+}
+
+impl Clone for Box<dyn Predicate> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+pub trait Matches: std::fmt::Debug {
+    fn matches(&self, file_path: &std::path::Path, file: &File) -> bool;
+}
@@ -1,7 +1,120 @@
+use serde::Serialize;
+
 pub type FullReport = Vec<Report>;
 
 #[derive(Clone, Debug)]
 pub struct Report {
     pub input_filename: std::path::PathBuf,
     pub result: Result<std::path::PathBuf, std::rc::Rc<super::error::Error>>,
+    /// Size of the input file in bytes, `0` if it couldn't be read.
+    pub size: u64,
+    /// Time spent by each strategy of the stack to compute its path segment.
+    pub strategy_timings: Vec<Timing>,
+    /// Name of the routing rule that chose the target of the file, if any.
+    pub rule: Option<String>,
+    /// Whether the file has been moved to its target, which dry runs don't do.
+    pub moved: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReportStatus {
+    Moved,
+    /// Would be moved, but the sort stopped before moving the files.
+    Planned,
+    Unchanged,
+    Failed,
+}
+
+impl std::fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ReportStatus::Moved => "moved",
+            ReportStatus::Planned => "planned",
+            ReportStatus::Unchanged => "unchanged",
+            ReportStatus::Failed => "failed",
+        })
+    }
+}
+
+impl Report {
+    pub fn status(&self) -> ReportStatus {
+        match &self.result {
+            Ok(target) if *target == self.input_filename => ReportStatus::Unchanged,
+            Ok(_) if self.moved => ReportStatus::Moved,
+            Ok(_) => ReportStatus::Planned,
+            Err(_) => ReportStatus::Failed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Timing {
+    pub name: String,
+    #[serde(rename = "elapsed_ms", serialize_with = "serialize_as_millis")]
+    pub elapsed: std::time::Duration,
+}
+
+impl Timing {
+    pub fn new(name: &str, elapsed: std::time::Duration) -> Self {
+        Timing {
+            name: name.to_string(),
+            elapsed,
+        }
+    }
+}
+
+fn serialize_as_millis<S: serde::Serializer>(
+    duration: &std::time::Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, rc::Rc};
+
+    use super::*;
+
+    fn report(input: &str, result: Result<PathBuf, Rc<crate::core::error::Error>>) -> Report {
+        Report {
+            input_filename: PathBuf::from(input),
+            result,
+            size: 0,
+            strategy_timings: vec![],
+            rule: None,
+            moved: false,
+        }
+    }
+
+    #[test]
+    fn should_be_moved_if_target_differs_from_input() {
+        let mut report = report("in/file.txt", Ok(PathBuf::from("out/file.txt")));
+        report.moved = true;
+        assert_eq!(report.status(), ReportStatus::Moved);
+    }
+
+    #[test]
+    fn should_be_planned_until_moved() {
+        let report = report("in/file.txt", Ok(PathBuf::from("out/file.txt")));
+        assert_eq!(report.status(), ReportStatus::Planned);
+    }
+
+    #[test]
+    fn should_be_unchanged_if_target_is_input() {
+        let report = report("in/file.txt", Ok(PathBuf::from("in/file.txt")));
+        assert_eq!(report.status(), ReportStatus::Unchanged);
+    }
+
+    #[test]
+    fn should_be_failed_if_result_is_an_error() {
+        let report = report(
+            "in/file.txt",
+            Err(Rc::new(
+                crate::core::error::Error::strategy("year", "failed")
+                    .with_path(&PathBuf::from("in/file.txt")),
+            )),
+        );
+        assert_eq!(report.status(), ReportStatus::Failed);
+    }
 }
@@ -58,6 +58,16 @@ impl<
 // The main trait
 pub trait Apply: std::fmt::Debug {
     fn apply(&self, file_path: &std::path::PathBuf, file: &File) -> Option<String>;
+
+    /// Same as [`Apply::apply`], along with the name of the routing rule that handled the
+    /// file. Strategies routing files, or holding other strategies, need to override it.
+    fn apply_with_rule(
+        &self,
+        file_path: &std::path::PathBuf,
+        file: &File,
+    ) -> (Option<String>, Option<String>) {
+        (self.apply(file_path, file), None)
+    }
 }
 
 pub trait Name {
@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{
+    core::report::{FullReport, Timing},
+    utils::string_manipulator::human_readable_size,
+};
+
+const SLOWEST_STRATEGIES_COUNT: usize = 5;
+/// Name given to the folder of files that were sorted directly in the output root.
+const OUTPUT_ROOT_FOLDER: &str = ".";
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FolderSummary {
+    pub name: String,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Statistics computed from a [`FullReport`] once the pipeline stopped.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Summary {
+    /// Files and bytes per top-level folder of the output directory.
+    pub folders: Vec<FolderSummary>,
+    pub statuses: BTreeMap<String, usize>,
+    /// Failed files grouped by error kind.
+    pub failures: BTreeMap<String, usize>,
+    /// Files routed by each rule.
+    pub rules: BTreeMap<String, usize>,
+    pub stages: Vec<Timing>,
+    /// Strategies with the highest cumulated apply time, slowest first.
+    pub slowest_strategies: Vec<Timing>,
+}
+
+impl Summary {
+    pub fn new(reports: &FullReport, output_dir: &std::path::Path, stages: Vec<Timing>) -> Self {
+        let mut folders: BTreeMap<String, FolderSummary> = BTreeMap::new();
+        let mut statuses: BTreeMap<String, usize> = BTreeMap::new();
+        let mut failures: BTreeMap<String, usize> = BTreeMap::new();
+        let mut rules: BTreeMap<String, usize> = BTreeMap::new();
+        let mut strategies: BTreeMap<String, std::time::Duration> = BTreeMap::new();
+
+        for report in reports {
+            *statuses.entry(report.status().to_string()).or_default() += 1;
+
+            match &report.result {
+                Ok(target) => {
+                    let name = top_level_folder(target, output_dir);
+                    let folder = folders.entry(name.clone()).or_insert(FolderSummary {
+                        name,
+                        files: 0,
+                        bytes: 0,
+                    });
+                    folder.files += 1;
+                    folder.bytes += report.size;
+                }
+                Err(err) => *failures.entry(err.kind().to_string()).or_default() += 1,
+            }
+
+            if let Some(rule) = &report.rule {
+                *rules.entry(rule.clone()).or_default() += 1;
+            }
+
+            for timing in &report.strategy_timings {
+                *strategies.entry(timing.name.clone()).or_default() += timing.elapsed;
+            }
+        }
+
+        let mut slowest_strategies: Vec<Timing> = strategies
+            .into_iter()
+            .map(|(name, elapsed)| Timing { name, elapsed })
+            .collect();
+        slowest_strategies.sort_by_key(|timing| std::cmp::Reverse(timing.elapsed));
+        slowest_strategies.truncate(SLOWEST_STRATEGIES_COUNT);
+
+        Summary {
+            folders: folders.into_values().collect(),
+            statuses,
+            failures,
+            rules,
+            stages,
+            slowest_strategies,
+        }
+    }
+
+    pub fn total_files(&self) -> usize {
+        self.statuses.values().sum()
+    }
+}
+
+fn top_level_folder(target: &std::path::Path, output_dir: &std::path::Path) -> String {
+    let relative = target.strip_prefix(output_dir).unwrap_or(target);
+    let mut components = relative.components();
+    match (components.next(), components.next()) {
+        (Some(folder), Some(_)) => folder.as_os_str().to_string_lossy().to_string(),
+        _ => OUTPUT_ROOT_FOLDER.to_string(),
+    }
+}
+
+pub fn format_millis(duration: &std::time::Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
+
+impl std::fmt::Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "SUMMARY ({} files)", self.total_files())?;
+        for (status, count) in &self.statuses {
+            writeln!(f, "\t{:<24}{:>8}", status, count)?;
+        }
+
+        writeln!(f, "\nFOLDERS")?;
+        for folder in &self.folders {
+            writeln!(
+                f,
+                "\t{:<24}{:>8} files{:>12}",
+                folder.name,
+                folder.files,
+                human_readable_size(folder.bytes)
+            )?;
+        }
+
+        if !self.failures.is_empty() {
+            writeln!(f, "\nFAILURES")?;
+            for (kind, count) in &self.failures {
+                writeln!(f, "\t{:<24}{:>8}", kind, count)?;
+            }
+        }
+
+        if !self.rules.is_empty() {
+            writeln!(f, "\nRULES")?;
+            for (rule, count) in &self.rules {
+                writeln!(f, "\t{:<24}{:>8}", rule, count)?;
+            }
+        }
+
+        writeln!(f, "\nSTAGES")?;
+        for stage in &self.stages {
+            writeln!(
+                f,
+                "\t{:<24}{:>12}",
+                stage.name,
+                format_millis(&stage.elapsed)
+            )?;
+        }
+
+        writeln!(f, "\nSLOWEST STRATEGIES")?;
+        for strategy in &self.slowest_strategies {
+            writeln!(
+                f,
+                "\t{:<24}{:>12}",
+                strategy.name,
+                format_millis(&strategy.elapsed)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, rc::Rc, time::Duration};
+
+    use super::*;
+    use crate::core::{error, report::Report};
+
+    fn report(target: Result<&str, error::Error>, size: u64, timings: Vec<Timing>) -> Report {
+        Report {
+            input_filename: PathBuf::from("/input/file"),
+            result: target.map(PathBuf::from).map_err(Rc::new),
+            size,
+            strategy_timings: timings,
+            rule: None,
+            moved: true,
+        }
+    }
+
+    #[test]
+    fn should_group_files_and_bytes_by_top_level_folder() {
+        let reports = vec![
+            report(Ok("/output/2023/05_May/a.txt"), 10, vec![]),
+            report(Ok("/output/2023/06_June/b.txt"), 5, vec![]),
+            report(Ok("/output/2022/c.txt"), 1, vec![]),
+            report(Ok("/output/d.txt"), 2, vec![]),
+        ];
+
+        let summary = Summary::new(&reports, &PathBuf::from("/output"), vec![]);
+
+        assert_eq!(
+            summary.folders,
+            vec![
+                FolderSummary {
+                    name: ".".to_string(),
+                    files: 1,
+                    bytes: 2
+                },
+                FolderSummary {
+                    name: "2022".to_string(),
+                    files: 1,
+                    bytes: 1
+                },
+                FolderSummary {
+                    name: "2023".to_string(),
+                    files: 2,
+                    bytes: 15
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_count_statuses_and_group_failures_by_kind() {
+        let reports = vec![
+            report(Ok("/output/a.txt"), 0, vec![]),
+            report(Ok("/input/file"), 0, vec![]),
+            report(
+                Err(error::Error::Pipeline {
+                    stage: "apply strategies",
+                    received: "empty".to_string(),
+                }),
+                0,
+                vec![],
+            ),
+            report(
+                Err(error::Error::io(
+                    std::path::Path::new("/input/file"),
+                    std::io::Error::other("io error"),
+                )),
+                0,
+                vec![],
+            ),
+            report(
+                Err(error::Error::io(
+                    std::path::Path::new("/input/file"),
+                    std::io::Error::other("io error"),
+                )),
+                0,
+                vec![],
+            ),
+        ];
+
+        let summary = Summary::new(&reports, &PathBuf::from("/output"), vec![]);
+
+        assert_eq!(summary.total_files(), 5);
+        assert_eq!(summary.statuses.get("moved"), Some(&1));
+        assert_eq!(summary.statuses.get("unchanged"), Some(&1));
+        assert_eq!(summary.statuses.get("failed"), Some(&3));
+        assert_eq!(summary.failures.get("io"), Some(&2));
+        assert_eq!(summary.failures.get("pipeline"), Some(&1));
+    }
+
+    #[test]
+    fn should_count_files_of_dry_runs_as_planned() {
+        let mut planned = report(Ok("/output/a.txt"), 0, vec![]);
+        planned.moved = false;
+
+        let summary = Summary::new(&vec![planned], &PathBuf::from("/output"), vec![]);
+
+        assert_eq!(summary.statuses.get("planned"), Some(&1));
+        assert_eq!(summary.statuses.get("moved"), None);
+    }
+
+    #[test]
+    fn should_count_files_per_rule() {
+        let mut images = report(Ok("/output/images/a.jpg"), 0, vec![]);
+        images.rule = Some("images".to_string());
+        let reports = vec![
+            images.clone(),
+            images,
+            report(Ok("/output/b.txt"), 0, vec![]),
+        ];
+
+        let summary = Summary::new(&reports, &PathBuf::from("/output"), vec![]);
+
+        assert_eq!(summary.rules.len(), 1);
+        assert_eq!(summary.rules.get("images"), Some(&2));
+    }
+
+    #[test]
+    fn should_sort_strategies_by_cumulated_time() {
+        let reports = vec![
+            report(
+                Ok("/output/a.txt"),
+                0,
+                vec![
+                    Timing::new("year", Duration::from_millis(2)),
+                    Timing::new("month", Duration::from_millis(3)),
+                ],
+            ),
+            report(
+                Ok("/output/b.txt"),
+                0,
+                vec![
+                    Timing::new("year", Duration::from_millis(2)),
+                    Timing::new("month", Duration::from_millis(0)),
+                ],
+            ),
+        ];
+
+        let summary = Summary::new(&reports, &PathBuf::from("/output"), vec![]);
+
+        assert_eq!(
+            summary.slowest_strategies,
+            vec![
+                Timing::new("year", Duration::from_millis(4)),
+                Timing::new("month", Duration::from_millis(3)),
+            ]
+        );
+    }
+}
@@ -3,6 +3,8 @@ pub enum Error {
     MissingMandatoryParameter(super::ParameterDetail),
     UnknownParameter(String),
     TypeError(super::ParameterDetail),
+    /// The parameter has the right kind, but a value the strategy can't use.
+    InvalidValue(String, String),
 }
 
 impl std::fmt::Display for Error {
@@ -21,6 +23,9 @@ impl std::fmt::Display for Error {
                 "Type error for parameter: {} expected kind {:?}",
                 validator.name, validator.kind
             ),
+            Error::InvalidValue(name, reason) => {
+                write!(f, "Invalid value for parameter {}: {}", name, reason)
+            }
         }
     }
 }
@@ -136,7 +136,12 @@ impl crate::core::context::ProcessContext for TextSemanticStrategy {
             cluster_number,
             TF_IDF_ITERATION,
         )
-        .map_err(|err| crate::core::error::Error::Strategy(err.kind().to_string()))?;
+        .map_err(|err| {
+            crate::core::error::Error::strategy(
+                &strategy::Name::name(self),
+                &err.kind().to_string(),
+            )
+        })?;
 
         // Associate each file to the most meaningfull word in its cluster
         for i in 0..kmean.labels.len() {
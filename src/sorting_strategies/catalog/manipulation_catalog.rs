@@ -1,6 +1,13 @@
+mod batch_strategy;
 mod concat_strategy;
+mod if_strategy;
+mod map_strategy;
 mod or_strategy;
+mod regex_strategy;
+mod rule_strategy;
+mod template_strategy;
 mod text_strategy;
+mod transform_strategy;
 
 use super::strategy_catalog::StrategyCatalog;
 
@@ -9,5 +16,12 @@ pub fn get_manipulation_catalog() -> StrategyCatalog {
         Box::new(concat_strategy::ConcatStrategy::new()),
         Box::new(text_strategy::TextStrategy::new()),
         Box::new(or_strategy::OrStrategy::new()),
+        Box::new(rule_strategy::RuleStrategy::new()),
+        Box::new(if_strategy::IfStrategy::new()),
+        Box::new(regex_strategy::RegexStrategy::new()),
+        Box::new(map_strategy::MapStrategy::new()),
+        Box::new(template_strategy::TemplateStrategy::new()),
+        Box::new(transform_strategy::TransformStrategy::new()),
+        Box::new(batch_strategy::BatchStrategy::new()),
     ])
 }
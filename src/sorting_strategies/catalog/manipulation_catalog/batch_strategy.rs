@@ -0,0 +1,428 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    core::{
+        context,
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const STRATEGIES: &str = "strategies";
+const BATCH_SIZE: &str = "batch_size";
+const ORDER: &str = "order";
+const DIGITS: &str = "digits";
+
+const NAME: &str = "name";
+const DATE: &str = "date";
+const SIZE: &str = "size";
+
+/// Splits the files into numbered folders (`0001`, `0002`, ...) of at most `batch_size` files,
+/// taken in the given `order`. The files are batched apart for each folder given by the
+/// strategies stacked before the batch and by its own `strategies`, such as `2023/0001`.
+///
+/// The numbering goes on from the batches already in each of these folders of the output
+/// directory, filling the last one first.
+#[derive(Clone, Debug)]
+pub struct BatchStrategy {
+    validator: utils::BaseValidator,
+    parameters: HashMap<String, StrategyParameter>,
+    batches: HashMap<PathBuf, String>,
+}
+
+impl BatchStrategy {
+    pub fn new() -> Self {
+        let mut order_detail = validation::ParameterDetail::new(
+            ORDER,
+            StrategyParameterKind::Choice(vec![
+                NAME.to_string(),
+                DATE.to_string(),
+                SIZE.to_string(),
+            ]),
+            false,
+        );
+        order_detail.with_default_value(StrategyParameter::SingleString(NAME.to_string()));
+
+        let mut digits_detail =
+            validation::ParameterDetail::new(DIGITS, StrategyParameterKind::Number, false);
+        digits_detail.with_default_value(StrategyParameter::Number(4));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                BATCH_SIZE,
+                StrategyParameterKind::Number,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                STRATEGIES,
+                StrategyParameterKind::Strategy,
+                false,
+            ))
+            .add_validator(order_detail)
+            .add_validator(digits_detail);
+
+        let parameters = validator.default_parameters();
+
+        BatchStrategy {
+            validator,
+            parameters,
+            batches: HashMap::new(),
+        }
+    }
+
+    /// Folder given by `strategies` to the file, if any.
+    fn group(&self, file_path: &PathBuf) -> Option<String> {
+        let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get(STRATEGIES) else {
+            return None;
+        };
+        let file = std::fs::File::open(file_path).ok()?;
+
+        let mut folder = PathBuf::new();
+        strategies
+            .iter()
+            .filter_map(|strategy| strategy.apply(file_path, &file))
+            .for_each(|segment| folder.push(segment));
+
+        let folders = folder
+            .iter()
+            .map(|level| level.to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        (!folders.is_empty()).then(|| folders.join("/"))
+    }
+
+    fn sort(&self, files: &mut [PathBuf]) {
+        let order = match self.parameters.get(ORDER) {
+            Some(StrategyParameter::SingleString(order)) => order.as_str(),
+            _ => NAME,
+        };
+        let metadata = |path: &PathBuf| std::fs::metadata(path).ok();
+
+        match order {
+            DATE => files.sort_by_key(|path| {
+                let modified = metadata(path).and_then(|m| m.modified().ok());
+                (modified, path.clone())
+            }),
+            SIZE => files.sort_by_key(|path| (metadata(path).map(|m| m.len()), path.clone())),
+            _ => files.sort_by_key(|path| (path.file_name().map(|n| n.to_owned()), path.clone())),
+        }
+    }
+
+    /// Number of the last batch in `directory` and the count of files it holds, leaving out the
+    /// files being sorted again.
+    fn last_batch(directory: &Path, sorted: &HashSet<PathBuf>) -> Option<(usize, usize)> {
+        let (number, path) = std::fs::read_dir(directory)
+            .ok()?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter_map(|path| {
+                let number = path.file_name()?.to_str()?.parse::<usize>().ok()?;
+                Some((number, path))
+            })
+            .max_by_key(|(number, _)| *number)?;
+        let files = std::fs::read_dir(path)
+            .ok()?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && !sorted.contains(path))
+            .count();
+
+        Some((number, files))
+    }
+}
+
+impl strategy::Apply for BatchStrategy {
+    fn apply(&self, file_path: &std::path::PathBuf, _: &std::fs::File) -> Option<String> {
+        self.batches.get(file_path).cloned()
+    }
+}
+
+impl strategy::Name for BatchStrategy {
+    fn name(&self) -> String {
+        "batch".to_string()
+    }
+}
+
+impl strategy::AddParameter for BatchStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for BatchStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, STRATEGIES)?;
+
+        if utils::number_parameter(&self.parameters, BATCH_SIZE).unwrap_or(0) == 0 {
+            return Err(validation::error::Error::InvalidValue(
+                BATCH_SIZE.to_string(),
+                "must be at least 1".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for BatchStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for BatchStrategy {
+    fn process_context(
+        &mut self,
+        context: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut(STRATEGIES) {
+            for strategy in strategies.iter_mut() {
+                strategy.process_context(context.clone())?;
+            }
+        }
+
+        let mut groups: HashMap<(PathBuf, Option<String>), Vec<PathBuf>> = HashMap::new();
+        for file_path in context.files() {
+            groups
+                .entry((context.parent_folder(&file_path), self.group(&file_path)))
+                .or_default()
+                .push(file_path);
+        }
+        let sorted = context.files().into_iter().collect::<HashSet<_>>();
+
+        let batch_size = utils::number_parameter(&self.parameters, BATCH_SIZE)
+            .unwrap_or(0)
+            .max(1);
+        let digits = utils::number_parameter(&self.parameters, DIGITS).unwrap_or(0);
+        self.batches.clear();
+        for ((parent, group), mut files) in groups {
+            self.sort(&mut files);
+
+            let mut directory = context.output_dir().join(parent);
+            if let Some(group) = &group {
+                directory.push(group);
+            }
+            let (mut number, mut count) = Self::last_batch(&directory, &sorted).unwrap_or((1, 0));
+            for file_path in files {
+                if count >= batch_size {
+                    number += 1;
+                    count = 0;
+                }
+                count += 1;
+
+                let batch = format!("{number:0digits$}");
+                let folder = match &group {
+                    Some(group) => format!("{group}/{batch}"),
+                    None => batch,
+                };
+                self.batches.insert(file_path, folder);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for BatchStrategy {
+    fn parameters(&self) -> HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            context::ProcessContext,
+            strategy::{AddParameter, Apply, Validate},
+        },
+        sorting_strategies::catalog::all_catalog,
+    };
+    use tempdir::TempDir;
+
+    fn batch(parameters: Vec<(&str, StrategyParameter)>) -> BatchStrategy {
+        let mut strategy = BatchStrategy::new();
+        strategy.add_parameter(BATCH_SIZE.to_string(), StrategyParameter::Number(2));
+        for (key, value) in parameters {
+            strategy.add_parameter(key.to_string(), value);
+        }
+
+        strategy
+    }
+
+    fn write_files(directory: &Path, files: Vec<(&str, usize)>) -> Vec<PathBuf> {
+        files
+            .into_iter()
+            .map(|(name, size)| {
+                let path = directory.join(name);
+                std::fs::write(&path, vec![b'x'; size]).unwrap();
+                path
+            })
+            .collect()
+    }
+
+    fn batches(strategy: &mut BatchStrategy, files: &[PathBuf], output: &Path) -> Vec<String> {
+        batches_in(strategy, files, output, vec![])
+    }
+
+    fn batches_in(
+        strategy: &mut BatchStrategy,
+        files: &[PathBuf],
+        output: &Path,
+        parents: Vec<Box<dyn strategy::Strategy>>,
+    ) -> Vec<String> {
+        strategy
+            .process_context(
+                context::StrategyContext::new(files.to_vec())
+                    .with_output_dir(output.into())
+                    .with_parents(parents),
+            )
+            .unwrap();
+
+        files
+            .iter()
+            .map(|path| {
+                strategy
+                    .apply(path, &std::fs::File::open(path).unwrap())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_number_batches_in_name_order() {
+        let input = TempDir::new("batch_input").unwrap();
+        let output = TempDir::new("batch_output").unwrap();
+        let files = write_files(
+            input.path(),
+            vec![
+                ("c.txt", 1),
+                ("a.txt", 1),
+                ("e.txt", 1),
+                ("b.txt", 1),
+                ("d.txt", 1),
+            ],
+        );
+
+        assert_eq!(
+            batches(&mut batch(vec![]), &files, output.path()),
+            vec!["0002", "0001", "0003", "0001", "0002"]
+        );
+    }
+
+    #[test]
+    fn should_order_by_size_and_pad_numbers() {
+        let input = TempDir::new("batch_input").unwrap();
+        let output = TempDir::new("batch_output").unwrap();
+        let files = write_files(
+            input.path(),
+            vec![("a.txt", 30), ("b.txt", 10), ("c.txt", 20)],
+        );
+        let mut strategy = batch(vec![
+            (ORDER, StrategyParameter::SingleString(SIZE.to_string())),
+            (DIGITS, StrategyParameter::Number(2)),
+        ]);
+
+        assert_eq!(
+            batches(&mut strategy, &files, output.path()),
+            vec!["02", "01", "01"]
+        );
+    }
+
+    #[test]
+    fn should_batch_each_group_apart() {
+        let input = TempDir::new("batch_input").unwrap();
+        let output = TempDir::new("batch_output").unwrap();
+        let files = write_files(
+            input.path(),
+            vec![("a.txt", 1), ("b.jpg", 1), ("c.txt", 1), ("d.txt", 1)],
+        );
+        let mut strategy = batch(vec![(
+            STRATEGIES,
+            StrategyParameter::Strategy(vec![all_catalog()
+                .get_strategy(&"file extension".to_string())
+                .unwrap()]),
+        )]);
+
+        assert_eq!(
+            batches(&mut strategy, &files, output.path()),
+            vec!["txt/0001", "jpg/0001", "txt/0001", "txt/0002"]
+        );
+    }
+
+    #[test]
+    fn should_continue_the_existing_batches() {
+        let input = TempDir::new("batch_input").unwrap();
+        let output = TempDir::new("batch_output").unwrap();
+        std::fs::create_dir_all(output.path().join("0001")).unwrap();
+        std::fs::create_dir_all(output.path().join("0003")).unwrap();
+        std::fs::create_dir_all(output.path().join("misc")).unwrap();
+        write_files(&output.path().join("0003"), vec![("old.txt", 1)]);
+        let files = write_files(input.path(), vec![("a.txt", 1), ("b.txt", 1), ("c.txt", 1)]);
+
+        assert_eq!(
+            batches(&mut batch(vec![]), &files, output.path()),
+            vec!["0003", "0004", "0004"]
+        );
+    }
+
+    #[test]
+    fn should_batch_each_parent_folder_apart() {
+        let input = TempDir::new("batch_input").unwrap();
+        let output = TempDir::new("batch_output").unwrap();
+        std::fs::create_dir_all(output.path().join("txt").join("0001")).unwrap();
+        write_files(
+            &output.path().join("txt").join("0001"),
+            vec![("old.txt", 1)],
+        );
+        let files = write_files(input.path(), vec![("a.txt", 1), ("b.jpg", 1), ("c.txt", 1)]);
+        let parents = vec![all_catalog()
+            .get_strategy(&"file extension".to_string())
+            .unwrap()];
+
+        assert_eq!(
+            batches_in(&mut batch(vec![]), &files, output.path(), parents),
+            vec!["0001", "0001", "0002"]
+        );
+    }
+
+    #[test]
+    fn should_not_count_the_files_sorted_again() {
+        let directory = TempDir::new("batch_output").unwrap();
+        let batch_directory = directory.path().join("0001");
+        std::fs::create_dir_all(batch_directory.join("nested")).unwrap();
+        let mut files = write_files(&batch_directory, vec![("a.txt", 1), ("b.txt", 1)]);
+        files.extend(write_files(directory.path(), vec![("c.txt", 1)]));
+
+        assert_eq!(
+            batches(&mut batch(vec![]), &files, directory.path()),
+            vec!["0001", "0001", "0002"]
+        );
+    }
+
+    #[test]
+    fn should_reject_empty_batches() {
+        let strategy = batch(vec![(BATCH_SIZE, StrategyParameter::Number(0))]);
+
+        assert!(strategy.validate().is_err());
+    }
+
+    #[test]
+    fn should_validate_the_nested_strategies() {
+        let strategy = batch(vec![(
+            STRATEGIES,
+            StrategyParameter::Strategy(vec![all_catalog()
+                .get_strategy(&"text".to_string())
+                .unwrap()]),
+        )]);
+
+        assert!(strategy.validate().is_err());
+    }
+}
@@ -6,6 +6,29 @@ use crate::{
     sorting_strategies::utils,
 };
 
+const STRATEGIES: &str = "strategies";
+const SEPARATOR: &str = "separator";
+const PREFIX: &str = "prefix";
+const SUFFIX: &str = "suffix";
+const SKIP_EMPTY: &str = "skip_empty";
+const MODE: &str = "mode";
+const ON_MISSING: &str = "on_missing";
+const PLACEHOLDER: &str = "placeholder";
+
+const YES: &str = "yes";
+const NO: &str = "no";
+
+const SEGMENT: &str = "segment";
+const DIRECTORIES: &str = "directories";
+
+const SKIP: &str = "skip";
+const NONE: &str = "none";
+
+/// Glues the folders given by `strategies` together, such as `2023-05` for `year` and `month`
+/// with the `-` separator, or nests them as `2023/05` in the `directories` mode.
+///
+/// A strategy giving no folder is skipped, replaced by the `placeholder` text, or makes the whole
+/// concat give no folder according to `on_missing`.
 #[derive(Clone, Debug)]
 pub struct ConcatStrategy {
     validator: utils::BaseValidator,
@@ -14,12 +37,65 @@ pub struct ConcatStrategy {
 
 impl ConcatStrategy {
     pub fn new() -> Self {
+        let mut skip_empty_detail = validation::ParameterDetail::new(
+            SKIP_EMPTY,
+            StrategyParameterKind::Choice(vec![YES.to_string(), NO.to_string()]),
+            false,
+        );
+        skip_empty_detail.with_default_value(StrategyParameter::SingleString(YES.to_string()));
+
+        let mut mode_detail = validation::ParameterDetail::new(
+            MODE,
+            StrategyParameterKind::Choice(vec![SEGMENT.to_string(), DIRECTORIES.to_string()]),
+            false,
+        );
+        mode_detail.with_default_value(StrategyParameter::SingleString(SEGMENT.to_string()));
+
+        let mut on_missing_detail = validation::ParameterDetail::new(
+            ON_MISSING,
+            StrategyParameterKind::Choice(vec![
+                SKIP.to_string(),
+                PLACEHOLDER.to_string(),
+                NONE.to_string(),
+            ]),
+            false,
+        );
+        on_missing_detail.with_default_value(StrategyParameter::SingleString(SKIP.to_string()));
+
+        let mut placeholder_detail = validation::ParameterDetail::new(
+            PLACEHOLDER,
+            StrategyParameterKind::SingleString,
+            false,
+        );
+        placeholder_detail
+            .with_default_value(StrategyParameter::SingleString("unknown".to_string()));
+
         let mut validator = utils::BaseValidator::new();
-        validator.add_validator(validation::ParameterDetail::new(
-            "strategies",
-            StrategyParameterKind::Strategy,
-            true,
-        ));
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                STRATEGIES,
+                StrategyParameterKind::Strategy,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                SEPARATOR,
+                StrategyParameterKind::SingleString,
+                false,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                PREFIX,
+                StrategyParameterKind::SingleString,
+                false,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                SUFFIX,
+                StrategyParameterKind::SingleString,
+                false,
+            ))
+            .add_validator(skip_empty_detail)
+            .add_validator(mode_detail)
+            .add_validator(on_missing_detail)
+            .add_validator(placeholder_detail);
 
         let parameters = validator.default_parameters();
 
@@ -38,7 +114,8 @@ impl strategy::AddParameter for ConcatStrategy {
 
 impl strategy::Validate for ConcatStrategy {
     fn validate(&self) -> Result<(), crate::core::validation::error::Error> {
-        self.validator.validate(&self.parameters)
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, STRATEGIES)
     }
 }
 
@@ -56,27 +133,71 @@ impl strategy::Name for ConcatStrategy {
 
 impl strategy::Apply for ConcatStrategy {
     fn apply(&self, file_path: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
-        let mut result = String::new();
-
-        let strategies = self.parameters.get("strategies").unwrap();
-        match strategies {
-            StrategyParameter::Strategy(strategies) => {
-                strategies
-                    .iter()
-                    .filter_map(|strategy| strategy.apply(file_path, f))
-                    .for_each(|part| result.push_str(&part));
+        strategy::Apply::apply_with_rule(self, file_path, f).0
+    }
+
+    fn apply_with_rule(
+        &self,
+        file_path: &std::path::PathBuf,
+        f: &std::fs::File,
+    ) -> (Option<String>, Option<String>) {
+        let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get(STRATEGIES) else {
+            return (Some(String::new()), None);
+        };
+
+        let mut parts = vec![];
+        let mut rule = None;
+        for strategy in strategies {
+            let (part, part_rule) = strategy.apply_with_rule(file_path, f);
+            rule = rule.or(part_rule);
+            match (
+                part,
+                utils::string_parameter(&self.parameters, ON_MISSING).map_or("", String::as_str),
+            ) {
+                (Some(part), _) => parts.push(part),
+                (None, PLACEHOLDER) => parts.push(
+                    utils::string_parameter(&self.parameters, PLACEHOLDER)
+                        .map_or("", String::as_str)
+                        .to_string(),
+                ),
+                (None, NONE) => return (None, None),
+                (None, _) => (),
             }
-            _ => (),
         }
-        Some(result)
+
+        if utils::string_parameter(&self.parameters, SKIP_EMPTY).map_or("", String::as_str) != NO {
+            parts.retain(|part| !part.is_empty());
+        }
+
+        let separator = match utils::string_parameter(&self.parameters, MODE)
+            .map_or("", String::as_str)
+        {
+            DIRECTORIES => "/",
+            _ => utils::string_parameter(&self.parameters, SEPARATOR).map_or("", String::as_str),
+        };
+
+        let folder = format!(
+            "{}{}{}",
+            utils::string_parameter(&self.parameters, PREFIX).map_or("", String::as_str),
+            parts.join(separator),
+            utils::string_parameter(&self.parameters, SUFFIX).map_or("", String::as_str)
+        );
+
+        (Some(folder), rule)
     }
 }
 
 impl crate::core::context::ProcessContext for ConcatStrategy {
     fn process_context(
         &mut self,
-        _: crate::core::context::StrategyContext,
+        context: crate::core::context::StrategyContext,
     ) -> Result<(), crate::core::error::Error> {
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut(STRATEGIES) {
+            for strategy in strategies.iter_mut() {
+                strategy.process_context(context.clone())?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -86,3 +207,140 @@ impl strategy::Parameters for ConcatStrategy {
         self.parameters.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::{AddParameter, Apply, Validate},
+        sorting_strategies::catalog::all_catalog,
+    };
+    use std::path::PathBuf;
+
+    fn text(value: &str) -> Box<dyn strategy::Strategy> {
+        let mut text = all_catalog().get_strategy(&"text".to_string()).unwrap();
+        text.add_parameter(
+            "value".to_string(),
+            StrategyParameter::SingleString(value.to_string()),
+        );
+        text
+    }
+
+    fn concat(strategies: Vec<&str>, parameters: Vec<(&str, &str)>) -> ConcatStrategy {
+        let mut concat = ConcatStrategy::new();
+        let strategies = strategies
+            .into_iter()
+            .map(|name| match name {
+                "exif date" => all_catalog().get_strategy(&name.to_string()).unwrap(),
+                value => text(value),
+            })
+            .collect();
+        concat.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(strategies),
+        );
+        for (key, value) in parameters {
+            concat.add_parameter(
+                key.to_string(),
+                StrategyParameter::SingleString(value.to_string()),
+            );
+        }
+
+        concat
+    }
+
+    #[test]
+    fn should_glue_the_folders() {
+        let strategy = concat(vec!["2023", "05_May"], vec![]);
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "a.txt"),
+            Some("202305_May".to_string())
+        );
+    }
+
+    #[test]
+    fn should_forward_the_rule_of_the_strategies() {
+        let mut rule = all_catalog().get_strategy(&"rule".to_string()).unwrap();
+        rule.add_parameter(
+            "name".to_string(),
+            StrategyParameter::SingleString("images".to_string()),
+        );
+        rule.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![text("photos")]),
+        );
+        let mut strategy = ConcatStrategy::new();
+        strategy.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![text("2023_"), rule]),
+        );
+
+        assert_eq!(
+            strategy.apply_with_rule(&PathBuf::from("a.jpg"), &utils::manifest_file()),
+            (Some("2023_photos".to_string()), Some("images".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_add_separator_prefix_and_suffix() {
+        let strategy = concat(
+            vec!["2023", "", "05"],
+            vec![(SEPARATOR, "-"), (PREFIX, "["), (SUFFIX, "]")],
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "a.txt"),
+            Some("[2023-05]".to_string())
+        );
+
+        let strategy = concat(
+            vec!["2023", "", "05"],
+            vec![(SEPARATOR, "-"), (SKIP_EMPTY, NO)],
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "a.txt"),
+            Some("2023--05".to_string())
+        );
+    }
+
+    #[test]
+    fn should_nest_the_folders() {
+        let strategy = concat(
+            vec!["2023", "05"],
+            vec![(MODE, DIRECTORIES), (SEPARATOR, "-")],
+        );
+
+        assert_eq!(
+            utils::apply_to(&strategy, "a.txt"),
+            Some("2023/05".to_string())
+        );
+    }
+
+    #[test]
+    fn should_follow_the_missing_policy() {
+        let strategies = vec!["2023", "exif date"];
+
+        assert_eq!(
+            utils::apply_to(
+                &concat(strategies.clone(), vec![(SEPARATOR, "-")]),
+                "README"
+            ),
+            Some("2023".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(
+                &concat(
+                    strategies.clone(),
+                    vec![(SEPARATOR, "-"), (ON_MISSING, PLACEHOLDER)]
+                ),
+                "README"
+            ),
+            Some("2023-unknown".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&concat(strategies, vec![(ON_MISSING, NONE)]), "README"),
+            None
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::path::PathBuf;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{
+        predicate::{nested_predicates, validate_nested_predicates},
+        utils,
+    },
+};
+
+const CONDITION: &str = "condition";
+const THEN: &str = "then";
+const ELSE: &str = "else";
+
+/// Applies the `then` strategies to the files matching every predicate of the `condition`,
+/// and the `else` strategies to the others, which get no folder from it when `else` is not given.
+#[derive(Clone, Debug)]
+pub struct IfStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl IfStrategy {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                CONDITION,
+                StrategyParameterKind::Predicate,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                THEN,
+                StrategyParameterKind::Strategy,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                ELSE,
+                StrategyParameterKind::Strategy,
+                false,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        IfStrategy {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for IfStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for IfStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for IfStrategy {
+    fn validate(&self) -> Result<(), crate::core::validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, THEN)?;
+        utils::validate_nested_strategies(&self.parameters, ELSE)?;
+        validate_nested_predicates(&self.parameters, CONDITION)
+    }
+}
+
+impl strategy::ParameterDetails for IfStrategy {
+    fn parameter_details(&self) -> Vec<crate::core::validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for IfStrategy {
+    fn name(&self) -> String {
+        "if".to_string()
+    }
+}
+
+impl strategy::Apply for IfStrategy {
+    fn apply(&self, file_path: &PathBuf, f: &std::fs::File) -> Option<String> {
+        strategy::Apply::apply_with_rule(self, file_path, f).0
+    }
+
+    fn apply_with_rule(
+        &self,
+        file_path: &PathBuf,
+        f: &std::fs::File,
+    ) -> (Option<String>, Option<String>) {
+        let is_matching = nested_predicates(&self.parameters, CONDITION)
+            .iter()
+            .all(|predicate| predicate.matches(file_path, f));
+        let branch = if is_matching { THEN } else { ELSE };
+
+        let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get(branch) else {
+            return (None, None);
+        };
+
+        let mut target = PathBuf::new();
+        let mut rule = None;
+        for strategy in strategies {
+            let (segment, segment_rule) = strategy.apply_with_rule(file_path, f);
+            if let Some(segment) = segment {
+                target.push(segment);
+            }
+            rule = rule.or(segment_rule);
+        }
+
+        (Some(target.to_string_lossy().to_string()), rule)
+    }
+}
+
+impl crate::core::context::ProcessContext for IfStrategy {
+    fn process_context(
+        &mut self,
+        context: crate::core::context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        for branch in [THEN, ELSE] {
+            if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut(branch) {
+                for strategy in strategies.iter_mut() {
+                    strategy.process_context(context.clone())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::AsStrategy for IfStrategy {
+    fn as_strategy(&self) -> &dyn strategy::Strategy {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::{AddParameter, Validate},
+        sorting_strategies::{
+            catalog::manipulation_catalog::text_strategy::TextStrategy, predicate::all_predicates,
+        },
+    };
+
+    fn text(value: &str) -> StrategyParameter {
+        let mut text = TextStrategy::new();
+        text.add_parameter(
+            "value".to_string(),
+            StrategyParameter::SingleString(value.to_string()),
+        );
+
+        StrategyParameter::Strategy(vec![Box::new(text)])
+    }
+
+    fn if_pdf(with_else: bool) -> IfStrategy {
+        let mut extension = all_predicates()
+            .get_predicate(&"extension".to_string())
+            .unwrap();
+        extension.add_parameter(
+            "extensions".to_string(),
+            StrategyParameter::SingleString("pdf".to_string()),
+        );
+
+        let mut strategy = IfStrategy::new();
+        strategy.add_parameter(
+            CONDITION.to_string(),
+            StrategyParameter::Predicate(vec![extension]),
+        );
+        strategy.add_parameter(THEN.to_string(), text("documents"));
+        if with_else {
+            strategy.add_parameter(ELSE.to_string(), text("others"));
+        }
+
+        strategy
+    }
+
+    #[test]
+    fn should_pick_the_branch_matching_the_condition() {
+        let strategy = if_pdf(true);
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "a.pdf"),
+            Some("documents".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "a.png"),
+            Some("others".to_string())
+        );
+    }
+
+    #[test]
+    fn should_leave_files_out_without_else_branch() {
+        assert_eq!(utils::apply_to(&if_pdf(false), "a.png"), None);
+    }
+
+    #[test]
+    fn should_validate_the_condition() {
+        let mut strategy = if_pdf(false);
+        strategy.add_parameter(
+            CONDITION.to_string(),
+            StrategyParameter::Predicate(vec![all_predicates()
+                .get_predicate(&"name".to_string())
+                .unwrap()]),
+        );
+
+        assert!(strategy.validate().is_err());
+    }
+
+    #[test]
+    fn should_validate_both_branches() {
+        for branch in [THEN, ELSE] {
+            let mut strategy = if_pdf(true);
+            strategy.add_parameter(
+                branch.to_string(),
+                StrategyParameter::Strategy(vec![Box::new(TextStrategy::new())]),
+            );
+
+            assert!(strategy.validate().is_err(), "{branch} should be validated");
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::path::PathBuf;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const STRATEGIES: &str = "strategies";
+const TABLE: &str = "table";
+const MATCHING: &str = "match";
+const DEFAULT: &str = "default";
+
+const GLOB: &str = "glob";
+const REGEX: &str = "regex";
+
+/// Translates the folder given by `strategies` through the `table`, such as
+/// `jpg|jpeg|heic -> Photos; pdf -> Documents/PDF`. The first entry whose key matches wins.
+///
+/// Glob keys ignore the case, list alternatives with `|` and accept the `*` and `?` wildcards.
+/// Regex keys must match the whole folder and their values may use the captured groups (`$1`).
+/// Folders matching no key go to the `default` folder when given, and are kept as they are
+/// otherwise.
+#[derive(Clone, Debug)]
+pub struct MapStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+    table: Vec<(regex::Regex, String)>,
+}
+
+impl MapStrategy {
+    pub fn new() -> Self {
+        let mut matching_detail = validation::ParameterDetail::new(
+            MATCHING,
+            StrategyParameterKind::Choice(vec![GLOB.to_string(), REGEX.to_string()]),
+            false,
+        );
+        matching_detail.with_default_value(StrategyParameter::SingleString(GLOB.to_string()));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                STRATEGIES,
+                StrategyParameterKind::Strategy,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                TABLE,
+                StrategyParameterKind::Map,
+                true,
+            ))
+            .add_validator(matching_detail)
+            .add_validator(validation::ParameterDetail::new(
+                DEFAULT,
+                StrategyParameterKind::SingleString,
+                false,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        MapStrategy {
+            validator,
+            parameters,
+            table: vec![],
+        }
+    }
+
+    fn is_regex(&self) -> bool {
+        utils::string_parameter(&self.parameters, MATCHING).map(String::as_str) == Some(REGEX)
+    }
+
+    /// Keys of the table with the pattern they are compiled from.
+    fn patterns(&self) -> Vec<(String, String, String)> {
+        let Some(StrategyParameter::Map(entries)) = self.parameters.get(TABLE) else {
+            return vec![];
+        };
+
+        entries
+            .iter()
+            .map(|(key, value)| {
+                let pattern = if self.is_regex() {
+                    format!("^(?:{key})$")
+                } else {
+                    glob_pattern(key)
+                };
+                (key.clone(), pattern, value.clone())
+            })
+            .collect()
+    }
+
+    fn translate(&self, folder: &str) -> String {
+        for (pattern, value) in &self.table {
+            let Some(captures) = pattern.captures(folder) else {
+                continue;
+            };
+            if !self.is_regex() {
+                return value.clone();
+            }
+
+            let mut translated = String::new();
+            captures.expand(value, &mut translated);
+            return translated;
+        }
+
+        utils::string_parameter(&self.parameters, DEFAULT)
+            .cloned()
+            .unwrap_or(folder.to_string())
+    }
+}
+
+/// Case insensitive regex matching any of the `|` separated globs.
+fn glob_pattern(key: &str) -> String {
+    let alternatives = key
+        .split('|')
+        .map(|glob| {
+            glob.trim()
+                .chars()
+                .map(|c| match c {
+                    '*' => ".*".to_string(),
+                    '?' => ".".to_string(),
+                    c => regex::escape(&c.to_string()),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    format!("(?i)^(?:{})$", alternatives.join("|"))
+}
+
+impl strategy::Parameters for MapStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for MapStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        let compiles_table = key == TABLE || key == MATCHING;
+        self.parameters.insert(key, value);
+
+        if compiles_table {
+            self.table = self
+                .patterns()
+                .into_iter()
+                .filter_map(|(_, pattern, value)| Some((regex::Regex::new(&pattern).ok()?, value)))
+                .collect();
+        }
+    }
+}
+
+impl strategy::Validate for MapStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, STRATEGIES)?;
+
+        for (key, pattern, _) in self.patterns() {
+            utils::compile_regex(TABLE, &pattern).map_err(|err| match err {
+                validation::error::Error::InvalidValue(name, reason) => {
+                    validation::error::Error::InvalidValue(name, format!("'{key}': {reason}"))
+                }
+                err => err,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for MapStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for MapStrategy {
+    fn name(&self) -> String {
+        "map".to_string()
+    }
+}
+
+impl strategy::Apply for MapStrategy {
+    fn apply(&self, file_path: &PathBuf, f: &std::fs::File) -> Option<String> {
+        strategy::Apply::apply_with_rule(self, file_path, f).0
+    }
+
+    fn apply_with_rule(
+        &self,
+        file_path: &PathBuf,
+        f: &std::fs::File,
+    ) -> (Option<String>, Option<String>) {
+        let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get(STRATEGIES) else {
+            return (None, None);
+        };
+
+        let mut folder = PathBuf::new();
+        let mut rule = None;
+        for strategy in strategies {
+            let (segment, segment_rule) = strategy.apply_with_rule(file_path, f);
+            if let Some(segment) = segment {
+                folder.push(segment);
+            }
+            rule = rule.or(segment_rule);
+        }
+        if folder.as_os_str().is_empty() {
+            return (None, None);
+        }
+
+        (Some(self.translate(&folder.to_string_lossy())), rule)
+    }
+}
+
+impl crate::core::context::ProcessContext for MapStrategy {
+    fn process_context(
+        &mut self,
+        context: crate::core::context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut(STRATEGIES) {
+            for strategy in strategies.iter_mut() {
+                strategy.process_context(context.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            parameter::parse_map,
+            strategy::{AddParameter, Apply, Validate},
+        },
+        sorting_strategies::catalog::all_catalog,
+    };
+
+    fn map(table: &str, parameters: Vec<(&str, &str)>) -> MapStrategy {
+        let mut strategy = MapStrategy::new();
+        strategy.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![all_catalog()
+                .get_strategy(&"file extension".to_string())
+                .unwrap()]),
+        );
+        strategy.add_parameter(TABLE.to_string(), StrategyParameter::Map(parse_map(table)));
+        for (key, value) in parameters {
+            strategy.add_parameter(
+                key.to_string(),
+                StrategyParameter::SingleString(value.to_string()),
+            );
+        }
+
+        strategy
+    }
+
+    #[test]
+    fn should_translate_through_glob_keys() {
+        let strategy = map(
+            "jpg|jpeg|heic -> Photos; pdf -> Documents/PDF; tar.* -> Archives",
+            vec![],
+        );
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "a.JPEG"),
+            Some("Photos".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "a.pdf"),
+            Some("Documents/PDF".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "a.tar.gz"),
+            Some("Archives".to_string())
+        );
+        assert_eq!(utils::apply_to(&strategy, "a.txt"), Some("txt".to_string()));
+        assert_eq!(
+            utils::apply_to(&map("pdf -> Documents", vec![(DEFAULT, "Misc")]), "a.txt"),
+            Some("Misc".to_string())
+        );
+    }
+
+    #[test]
+    fn should_expand_regex_captures() {
+        let strategy = map(
+            r"(mp[34]) -> Media/$1; \w+ -> Other",
+            vec![(MATCHING, REGEX)],
+        );
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "a.mp4"),
+            Some("Media/mp4".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "a.mp45"),
+            Some("Other".to_string())
+        );
+    }
+
+    #[test]
+    fn should_forward_the_rule_of_the_strategies() {
+        let mut rule = all_catalog().get_strategy(&"rule".to_string()).unwrap();
+        rule.add_parameter(
+            "name".to_string(),
+            StrategyParameter::SingleString("images".to_string()),
+        );
+        rule.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![all_catalog()
+                .get_strategy(&"file extension".to_string())
+                .unwrap()]),
+        );
+        let mut strategy = map("jpg -> Photos", vec![]);
+        strategy.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![rule]),
+        );
+
+        assert_eq!(
+            strategy.apply_with_rule(&PathBuf::from("a.jpg"), &utils::manifest_file()),
+            (Some("Photos".to_string()), Some("images".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_tables() {
+        assert!(map("pdf", vec![]).validate().is_err());
+        assert!(map("(pdf -> Documents", vec![(MATCHING, REGEX)])
+            .validate()
+            .is_err());
+
+        let mut regex = all_catalog().get_strategy(&"regex".to_string()).unwrap();
+        regex.add_parameter(
+            "pattern".to_string(),
+            StrategyParameter::SingleString("ab(c".to_string()),
+        );
+        let mut strategy = map("pdf -> Documents", vec![]);
+        strategy.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![regex]),
+        );
+        assert!(strategy.validate().is_err());
+    }
+}
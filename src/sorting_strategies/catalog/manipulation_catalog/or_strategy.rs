@@ -45,7 +45,8 @@ impl strategy::AddParameter for OrStrategy {
 
 impl strategy::Validate for OrStrategy {
     fn validate(&self) -> Result<(), crate::core::validation::error::Error> {
-        self.validator.validate(&self.parameters)
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, "strategies")
     }
 }
 
@@ -69,29 +70,41 @@ impl strategy::Name for OrStrategy {
 
 impl strategy::Apply for OrStrategy {
     fn apply(&self, file_path: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
-        let strategies = self
-            .parameters
-            .get("strategies")
-            .and_then(|param| match param {
-                StrategyParameter::Strategy(list) => Some(list),
-                _ => None,
-            })?;
+        strategy::Apply::apply_with_rule(self, file_path, f).0
+    }
+
+    fn apply_with_rule(
+        &self,
+        file_path: &std::path::PathBuf,
+        f: &std::fs::File,
+    ) -> (Option<String>, Option<String>) {
+        let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get("strategies")
+        else {
+            return (None, None);
+        };
 
         for strategy in strategies {
-            if let Some(result) = strategy.apply(file_path, f) {
-                return Some(result);
+            if let (Some(result), rule) = strategy.apply_with_rule(file_path, f) {
+                return (Some(result), rule);
             }
         }
 
-        None
+        (None, None)
     }
 }
 
 impl context::ProcessContext for OrStrategy {
     fn process_context(
         &mut self,
-        _: context::StrategyContext,
+        context: context::StrategyContext,
     ) -> Result<(), crate::core::error::Error> {
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut("strategies")
+        {
+            for strategy in strategies.iter_mut() {
+                strategy.process_context(context.clone())?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -100,8 +113,13 @@ impl context::ProcessContext for OrStrategy {
 mod tests {
     use super::*;
     use crate::{
-        core::strategy::Strategy,
-        sorting_strategies::catalog::manipulation_catalog::text_strategy::TextStrategy,
+        core::{
+            context::ProcessContext,
+            strategy::{AddParameter, Strategy},
+        },
+        sorting_strategies::catalog::{
+            all_catalog, manipulation_catalog::text_strategy::TextStrategy,
+        },
     };
 
     #[derive(Clone, Debug)]
@@ -190,10 +208,9 @@ mod tests {
             ]),
         );
 
-        let result = or_strategy.as_apply().apply(
-            &std::path::PathBuf::new(),
-            &std::fs::File::open("Cargo.toml").unwrap(),
-        );
+        let result = or_strategy
+            .as_apply()
+            .apply(&std::path::PathBuf::new(), &utils::manifest_file());
         assert_eq!(result, Some("first".to_string()));
     }
 
@@ -209,10 +226,35 @@ mod tests {
             ]),
         );
 
+        let result = or_strategy
+            .as_apply()
+            .apply(&std::path::PathBuf::new(), &utils::manifest_file());
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn should_forward_the_context_to_the_strategies() {
+        let mut or_strategy = OrStrategy::new();
+        or_strategy.add_parameter(
+            "strategies".to_string(),
+            StrategyParameter::Strategy(vec![
+                Box::new(NoneStrategy),
+                all_catalog()
+                    .get_strategy(&"relative path".to_string())
+                    .unwrap(),
+            ]),
+        );
+        or_strategy
+            .process_context(
+                context::StrategyContext::new(vec![])
+                    .with_input_dir(std::path::PathBuf::from("input")),
+            )
+            .unwrap();
+
         let result = or_strategy.as_apply().apply(
-            &std::path::PathBuf::new(),
-            &std::fs::File::open("Cargo.toml").unwrap(),
+            &std::path::PathBuf::from("input/photos/a.jpg"),
+            &utils::manifest_file(),
         );
-        assert_eq!(result, None);
+        assert_eq!(result, Some("photos".to_string()));
     }
 }
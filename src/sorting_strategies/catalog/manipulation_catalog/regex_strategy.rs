@@ -0,0 +1,254 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const PATTERN: &str = "pattern";
+const TARGET: &str = "target";
+const TEMPLATE: &str = "template";
+
+const FILE_NAME: &str = "file name";
+const STEM: &str = "stem";
+const RELATIVE_PATH: &str = "relative path";
+
+/// Matches `pattern` against a part of the file path and expands `template` with the captured
+/// groups (`$1`, `$client` or `${client}`), the whole match by default.
+/// Files that don't match are left to the next strategy of an `or`.
+#[derive(Clone, Debug)]
+pub struct RegexStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+    pattern: Option<regex::Regex>,
+    /// Directory the files are sorted from, which relative paths are computed from.
+    input_dir: PathBuf,
+}
+
+impl RegexStrategy {
+    pub fn new() -> Self {
+        let mut target_detail = validation::ParameterDetail::new(
+            TARGET,
+            StrategyParameterKind::Choice(vec![
+                FILE_NAME.to_string(),
+                STEM.to_string(),
+                RELATIVE_PATH.to_string(),
+            ]),
+            false,
+        );
+        target_detail.with_default_value(StrategyParameter::SingleString(FILE_NAME.to_string()));
+
+        let mut template_detail =
+            validation::ParameterDetail::new(TEMPLATE, StrategyParameterKind::SingleString, false);
+        template_detail.with_default_value(StrategyParameter::SingleString("$0".to_string()));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                PATTERN,
+                StrategyParameterKind::SingleString,
+                true,
+            ))
+            .add_validator(target_detail)
+            .add_validator(template_detail);
+
+        let parameters = validator.default_parameters();
+
+        RegexStrategy {
+            validator,
+            parameters,
+            pattern: None,
+            input_dir: PathBuf::new(),
+        }
+    }
+
+    fn target(&self, file_path: &Path) -> Option<String> {
+        match utils::string_parameter(&self.parameters, TARGET).map(String::as_str) {
+            Some(STEM) => file_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string()),
+            Some(RELATIVE_PATH) => {
+                let relative = file_path.strip_prefix(&self.input_dir).unwrap_or(file_path);
+                Some(
+                    relative
+                        .components()
+                        .map(|component| component.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                )
+            }
+            _ => file_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string()),
+        }
+    }
+}
+
+impl strategy::Parameters for RegexStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for RegexStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        if key == PATTERN {
+            self.pattern = match &value {
+                StrategyParameter::SingleString(pattern) => regex::Regex::new(pattern).ok(),
+                _ => None,
+            };
+        }
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for RegexStrategy {
+    fn validate(&self) -> Result<(), crate::core::validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        if let Some(pattern) = utils::string_parameter(&self.parameters, PATTERN) {
+            utils::compile_regex(PATTERN, pattern)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for RegexStrategy {
+    fn parameter_details(&self) -> Vec<crate::core::validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for RegexStrategy {
+    fn name(&self) -> String {
+        "regex".to_string()
+    }
+}
+
+impl strategy::Apply for RegexStrategy {
+    fn apply(&self, file_path: &PathBuf, _: &std::fs::File) -> Option<String> {
+        let pattern = self.pattern.as_ref()?;
+        let target = self.target(file_path)?;
+        let captures = pattern.captures(&target)?;
+
+        let mut segment = String::new();
+        captures.expand(
+            utils::string_parameter(&self.parameters, TEMPLATE)?,
+            &mut segment,
+        );
+
+        (!segment.is_empty()).then_some(segment)
+    }
+}
+
+impl crate::core::context::ProcessContext for RegexStrategy {
+    fn process_context(
+        &mut self,
+        context: crate::core::context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        self.input_dir = context.input_dir();
+
+        Ok(())
+    }
+}
+
+impl strategy::AsStrategy for RegexStrategy {
+    fn as_strategy(&self) -> &dyn strategy::Strategy {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            context::{ProcessContext, StrategyContext},
+            strategy::Validate,
+        },
+        sorting_strategies::catalog::all_catalog,
+    };
+
+    #[test]
+    fn should_expand_named_and_numbered_groups() {
+        let strategy = utils::with_parameters(
+            RegexStrategy::new(),
+            vec![
+                (PATTERN, r"^(?P<client>[a-z]+)_(\d{4})"),
+                (TEMPLATE, "$client/$2"),
+            ],
+        );
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "/in/acme_2023_invoice.pdf"),
+            Some("acme/2023".to_string())
+        );
+        assert_eq!(utils::apply_to(&strategy, "/in/invoice.pdf"), None);
+    }
+
+    #[test]
+    fn should_match_the_selected_target() {
+        let stem = utils::with_parameters(
+            RegexStrategy::new(),
+            vec![(PATTERN, r"\w+$"), (TARGET, STEM)],
+        );
+        assert_eq!(
+            utils::apply_to(&stem, "/in/report.pdf"),
+            Some("report".to_string())
+        );
+
+        let mut relative = utils::with_parameters(
+            RegexStrategy::new(),
+            vec![(PATTERN, r"^[^/]+"), (TARGET, RELATIVE_PATH)],
+        );
+        relative
+            .process_context(
+                StrategyContext::new(vec![PathBuf::from("/in/clients/a.pdf")])
+                    .with_input_dir(PathBuf::from("/in")),
+            )
+            .unwrap();
+        assert_eq!(
+            utils::apply_to(&relative, "/in/clients/a.pdf"),
+            Some("clients".to_string())
+        );
+    }
+
+    #[test]
+    fn should_report_the_position_of_syntax_errors() {
+        let strategy = utils::with_parameters(RegexStrategy::new(), vec![(PATTERN, "ab(c")]);
+
+        assert!(matches!(
+            strategy.validate(),
+            Err(validation::error::Error::InvalidValue(name, reason))
+                if name == PATTERN && reason == "unclosed group at position 3"
+        ));
+    }
+
+    #[test]
+    fn should_report_syntax_errors_of_nested_patterns() {
+        for wrapper in ["or", "concat"] {
+            let mut strategy = all_catalog().get_strategy(&wrapper.to_string()).unwrap();
+            strategy.add_parameter(
+                "strategies".to_string(),
+                StrategyParameter::Strategy(vec![Box::new(utils::with_parameters(
+                    RegexStrategy::new(),
+                    vec![(PATTERN, "ab(c")],
+                ))]),
+            );
+
+            assert!(
+                matches!(
+                    strategy.validate(),
+                    Err(validation::error::Error::InvalidValue(name, reason))
+                        if name == PATTERN && reason == "unclosed group at position 3"
+                ),
+                "{wrapper} should validate its patterns"
+            );
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::path::PathBuf;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{
+        predicate::{nested_predicates, validate_nested_predicates},
+        utils,
+    },
+};
+
+const NAME: &str = "name";
+const STRATEGIES: &str = "strategies";
+const CONDITION: &str = "condition";
+
+/// Routes the files matching every predicate of the `condition` to its own stack of strategies.
+/// A rule without condition matches every file, which makes it the default rule of an `or`.
+#[derive(Clone, Debug)]
+pub struct RuleStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl RuleStrategy {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                NAME,
+                StrategyParameterKind::SingleString,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                STRATEGIES,
+                StrategyParameterKind::Strategy,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                CONDITION,
+                StrategyParameterKind::Predicate,
+                false,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        RuleStrategy {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for RuleStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for RuleStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for RuleStrategy {
+    fn validate(&self) -> Result<(), crate::core::validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, STRATEGIES)?;
+        validate_nested_predicates(&self.parameters, CONDITION)
+    }
+}
+
+impl strategy::ParameterDetails for RuleStrategy {
+    fn parameter_details(&self) -> Vec<crate::core::validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for RuleStrategy {
+    fn name(&self) -> String {
+        "rule".to_string()
+    }
+}
+
+impl strategy::Apply for RuleStrategy {
+    fn apply(&self, file_path: &PathBuf, f: &std::fs::File) -> Option<String> {
+        strategy::Apply::apply_with_rule(self, file_path, f).0
+    }
+
+    fn apply_with_rule(
+        &self,
+        file_path: &PathBuf,
+        f: &std::fs::File,
+    ) -> (Option<String>, Option<String>) {
+        let is_matching = nested_predicates(&self.parameters, CONDITION)
+            .iter()
+            .all(|predicate| predicate.matches(file_path, f));
+        if !is_matching {
+            return (None, None);
+        }
+
+        let mut target = PathBuf::new();
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get(STRATEGIES) {
+            strategies
+                .iter()
+                .filter_map(|strategy| strategy.apply(file_path, f))
+                .for_each(|segment| target.push(segment));
+        }
+
+        let name = match self.parameters.get(NAME) {
+            Some(StrategyParameter::SingleString(name)) => Some(name.clone()),
+            _ => None,
+        };
+
+        (Some(target.to_string_lossy().to_string()), name)
+    }
+}
+
+impl crate::core::context::ProcessContext for RuleStrategy {
+    fn process_context(
+        &mut self,
+        context: crate::core::context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut(STRATEGIES) {
+            for strategy in strategies.iter_mut() {
+                strategy.process_context(context.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::AsStrategy for RuleStrategy {
+    fn as_strategy(&self) -> &dyn strategy::Strategy {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            predicate::Predicate,
+            strategy::{AddParameter, Apply, Validate},
+        },
+        sorting_strategies::{
+            catalog::manipulation_catalog::text_strategy::TextStrategy, predicate::all_predicates,
+        },
+    };
+
+    fn predicate(name: &str, parameters: Vec<(&str, StrategyParameter)>) -> Box<dyn Predicate> {
+        let mut predicate = all_predicates().get_predicate(&name.to_string()).unwrap();
+        for (key, value) in parameters {
+            predicate.add_parameter(key.to_string(), value);
+        }
+
+        predicate
+    }
+
+    fn rule(name: &str, condition: Vec<Box<dyn Predicate>>) -> RuleStrategy {
+        let mut text = TextStrategy::new();
+        text.add_parameter(
+            "value".to_string(),
+            StrategyParameter::SingleString(name.to_string()),
+        );
+
+        let mut rule = RuleStrategy::new();
+        rule.add_parameter(
+            NAME.to_string(),
+            StrategyParameter::SingleString(name.to_string()),
+        );
+        rule.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![Box::new(text.clone()), Box::new(text)]),
+        );
+        if !condition.is_empty() {
+            rule.add_parameter(
+                CONDITION.to_string(),
+                StrategyParameter::Predicate(condition),
+            );
+        }
+
+        rule
+    }
+
+    fn apply(rule: &RuleStrategy, file_name: &str) -> (Option<String>, Option<String>) {
+        rule.apply_with_rule(&PathBuf::from(file_name), &utils::manifest_file())
+    }
+
+    #[test]
+    fn should_match_every_file_without_condition() {
+        let rule = rule("misc", vec![]);
+        assert_eq!(
+            apply(&rule, "a.bin"),
+            (
+                Some(PathBuf::from("misc").join("misc").display().to_string()),
+                Some("misc".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn should_match_every_predicate_of_the_condition() {
+        let rule = rule(
+            "images",
+            vec![
+                predicate(
+                    "extension",
+                    vec![(
+                        "extensions",
+                        StrategyParameter::SingleString("jpg, .PNG".to_string()),
+                    )],
+                ),
+                predicate(
+                    "name",
+                    vec![(
+                        "pattern",
+                        StrategyParameter::SingleString("^photo".to_string()),
+                    )],
+                ),
+            ],
+        );
+
+        assert!(rule.validate().is_ok());
+        assert_eq!(apply(&rule, "photo.png").1, Some("images".to_string()));
+        assert_eq!(apply(&rule, "photo.gif"), (None, None));
+        assert_eq!(apply(&rule, "scan.png"), (None, None));
+    }
+
+    #[test]
+    fn should_reject_invalid_condition() {
+        let rule = rule("broken", vec![predicate("extension", vec![])]);
+
+        assert!(rule.validate().is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_nested_strategies() {
+        let mut rule = rule("misc", vec![]);
+        rule.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![Box::new(TextStrategy::new())]),
+        );
+
+        assert!(rule.validate().is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use std::path::PathBuf;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{template::Template, utils},
+};
+
+const TEMPLATE: &str = "template";
+
+/// Names the folders after a [`Template`] such as `{year}/{month:%m}/{ext}`, whose placeholders
+/// are the values of other strategies. Each `/` of the template opens a new folder level.
+#[derive(Clone, Debug)]
+pub struct TemplateStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+    template: Option<Template>,
+}
+
+impl TemplateStrategy {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            TEMPLATE,
+            StrategyParameterKind::SingleString,
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        TemplateStrategy {
+            validator,
+            parameters,
+            template: None,
+        }
+    }
+
+    fn parse(&self) -> Option<Result<Template, String>> {
+        match self.parameters.get(TEMPLATE) {
+            Some(StrategyParameter::SingleString(template)) => Some(Template::parse(template)),
+            _ => None,
+        }
+    }
+}
+
+impl strategy::Parameters for TemplateStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for TemplateStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        let is_template = key == TEMPLATE;
+        self.parameters.insert(key, value);
+
+        if is_template {
+            self.template = self.parse().and_then(Result::ok);
+        }
+    }
+}
+
+impl strategy::Validate for TemplateStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        if let Some(Err(reason)) = self.parse() {
+            return Err(validation::error::Error::InvalidValue(
+                TEMPLATE.to_string(),
+                reason,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for TemplateStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for TemplateStrategy {
+    fn name(&self) -> String {
+        "template".to_string()
+    }
+}
+
+impl strategy::Apply for TemplateStrategy {
+    fn apply(&self, file_path: &PathBuf, f: &std::fs::File) -> Option<String> {
+        self.template.as_ref()?.render(file_path, f)
+    }
+}
+
+impl crate::core::context::ProcessContext for TemplateStrategy {
+    fn process_context(
+        &mut self,
+        context: crate::core::context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        match self.template.as_mut() {
+            Some(template) => template.process_context(context),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::{AddParameter, Apply, Validate},
+        sorting_strategies::catalog::all_catalog,
+    };
+
+    fn template(template: &str) -> TemplateStrategy {
+        let mut strategy = TemplateStrategy::new();
+        strategy.add_parameter(
+            TEMPLATE.to_string(),
+            StrategyParameter::SingleString(template.to_string()),
+        );
+
+        strategy
+    }
+
+    #[test]
+    fn should_create_a_folder_per_level() {
+        let strategy = template("{type}/{ext|upper}");
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            strategy.apply(&PathBuf::from("notes.md"), &utils::manifest_file()),
+            Some("document/MD".to_string())
+        );
+    }
+
+    #[test]
+    fn should_report_template_errors_on_validation() {
+        assert_eq!(
+            template("{year}/{month").validate(),
+            Err(validation::error::Error::InvalidValue(
+                TEMPLATE.to_string(),
+                "unclosed placeholder at position 8".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn should_report_template_errors_of_nested_templates() {
+        for wrapper in ["or", "concat", "map"] {
+            let mut strategy = all_catalog().get_strategy(&wrapper.to_string()).unwrap();
+            strategy.add_parameter(
+                "strategies".to_string(),
+                StrategyParameter::Strategy(vec![Box::new(template("{year}/{month"))]),
+            );
+            if wrapper == "map" {
+                strategy.add_parameter(
+                    "table".to_string(),
+                    StrategyParameter::Map(vec![("*".to_string(), "Other".to_string())]),
+                );
+            }
+
+            assert!(
+                strategy.validate().is_err_and(|err| err
+                    == validation::error::Error::InvalidValue(
+                        TEMPLATE.to_string(),
+                        "unclosed placeholder at position 8".to_string()
+                    )),
+                "{wrapper} should validate its templates"
+            );
+        }
+    }
+}
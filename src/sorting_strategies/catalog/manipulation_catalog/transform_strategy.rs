@@ -0,0 +1,258 @@
+use std::path::PathBuf;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{text_transform::Operation, utils},
+};
+
+const STRATEGIES: &str = "strategies";
+const OPERATIONS: &str = "operations";
+
+/// Normalises the folders given by `strategies` with a chain of [`Operation`], such as
+/// `replace:_: ` then `title` to turn `05_mai` into `05 Mai`. Each folder level is transformed on
+/// its own, and the levels left empty are dropped.
+#[derive(Clone, Debug)]
+pub struct TransformStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+    operations: Vec<Operation>,
+}
+
+impl TransformStrategy {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                STRATEGIES,
+                StrategyParameterKind::Strategy,
+                true,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                OPERATIONS,
+                StrategyParameterKind::List,
+                true,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        TransformStrategy {
+            validator,
+            parameters,
+            operations: vec![],
+        }
+    }
+
+    fn parse_operations(&self) -> Result<Vec<Operation>, String> {
+        match self.parameters.get(OPERATIONS) {
+            Some(StrategyParameter::List(operations)) => operations
+                .iter()
+                .map(|operation| Operation::parse(operation))
+                .collect(),
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn transform(&self, folder: &str) -> String {
+        self.operations
+            .iter()
+            .fold(folder.to_string(), |folder, operation| {
+                operation.apply(&folder)
+            })
+    }
+}
+
+impl strategy::Parameters for TransformStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for TransformStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        let is_operations = key == OPERATIONS;
+        self.parameters.insert(key, value);
+
+        if is_operations {
+            self.operations = self.parse_operations().unwrap_or_default();
+        }
+    }
+}
+
+impl strategy::Validate for TransformStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, STRATEGIES)?;
+
+        self.parse_operations().map(|_| ()).map_err(|reason| {
+            validation::error::Error::InvalidValue(OPERATIONS.to_string(), reason)
+        })
+    }
+}
+
+impl strategy::ParameterDetails for TransformStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for TransformStrategy {
+    fn name(&self) -> String {
+        "transform".to_string()
+    }
+}
+
+impl strategy::Apply for TransformStrategy {
+    fn apply(&self, file_path: &PathBuf, f: &std::fs::File) -> Option<String> {
+        strategy::Apply::apply_with_rule(self, file_path, f).0
+    }
+
+    fn apply_with_rule(
+        &self,
+        file_path: &PathBuf,
+        f: &std::fs::File,
+    ) -> (Option<String>, Option<String>) {
+        let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get(STRATEGIES) else {
+            return (None, None);
+        };
+
+        let mut folder = PathBuf::new();
+        let mut rule = None;
+        for strategy in strategies {
+            let (segment, segment_rule) = strategy.apply_with_rule(file_path, f);
+            if let Some(segment) = segment {
+                folder.push(segment);
+            }
+            rule = rule.or(segment_rule);
+        }
+
+        let folders = folder
+            .iter()
+            .map(|level| self.transform(&level.to_string_lossy()))
+            .filter(|level| !level.trim().is_empty())
+            .collect::<Vec<_>>();
+
+        if folders.is_empty() {
+            return (None, None);
+        }
+
+        (Some(folders.join("/")), rule)
+    }
+}
+
+impl crate::core::context::ProcessContext for TransformStrategy {
+    fn process_context(
+        &mut self,
+        context: crate::core::context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut(STRATEGIES) {
+            for strategy in strategies.iter_mut() {
+                strategy.process_context(context.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::{AddParameter, Apply, Validate},
+        sorting_strategies::catalog::all_catalog,
+    };
+
+    fn transform(strategy: &str, operations: Vec<&str>) -> TransformStrategy {
+        let mut transform = TransformStrategy::new();
+        transform.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![all_catalog()
+                .get_strategy(&strategy.to_string())
+                .unwrap()]),
+        );
+        transform.add_parameter(
+            OPERATIONS.to_string(),
+            StrategyParameter::List(operations.into_iter().map(str::to_string).collect()),
+        );
+
+        transform
+    }
+
+    #[test]
+    fn should_chain_operations() {
+        let strategy = transform("file extension", vec!["lower", "replace:.:-"]);
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "backup.TAR.GZ"),
+            Some("tar-gz".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "photo.JPG"),
+            Some("jpg".to_string())
+        );
+    }
+
+    #[test]
+    fn should_transform_each_level() {
+        let mut text = all_catalog().get_strategy(&"text".to_string()).unwrap();
+        text.add_parameter(
+            "value".to_string(),
+            StrategyParameter::SingleString("05_mai/Été 2024/__".to_string()),
+        );
+        let mut strategy = transform("file extension", vec!["strip digits", "trim:_ ", "title"]);
+        strategy.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![text]),
+        );
+
+        assert_eq!(
+            utils::apply_to(&strategy, "a.txt"),
+            Some("Mai/Été".to_string())
+        );
+    }
+
+    #[test]
+    fn should_forward_the_rule_of_the_strategies() {
+        let mut rule = all_catalog().get_strategy(&"rule".to_string()).unwrap();
+        rule.add_parameter(
+            "name".to_string(),
+            StrategyParameter::SingleString("images".to_string()),
+        );
+        rule.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![all_catalog()
+                .get_strategy(&"file extension".to_string())
+                .unwrap()]),
+        );
+        let mut strategy = transform("file extension", vec!["upper"]);
+        strategy.add_parameter(
+            STRATEGIES.to_string(),
+            StrategyParameter::Strategy(vec![rule]),
+        );
+
+        assert_eq!(
+            strategy.apply_with_rule(&PathBuf::from("a.jpg"), &utils::manifest_file()),
+            (Some("JPG".to_string()), Some("images".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_operations() {
+        assert_eq!(
+            transform("file extension", vec!["lower", "shout"]).validate(),
+            Err(validation::error::Error::InvalidValue(
+                OPERATIONS.to_string(),
+                "unknown operation 'shout'".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn should_validate_the_nested_strategies() {
+        assert!(transform("text", vec!["lower"]).validate().is_err());
+    }
+}
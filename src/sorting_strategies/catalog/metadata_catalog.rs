@@ -1,14 +1,34 @@
 use super::strategy_catalog::StrategyCatalog;
+mod camera_strategy;
+mod date_strategy;
+mod document_metadata_strategy;
+mod exif_date_strategy;
 mod file_ext_strategy;
 mod file_type_strategy;
-mod month_strategy;
-mod year_strategy;
+mod filename_date_strategy;
+mod letter_strategy;
+mod mime_type_strategy;
+mod music_tag_strategy;
+mod relative_path_strategy;
+mod shard_strategy;
+mod size_strategy;
 
 pub fn get_metadata_catalog() -> StrategyCatalog {
     StrategyCatalog::new(vec![
-        Box::new(month_strategy::MonthStrategy::new()),
-        Box::new(year_strategy::YearStrategy::new()),
+        Box::new(date_strategy::DateStrategy::month()),
+        Box::new(date_strategy::DateStrategy::year()),
+        Box::new(date_strategy::DateStrategy::new()),
         Box::new(file_ext_strategy::FileExtStrategy::new()),
         Box::new(file_type_strategy::FileTypeStrategy::new()),
+        Box::new(mime_type_strategy::MimeTypeStrategy::new()),
+        Box::new(filename_date_strategy::FilenameDateStrategy::new()),
+        Box::new(exif_date_strategy::ExifDateStrategy::new()),
+        Box::new(camera_strategy::CameraStrategy::new()),
+        Box::new(music_tag_strategy::MusicTagStrategy::new()),
+        Box::new(document_metadata_strategy::DocumentMetadataStrategy::new()),
+        Box::new(size_strategy::SizeStrategy::new()),
+        Box::new(relative_path_strategy::RelativePathStrategy::new()),
+        Box::new(letter_strategy::LetterStrategy::new()),
+        Box::new(shard_strategy::ShardStrategy::new()),
     ])
 }
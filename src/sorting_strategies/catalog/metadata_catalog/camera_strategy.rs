@@ -0,0 +1,268 @@
+use crate::{
+    core::{
+        context,
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{
+        file::exif::{Exif, LENS_MODEL_TAG, MAKE_TAG, MODEL_TAG},
+        utils,
+    },
+};
+
+const FIELD: &str = "field";
+const MAPPING: &str = "mapping";
+
+const MAKE: &str = "make";
+const MODEL: &str = "model";
+const MAKE_MODEL: &str = "make_model";
+const LENS: &str = "lens";
+
+/// Spellings of the vendors in the EXIF `Make` tag, by lowercase prefix.
+const VENDORS: [(&str, &str); 19] = [
+    ("apple", "Apple"),
+    ("canon", "Canon"),
+    ("dji", "DJI"),
+    ("eastman kodak", "Kodak"),
+    ("fujifilm", "Fujifilm"),
+    ("google", "Google"),
+    ("gopro", "GoPro"),
+    ("hasselblad", "Hasselblad"),
+    ("huawei", "Huawei"),
+    ("leica", "Leica"),
+    ("lg electronics", "LG"),
+    ("nikon", "Nikon"),
+    ("olympus", "Olympus"),
+    ("om digital", "OM System"),
+    ("panasonic", "Panasonic"),
+    ("pentax", "Pentax"),
+    ("ricoh", "Ricoh"),
+    ("samsung", "Samsung"),
+    ("sony", "Sony"),
+];
+
+/// Names the folder after the camera that took the photo, read from its EXIF tags.
+///
+/// The vendor is spelled the same way whatever the body (`NIKON CORPORATION` gives `Nikon`), and
+/// the model drops the vendor it often repeats (`Canon EOS R5` gives `EOS R5`). The `mapping`
+/// gives the folder of some names, such as `EOS R5 -> DSLR; iPhone* -> Phone`, so that several
+/// bodies share a folder; a trailing `*` matches any name starting with what precedes it.
+/// Files without EXIF data or without the chosen `field` give no folder.
+#[derive(Clone, Debug)]
+pub struct CameraStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl CameraStrategy {
+    pub fn new() -> Self {
+        let mut field_detail = validation::ParameterDetail::new(
+            FIELD,
+            StrategyParameterKind::Choice(
+                [MAKE, MODEL, MAKE_MODEL, LENS]
+                    .iter()
+                    .map(|field| field.to_string())
+                    .collect(),
+            ),
+            false,
+        );
+        field_detail.with_default_value(StrategyParameter::SingleString(MAKE_MODEL.to_string()));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(field_detail)
+            .add_validator(validation::ParameterDetail::new(
+                MAPPING,
+                StrategyParameterKind::Map,
+                false,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        CameraStrategy {
+            validator,
+            parameters,
+        }
+    }
+
+    fn field_value(&self, exif: &Exif) -> Option<String> {
+        let make = exif.get(MAKE_TAG).map(normalise_make);
+        let model = || {
+            let model = collapse_whitespace(exif.get(MODEL_TAG)?);
+            Some(strip_vendor(&model, exif.get(MAKE_TAG).unwrap_or_default()))
+        };
+
+        match utils::string_parameter(&self.parameters, FIELD).map(String::as_str) {
+            Some(MAKE) => make,
+            Some(MODEL) => model(),
+            Some(LENS) => Some(collapse_whitespace(exif.get(LENS_MODEL_TAG)?)),
+            _ => match (make, model()) {
+                (Some(make), Some(model)) => Some(format!("{make} {model}")),
+                (make, model) => make.or(model),
+            },
+        }
+    }
+
+    fn map(&self, value: String) -> String {
+        let Some(StrategyParameter::Map(mapping)) = self.parameters.get(MAPPING) else {
+            return value;
+        };
+
+        mapping
+            .iter()
+            .find(|(name, _)| {
+                let name = name.to_lowercase();
+                let value = value.to_lowercase();
+                match name.strip_suffix('*') {
+                    Some(prefix) => value.starts_with(prefix),
+                    None => value == name,
+                }
+            })
+            .map(|(_, folder)| folder.to_string())
+            .unwrap_or(value)
+    }
+}
+
+fn collapse_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn normalise_make(make: &str) -> String {
+    let make = collapse_whitespace(make);
+    let lowercase = make.to_lowercase();
+
+    VENDORS
+        .iter()
+        .find(|(prefix, _)| lowercase.starts_with(prefix))
+        .map(|(_, vendor)| vendor.to_string())
+        .unwrap_or(make)
+}
+
+/// Model without the first word of the vendor, which many bodies repeat.
+fn strip_vendor(model: &str, make: &str) -> String {
+    let vendor = make.split_whitespace().next().unwrap_or_default();
+    match model.split_once(' ') {
+        Some((first, rest)) if !vendor.is_empty() && first.eq_ignore_ascii_case(vendor) => {
+            rest.to_string()
+        }
+        _ => model.to_string(),
+    }
+}
+
+impl strategy::Apply for CameraStrategy {
+    fn apply(&self, _: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let value = self.field_value(&Exif::read(f)?)?;
+
+        Some(utils::folder_name(&self.map(value)))
+    }
+}
+
+impl strategy::Name for CameraStrategy {
+    fn name(&self) -> String {
+        "camera".to_string()
+    }
+}
+
+impl strategy::AddParameter for CameraStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for CameraStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)
+    }
+}
+
+impl strategy::ParameterDetails for CameraStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for CameraStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for CameraStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            parameter::parse_map,
+            strategy::{AddParameter, Validate},
+        },
+        sorting_strategies::file::exif::tests::tiff,
+    };
+    use std::io::Cursor;
+
+    fn exif(make: &str, model: &str) -> Exif {
+        Exif::read(Cursor::new(tiff(&[
+            (MAKE_TAG, make),
+            (MODEL_TAG, model),
+            (LENS_MODEL_TAG, "EF24-70mm  f/2.8L"),
+        ])))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_normalise_vendor_spellings() {
+        let strategy = CameraStrategy::new();
+
+        assert_eq!(
+            strategy.field_value(&exif("NIKON CORPORATION", "NIKON D750")),
+            Some("Nikon D750".to_string())
+        );
+        assert_eq!(
+            strategy.field_value(&exif("Canon", "Canon EOS R5")),
+            Some("Canon EOS R5".to_string())
+        );
+        assert_eq!(
+            utils::with_parameters(CameraStrategy::new(), vec![(FIELD, MAKE)])
+                .field_value(&exif("OLYMPUS IMAGING CORP.  ", "E-M5")),
+            Some("Olympus".to_string())
+        );
+        assert_eq!(
+            utils::with_parameters(CameraStrategy::new(), vec![(FIELD, LENS)])
+                .field_value(&exif("Canon", "Canon EOS R5")),
+            Some("EF24-70mm f/2.8L".to_string())
+        );
+    }
+
+    #[test]
+    fn should_map_several_bodies_to_a_folder() {
+        let mut strategy = utils::with_parameters(CameraStrategy::new(), vec![(FIELD, MODEL)]);
+        strategy.add_parameter(
+            MAPPING.to_string(),
+            StrategyParameter::Map(parse_map("EOS R5 -> DSLR; D750 -> DSLR; iPhone* -> Phone")),
+        );
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(strategy.map("eos r5".to_string()), "DSLR");
+        assert_eq!(strategy.map("iPhone 15 Pro".to_string()), "Phone");
+        assert_eq!(strategy.map("X100V".to_string()), "X100V");
+    }
+
+    #[test]
+    fn should_reject_invalid_mapping() {
+        let mut strategy = CameraStrategy::new();
+        strategy.add_parameter(
+            MAPPING.to_string(),
+            StrategyParameter::Map(parse_map("EOS R5 -> DSLR; D750")),
+        );
+
+        assert!(strategy.validate().is_err());
+    }
+}
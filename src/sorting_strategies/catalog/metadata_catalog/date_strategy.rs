@@ -0,0 +1,455 @@
+use std::{collections::HashMap, fmt::Write, str::FromStr};
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Datelike, FixedOffset,
+};
+
+use crate::{
+    core::{context, parameter, strategy, validation},
+    sorting_strategies::{file::file_time, utils},
+};
+
+const FORMAT_PARAMETER_NAME: &str = "format";
+const LOCALE_PARAMETER_NAME: &str = "locale";
+const FISCAL_START_PARAMETER_NAME: &str = "fiscal.start";
+const DEFAULT_FORMAT: &str = "%Y/%m";
+
+/// Locales offered for the names of months and days.
+static SUPPORTED_LOCALES: &[chrono::Locale] = &[
+    chrono::Locale::fr_FR,
+    chrono::Locale::en_US,
+    chrono::Locale::en_GB,
+    chrono::Locale::es_ES,
+    chrono::Locale::de_DE,
+    chrono::Locale::it_IT,
+    chrono::Locale::pt_PT,
+    chrono::Locale::pt_BR,
+    chrono::Locale::nl_NL,
+    chrono::Locale::sv_SE,
+    chrono::Locale::da_DK,
+    chrono::Locale::nb_NO,
+    chrono::Locale::fi_FI,
+    chrono::Locale::pl_PL,
+    chrono::Locale::cs_CZ,
+    chrono::Locale::ru_RU,
+    chrono::Locale::uk_UA,
+    chrono::Locale::tr_TR,
+    chrono::Locale::el_GR,
+    chrono::Locale::ja_JP,
+    chrono::Locale::ko_KR,
+    chrono::Locale::zh_CN,
+];
+
+/// Placeholders handled on top of the strftime specifiers.
+const QUARTER_PLACEHOLDER: &str = "{quarter}";
+const FISCAL_YEAR_PLACEHOLDER: &str = "{fiscal.year}";
+const FISCAL_QUARTER_PLACEHOLDER: &str = "{fiscal.quarter}";
+
+/// Formats the date of the file with any strftime `format` and chrono `locale`.
+///
+/// Besides the strftime specifiers (`%G-W%V` for the ISO week, `%A` for the day of the week...),
+/// the format accepts `{quarter}` (`Q1`), `{fiscal.year}` and `{fiscal.quarter}`. A fiscal year
+/// starts on the first day of the `fiscal.start` month, and is named after the calendar year in
+/// which it ends. The locale defaults to the one of the `LC_ALL`, `LC_TIME` or `LANG` variables.
+///
+/// `year` and `month` are presets of this strategy, with a fixed format.
+#[derive(Clone, Debug)]
+pub struct DateStrategy {
+    name: &'static str,
+    /// Format of the presets, which then don't have a `format` parameter.
+    preset_format: Option<&'static str>,
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, parameter::StrategyParameter>,
+}
+
+impl DateStrategy {
+    pub fn new() -> Self {
+        DateStrategy::build("date", None, format_parameter_details())
+    }
+
+    pub fn year() -> Self {
+        DateStrategy::build("year", Some("%Y"), vec![])
+    }
+
+    pub fn month() -> Self {
+        DateStrategy::build("month", Some("%m_%B"), vec![locale_parameter_details()])
+    }
+
+    fn build(
+        name: &'static str,
+        preset_format: Option<&'static str>,
+        validation_criteria: Vec<validation::ParameterDetail>,
+    ) -> Self {
+        let mut validator = utils::BaseValidator::new();
+        for criteria in validation_criteria
+            .into_iter()
+            .chain(file_time::time_parameter_details())
+        {
+            validator.add_validator(criteria);
+        }
+
+        let parameters = validator.default_parameters();
+
+        DateStrategy {
+            name,
+            preset_format,
+            validator,
+            parameters,
+        }
+    }
+
+    fn formatter(&self) -> DateFormatter<'_> {
+        DateFormatter {
+            parameters: &self.parameters,
+            preset_format: self.preset_format,
+        }
+    }
+}
+
+/// Parameters of the strategies formatting a date freely.
+pub(super) fn format_parameter_details() -> Vec<validation::ParameterDetail> {
+    let mut format_validation_criteria = validation::ParameterDetail::new(
+        FORMAT_PARAMETER_NAME,
+        parameter::StrategyParameterKind::SingleString,
+        false,
+    );
+    format_validation_criteria.with_default_value(parameter::StrategyParameter::SingleString(
+        DEFAULT_FORMAT.to_string(),
+    ));
+
+    let mut fiscal_start_validation_criteria = validation::ParameterDetail::new(
+        FISCAL_START_PARAMETER_NAME,
+        parameter::StrategyParameterKind::Number,
+        false,
+    );
+    fiscal_start_validation_criteria.with_default_value(parameter::StrategyParameter::Number(1));
+
+    vec![
+        format_validation_criteria,
+        locale_parameter_details(),
+        fiscal_start_validation_criteria,
+    ]
+}
+
+/// Optional locale, which defaults to the one of the environment.
+fn locale_parameter_details() -> validation::ParameterDetail {
+    validation::ParameterDetail::new(
+        LOCALE_PARAMETER_NAME,
+        parameter::StrategyParameterKind::Choice(
+            SUPPORTED_LOCALES
+                .iter()
+                .map(chrono::Locale::to_string)
+                .collect(),
+        ),
+        false,
+    )
+}
+
+/// Formats dates according to the `format`, `locale` and `fiscal.start` parameters.
+pub(super) struct DateFormatter<'a> {
+    pub parameters: &'a HashMap<String, parameter::StrategyParameter>,
+    pub preset_format: Option<&'static str>,
+}
+
+impl DateFormatter<'_> {
+    fn format(&self) -> &str {
+        self.preset_format
+            .or(utils::string_parameter(self.parameters, FORMAT_PARAMETER_NAME).map(String::as_str))
+            .unwrap_or(DEFAULT_FORMAT)
+    }
+
+    fn locale(&self) -> chrono::Locale {
+        utils::string_parameter(self.parameters, LOCALE_PARAMETER_NAME)
+            .and_then(|locale| chrono::Locale::from_str(locale).ok())
+            .unwrap_or_else(environment_locale)
+    }
+
+    fn fiscal_start(&self) -> u32 {
+        match self.parameters.get(FISCAL_START_PARAMETER_NAME) {
+            Some(parameter::StrategyParameter::Number(month)) => *month as u32,
+            _ => 1,
+        }
+    }
+
+    /// Date written with the format, or `None` when the format is invalid: strategies nested in
+    /// others may be applied without having been validated.
+    pub fn format_date(&self, datetime: &DateTime<FixedOffset>) -> Option<String> {
+        let format = expand_placeholders(self.format(), datetime, self.fiscal_start());
+        let mut folder = String::new();
+        write!(
+            folder,
+            "{}",
+            datetime.format_localized(&format, self.locale())
+        )
+        .ok()?;
+
+        Some(folder)
+    }
+
+    pub fn validate(&self) -> Result<(), validation::error::Error> {
+        if !(1..=12).contains(&self.fiscal_start()) {
+            return Err(validation::error::Error::InvalidValue(
+                FISCAL_START_PARAMETER_NAME.to_string(),
+                "the fiscal year must start on a month between 1 and 12".to_string(),
+            ));
+        }
+
+        let format = self.format();
+        let expanded = [
+            QUARTER_PLACEHOLDER,
+            FISCAL_YEAR_PLACEHOLDER,
+            FISCAL_QUARTER_PLACEHOLDER,
+        ]
+        .iter()
+        .fold(format.to_string(), |format, placeholder| {
+            format.replace(placeholder, "")
+        });
+        if StrftimeItems::new(&expanded).any(|item| item == Item::Error) {
+            return Err(validation::error::Error::InvalidValue(
+                FORMAT_PARAMETER_NAME.to_string(),
+                format!("'{format}' is not a valid date format"),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Locale of the `LC_ALL`, `LC_TIME` or `LANG` variables, such as `fr_FR.UTF-8`.
+fn environment_locale() -> chrono::Locale {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|variable| std::env::var(variable).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| {
+            let name = value.split(['.', '@']).next().unwrap_or_default();
+            chrono::Locale::from_str(name).ok()
+        })
+        .unwrap_or(chrono::Locale::en_US)
+}
+
+/// Replaces the placeholders of `format` that strftime doesn't know about.
+fn expand_placeholders(
+    format: &str,
+    datetime: &DateTime<FixedOffset>,
+    fiscal_start: u32,
+) -> String {
+    let month0 = datetime.month0();
+    let fiscal_month0 = (month0 + 12 - fiscal_start.saturating_sub(1) % 12) % 12;
+    let fiscal_year = if fiscal_start > 1 && datetime.month() >= fiscal_start {
+        datetime.year() + 1
+    } else {
+        datetime.year()
+    };
+
+    format
+        .replace(QUARTER_PLACEHOLDER, &format!("Q{}", month0 / 3 + 1))
+        .replace(FISCAL_YEAR_PLACEHOLDER, &fiscal_year.to_string())
+        .replace(
+            FISCAL_QUARTER_PLACEHOLDER,
+            &format!("Q{}", fiscal_month0 / 3 + 1),
+        )
+}
+
+impl strategy::Apply for DateStrategy {
+    fn apply(&self, file_path: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        file_time::file_time(file_path, f, &self.parameters)
+            .ok()
+            .and_then(|datetime| self.formatter().format_date(&datetime))
+    }
+}
+
+impl strategy::Name for DateStrategy {
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+}
+
+impl strategy::AddParameter for DateStrategy {
+    fn add_parameter(&mut self, key: String, value: parameter::StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for DateStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        file_time::validate_time_parameters(&self.parameters)?;
+        self.formatter().validate()
+    }
+}
+
+impl strategy::ParameterDetails for DateStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for DateStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for DateStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, parameter::StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::{AddParameter, Apply, ParameterDetails, Validate},
+        sorting_strategies::catalog::all_catalog,
+    };
+    use chrono::TimeZone;
+
+    fn datetime(year: i32, month: u32, day: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(year, month, day, 12, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_expand_quarters_and_fiscal_years() {
+        let format = "{fiscal.year}-{fiscal.quarter}/{quarter}";
+
+        assert_eq!(
+            expand_placeholders(format, &datetime(2023, 9, 30), 10),
+            "2023-Q4/Q3"
+        );
+        assert_eq!(
+            expand_placeholders(format, &datetime(2023, 10, 1), 10),
+            "2024-Q1/Q4"
+        );
+        assert_eq!(
+            expand_placeholders(format, &datetime(2023, 1, 1), 1),
+            "2023-Q1/Q1"
+        );
+    }
+
+    #[test]
+    fn should_format_with_the_given_locale() {
+        let path = std::env::temp_dir().join("date_strategy_locale.txt");
+        let file = std::fs::File::create(&path).unwrap();
+        file.set_modified(datetime(2023, 3, 1).into()).unwrap();
+
+        let mut strategy = DateStrategy::new();
+        for (name, value) in [
+            (FORMAT_PARAMETER_NAME, "%B %A"),
+            (LOCALE_PARAMETER_NAME, "de_DE"),
+            (file_time::TIME_ZONE_PARAMETER_NAME, "UTC"),
+        ] {
+            strategy.add_parameter(
+                name.to_string(),
+                parameter::StrategyParameter::SingleString(value.to_string()),
+            );
+        }
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            strategy.apply(&path, &file),
+            Some("März Mittwoch".to_string())
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn should_date_files_whatever_the_time_source() {
+        let path = std::env::temp_dir().join("date_strategy_time_source.txt");
+        std::fs::write(&path, "").unwrap();
+
+        for source in file_time::TimeSource::all() {
+            let mut strategy = DateStrategy::year();
+            strategy.add_parameter(
+                file_time::TIME_SOURCE_PARAMETER_NAME.to_string(),
+                parameter::StrategyParameter::SingleString(source.into()),
+            );
+
+            assert!(strategy
+                .apply(&path, &std::fs::File::open(&path).unwrap())
+                .is_some());
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn should_skip_files_with_an_invalid_format() {
+        let mut strategy = DateStrategy::new();
+        strategy.add_parameter(
+            FORMAT_PARAMETER_NAME.to_string(),
+            parameter::StrategyParameter::SingleString("%Q".to_string()),
+        );
+
+        assert_eq!(
+            strategy.apply(
+                &std::path::PathBuf::from("Cargo.toml"),
+                &utils::manifest_file()
+            ),
+            None
+        );
+
+        let mut concat = all_catalog().get_strategy(&"concat".to_string()).unwrap();
+        concat.add_parameter(
+            "strategies".to_string(),
+            parameter::StrategyParameter::Strategy(vec![Box::new(strategy)]),
+        );
+        assert!(concat.validate().is_err());
+    }
+
+    #[test]
+    fn should_keep_the_presets_format() {
+        let mut month = DateStrategy::month();
+        assert_eq!(month.formatter().format(), "%m_%B");
+        assert_eq!(month.formatter().locale(), environment_locale());
+        let locale = month
+            .parameter_details()
+            .into_iter()
+            .find(|detail| detail.name == LOCALE_PARAMETER_NAME)
+            .unwrap();
+        assert_eq!(locale.default_value, None);
+        assert!(locale
+            .kind
+            .is_matching(&parameter::StrategyParameter::SingleString(
+                "fr_FR".to_string()
+            )));
+
+        month.add_parameter(
+            FORMAT_PARAMETER_NAME.to_string(),
+            parameter::StrategyParameter::SingleString("%Y".to_string()),
+        );
+        assert!(month.validate().is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_parameters() {
+        for (name, value) in [
+            (
+                FORMAT_PARAMETER_NAME,
+                parameter::StrategyParameter::SingleString("%Q {quarter}".to_string()),
+            ),
+            (
+                LOCALE_PARAMETER_NAME,
+                parameter::StrategyParameter::SingleString("xx_XX".to_string()),
+            ),
+            (
+                FISCAL_START_PARAMETER_NAME,
+                parameter::StrategyParameter::Number(13),
+            ),
+        ] {
+            let mut strategy = DateStrategy::new();
+            strategy.add_parameter(name.to_string(), value);
+
+            assert!(strategy.validate().is_err(), "{name} should be invalid");
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    core::{
+        context,
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{
+        file::document_metadata::{DocumentField, DocumentMetadata},
+        utils,
+    },
+};
+
+use super::date_strategy::{format_parameter_details, DateFormatter};
+
+const FIELD: &str = "field";
+
+/// Names the folder after a metadata of a PDF, Office or OpenDocument file, such as its author.
+/// Date fields are formatted with the same parameters as the `date` strategy.
+/// Files without the chosen `field` give no folder.
+#[derive(Clone, Debug)]
+pub struct DocumentMetadataStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl DocumentMetadataStrategy {
+    pub fn new() -> Self {
+        let mut field_detail = validation::ParameterDetail::new(
+            FIELD,
+            StrategyParameterKind::Choice(
+                DocumentField::all().into_iter().map(Into::into).collect(),
+            ),
+            false,
+        );
+        field_detail.with_default_value(StrategyParameter::SingleString(
+            DocumentField::default().into(),
+        ));
+
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(field_detail);
+        for criteria in format_parameter_details() {
+            validator.add_validator(criteria);
+        }
+
+        let parameters = validator.default_parameters();
+
+        DocumentMetadataStrategy {
+            validator,
+            parameters,
+        }
+    }
+
+    fn field(&self) -> DocumentField {
+        match self.parameters.get(FIELD) {
+            Some(StrategyParameter::SingleString(field)) => DocumentField::from(field.clone()),
+            _ => DocumentField::default(),
+        }
+    }
+
+    fn formatter(&self) -> DateFormatter<'_> {
+        DateFormatter {
+            parameters: &self.parameters,
+            preset_format: None,
+        }
+    }
+}
+
+impl strategy::Apply for DocumentMetadataStrategy {
+    fn apply(&self, _: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let metadata = DocumentMetadata::read(f)?;
+        let field = self.field();
+
+        if field.is_date() {
+            let datetime = metadata.date(field)?;
+            self.formatter().format_date(&datetime)
+        } else {
+            metadata.get(field).map(utils::folder_name)
+        }
+    }
+}
+
+impl strategy::Name for DocumentMetadataStrategy {
+    fn name(&self) -> String {
+        "document metadata".to_string()
+    }
+}
+
+impl strategy::AddParameter for DocumentMetadataStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for DocumentMetadataStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        self.formatter().validate()
+    }
+}
+
+impl strategy::ParameterDetails for DocumentMetadataStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for DocumentMetadataStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for DocumentMetadataStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::Validate, sorting_strategies::file::document_metadata::tests::PDF,
+    };
+
+    #[test]
+    fn should_name_the_folder_after_the_chosen_field() {
+        assert_eq!(
+            utils::apply_to_content(&DocumentMetadataStrategy::new(), "note.pdf", PDF.as_bytes()),
+            Some("José (Finance)".to_string())
+        );
+
+        let strategy =
+            utils::with_parameters(DocumentMetadataStrategy::new(), vec![(FIELD, "title")]);
+        assert_eq!(
+            utils::apply_to_content(&strategy, "note.pdf", PDF.as_bytes()),
+            Some("Note".to_string())
+        );
+    }
+
+    #[test]
+    fn should_format_date_fields() {
+        let strategy = utils::with_parameters(
+            DocumentMetadataStrategy::new(),
+            vec![(FIELD, "creation date"), ("format", "%Y/{quarter}")],
+        );
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to_content(&strategy, "note.pdf", PDF.as_bytes()),
+            Some("2023/Q2".to_string())
+        );
+    }
+
+    #[test]
+    fn should_give_no_folder_without_the_field() {
+        let strategy =
+            utils::with_parameters(DocumentMetadataStrategy::new(), vec![(FIELD, "subject")]);
+
+        assert_eq!(
+            utils::apply_to_content(&strategy, "note.pdf", PDF.as_bytes()),
+            None
+        );
+        assert_eq!(
+            utils::apply_to_content(&DocumentMetadataStrategy::new(), "note.txt", b"plain text"),
+            None
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_date_formats() {
+        let strategy = utils::with_parameters(
+            DocumentMetadataStrategy::new(),
+            vec![(FIELD, "creation date"), ("format", "%Q")],
+        );
+
+        assert!(strategy.validate().is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    core::{context, parameter, strategy, validation},
+    sorting_strategies::{file::exif::Exif, utils},
+};
+
+use super::date_strategy::{format_parameter_details, DateFormatter};
+
+/// Formats the date a photo was taken, read from its EXIF `DateTimeOriginal` tag, with the same
+/// parameters as the `date` strategy. Files without EXIF data give no folder, so that the strategy
+/// can fall back on another one with `or`.
+#[derive(Clone, Debug)]
+pub struct ExifDateStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, parameter::StrategyParameter>,
+}
+
+impl ExifDateStrategy {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        for criteria in format_parameter_details() {
+            validator.add_validator(criteria);
+        }
+
+        let parameters = validator.default_parameters();
+
+        ExifDateStrategy {
+            validator,
+            parameters,
+        }
+    }
+
+    fn formatter(&self) -> DateFormatter<'_> {
+        DateFormatter {
+            parameters: &self.parameters,
+            preset_format: None,
+        }
+    }
+}
+
+impl strategy::Apply for ExifDateStrategy {
+    fn apply(&self, _: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let datetime = Exif::read(f)?.date_time_original()?;
+
+        self.formatter().format_date(&datetime)
+    }
+}
+
+impl strategy::Name for ExifDateStrategy {
+    fn name(&self) -> String {
+        "exif date".to_string()
+    }
+}
+
+impl strategy::AddParameter for ExifDateStrategy {
+    fn add_parameter(&mut self, key: String, value: parameter::StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for ExifDateStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        self.formatter().validate()
+    }
+}
+
+impl strategy::ParameterDetails for ExifDateStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for ExifDateStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for ExifDateStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, parameter::StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::AddParameter,
+        sorting_strategies::file::exif::{tests::jpeg, DATE_TIME_ORIGINAL_TAG},
+    };
+
+    #[test]
+    fn should_format_the_capture_date() {
+        let mut strategy = ExifDateStrategy::new();
+        strategy.add_parameter(
+            "format".to_string(),
+            parameter::StrategyParameter::SingleString("%Y/{quarter}".to_string()),
+        );
+
+        assert_eq!(
+            utils::apply_to_content(
+                &strategy,
+                "photo.jpg",
+                &jpeg(&[(DATE_TIME_ORIGINAL_TAG, "2019:11:30 23:59:59")])
+            ),
+            Some("2019/Q4".to_string())
+        );
+    }
+
+    #[test]
+    fn should_give_no_folder_without_exif() {
+        assert_eq!(
+            utils::apply_to_content(&ExifDateStrategy::new(), "photo.jpg", b"not a photo"),
+            None
+        );
+    }
+}
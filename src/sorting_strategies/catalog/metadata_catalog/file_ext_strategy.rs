@@ -1,25 +1,57 @@
-use crate::core::{context, strategy};
+use crate::{
+    core::{context, parameter::StrategyParameter, strategy, validation},
+    sorting_strategies::{
+        file::file_ext::{self, Case},
+        utils,
+    },
+};
 
+/// Names the folder after the extension of the file, multi-part ones such as `tar.gz` included,
+/// in the given `case`. Files without extension go to the `no_extension` folder.
 #[derive(Clone, Debug)]
-pub struct FileExtStrategy;
+pub struct FileExtStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
 
 impl FileExtStrategy {
     pub fn new() -> Self {
-        FileExtStrategy
+        let mut validator = utils::BaseValidator::new();
+        for detail in file_ext::parameter_details() {
+            validator.add_validator(detail);
+        }
+
+        let parameters = validator.default_parameters();
+
+        FileExtStrategy {
+            validator,
+            parameters,
+        }
     }
 }
 
 impl strategy::Apply for FileExtStrategy {
     fn apply(&self, file_path: &std::path::PathBuf, _: &std::fs::File) -> Option<String> {
-        Some(crate::sorting_strategies::file::file_ext::file_ext(
-            file_path,
-        ))
+        let case = utils::string_parameter(&self.parameters, file_ext::CASE_PARAMETER_NAME)
+            .map(|case| Case::from(case.clone()))
+            .unwrap_or_default();
+
+        let folder = match file_ext::extension(file_path) {
+            Some(extension) => case.apply(&extension),
+            None => {
+                utils::string_parameter(&self.parameters, file_ext::NO_EXTENSION_PARAMETER_NAME)
+                    .cloned()
+                    .unwrap_or(file_ext::NO_EXTENSION.to_string())
+            }
+        };
+
+        Some(folder)
     }
 }
 
 impl strategy::Validate for FileExtStrategy {
-    fn validate(&self) -> Result<(), crate::core::validation::error::Error> {
-        Ok(())
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)
     }
 }
 
@@ -30,12 +62,14 @@ impl strategy::Name for FileExtStrategy {
 }
 
 impl strategy::AddParameter for FileExtStrategy {
-    fn add_parameter(&mut self, _: String, _: crate::core::parameter::StrategyParameter) {}
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
 }
 
 impl strategy::ParameterDetails for FileExtStrategy {
-    fn parameter_details(&self) -> Vec<crate::core::validation::ParameterDetail> {
-        vec![]
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
     }
 }
 
@@ -49,9 +83,50 @@ impl context::ProcessContext for FileExtStrategy {
 }
 
 impl strategy::Parameters for FileExtStrategy {
-    fn parameters(
-        &self,
-    ) -> std::collections::HashMap<String, crate::core::parameter::StrategyParameter> {
-        std::collections::HashMap::new()
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::strategy::{AddParameter, Apply};
+    use std::path::PathBuf;
+
+    fn apply(parameters: Vec<(&str, &str)>, file_path: &str) -> Option<String> {
+        let mut strategy = FileExtStrategy::new();
+        for (key, value) in parameters {
+            strategy.add_parameter(
+                key.to_string(),
+                StrategyParameter::SingleString(value.to_string()),
+            );
+        }
+
+        strategy.apply(&PathBuf::from(file_path), &utils::manifest_file())
+    }
+
+    #[test]
+    fn should_keep_compound_extensions_whole() {
+        assert_eq!(apply(vec![], "backup.TAR.gz"), Some("TAR.gz".to_string()));
+        assert_eq!(
+            apply(
+                vec![(file_ext::CASE_PARAMETER_NAME, "lower")],
+                "backup.TAR.gz"
+            ),
+            Some("tar.gz".to_string())
+        );
+    }
+
+    #[test]
+    fn should_label_files_without_extension() {
+        assert_eq!(apply(vec![], "README"), Some("unknown".to_string()));
+        assert_eq!(
+            apply(
+                vec![(file_ext::NO_EXTENSION_PARAMETER_NAME, "no extension")],
+                ".gitignore"
+            ),
+            Some("no extension".to_string())
+        );
     }
 }
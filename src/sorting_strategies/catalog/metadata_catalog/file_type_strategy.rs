@@ -1,23 +1,79 @@
-use crate::core::strategy;
+use crate::{
+    core::{context, parameter, strategy, validation},
+    sorting_strategies::{
+        file::{
+            filetype::Taxonomy,
+            mime::{self, Detection},
+        },
+        utils,
+    },
+};
 
+const TAXONOMY: &str = "taxonomy";
+
+/// Names the folder after the category of the file, read from its content or its extension
+/// according to the `detection` parameter.
+///
+/// The categories come from the built-in taxonomy, overridden by the TOML file at `taxonomy`
+/// which may add extensions and MIME types to a category or define new ones.
 #[derive(Clone, Debug)]
-pub struct FileTypeStrategy;
+pub struct FileTypeStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, parameter::StrategyParameter>,
+    taxonomy: Option<Taxonomy>,
+}
 
 impl FileTypeStrategy {
     pub fn new() -> Self {
-        FileTypeStrategy
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(mime::detection_parameter_detail())
+            .add_validator(validation::ParameterDetail::new(
+                TAXONOMY,
+                parameter::StrategyParameterKind::SingleString,
+                false,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        FileTypeStrategy {
+            validator,
+            parameters,
+            taxonomy: None,
+        }
+    }
+
+    fn load_taxonomy(&self) -> Option<Result<Taxonomy, String>> {
+        match self.parameters.get(TAXONOMY) {
+            Some(parameter::StrategyParameter::SingleString(path)) => {
+                Some(Taxonomy::load(std::path::Path::new(path)))
+            }
+            _ => None,
+        }
     }
 }
 
 impl strategy::Apply for FileTypeStrategy {
-    fn apply(&self, file_path: &std::path::PathBuf, _: &std::fs::File) -> Option<String> {
-        let ext = crate::sorting_strategies::file::file_ext::file_ext(file_path);
-        Some(crate::sorting_strategies::file::filetype::FileType::from_extension(&ext).to_string())
+    fn apply(&self, file_path: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let taxonomy = self.taxonomy.as_ref().unwrap_or(Taxonomy::builtin());
+        let file_type =
+            taxonomy.file_type(Detection::from_parameters(&self.parameters), file_path, f);
+
+        Some(file_type.to_string())
     }
 }
 
 impl strategy::Validate for FileTypeStrategy {
-    fn validate(&self) -> Result<(), crate::core::validation::error::Error> {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        if let Some(Err(reason)) = self.load_taxonomy() {
+            return Err(validation::error::Error::InvalidValue(
+                TAXONOMY.to_string(),
+                reason,
+            ));
+        }
+
         Ok(())
     }
 }
@@ -29,28 +85,104 @@ impl strategy::Name for FileTypeStrategy {
 }
 
 impl strategy::AddParameter for FileTypeStrategy {
-    fn add_parameter(&mut self, _: String, _: crate::core::parameter::StrategyParameter) {}
+    fn add_parameter(&mut self, key: String, value: parameter::StrategyParameter) {
+        self.parameters.insert(key, value);
+        self.taxonomy = self.load_taxonomy().and_then(Result::ok);
+    }
 }
 
 impl strategy::ParameterDetails for FileTypeStrategy {
-    fn parameter_details(&self) -> Vec<crate::core::validation::ParameterDetail> {
-        vec![]
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
     }
 }
 
-impl crate::core::context::ProcessContext for FileTypeStrategy {
+impl context::ProcessContext for FileTypeStrategy {
     fn process_context(
         &mut self,
-        _: crate::core::context::StrategyContext,
+        _: context::StrategyContext,
     ) -> Result<(), crate::core::error::Error> {
         Ok(())
     }
 }
 
 impl strategy::Parameters for FileTypeStrategy {
-    fn parameters(
-        &self,
-    ) -> std::collections::HashMap<String, crate::core::parameter::StrategyParameter> {
-        std::collections::HashMap::new()
+    fn parameters(&self) -> std::collections::HashMap<String, parameter::StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::strategy::{AddParameter, Apply, Validate};
+    use std::path::PathBuf;
+
+    fn apply(detection: Detection, file_path: &str) -> Option<String> {
+        let mut strategy = FileTypeStrategy::new();
+        strategy.add_parameter(
+            mime::DETECTION_PARAMETER_NAME.to_string(),
+            parameter::StrategyParameter::SingleString(detection.into()),
+        );
+
+        strategy.apply(&PathBuf::from(file_path), &utils::manifest_file())
+    }
+
+    #[test]
+    fn should_classify_by_extension() {
+        assert_eq!(
+            apply(Detection::Extension, "data.csv"),
+            Some("spreadsheet".to_string())
+        );
+        assert_eq!(
+            apply(Detection::Extension, "setup.exe"),
+            Some("executable".to_string())
+        );
+        assert_eq!(
+            apply(Detection::Extension, "notes.unknown"),
+            Some("other".to_string())
+        );
+    }
+
+    #[test]
+    fn should_read_the_content_first() {
+        assert_eq!(
+            apply(Detection::Content, "photo.png"),
+            Some("document".to_string())
+        );
+        assert_eq!(
+            apply(Detection::ContentThenExtension, "Cargo.toml"),
+            Some("code".to_string())
+        );
+    }
+
+    #[test]
+    fn should_use_the_taxonomy_file() {
+        let path = std::env::temp_dir().join("file_type_strategy_taxonomy.toml");
+        std::fs::write(
+            &path,
+            "[[category]]\nname = \"Manifest\"\nextensions = [\"toml\", \"lock\"]\n",
+        )
+        .unwrap();
+
+        let mut strategy = FileTypeStrategy::new();
+        strategy.add_parameter(
+            TAXONOMY.to_string(),
+            parameter::StrategyParameter::SingleString(path.to_string_lossy().to_string()),
+        );
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            strategy.apply(&PathBuf::from("Cargo.toml"), &utils::manifest_file()),
+            Some("manifest".to_string())
+        );
+
+        strategy.add_parameter(
+            TAXONOMY.to_string(),
+            parameter::StrategyParameter::SingleString("missing.toml".to_string()),
+        );
+        assert!(strategy.validate().is_err());
+
+        std::fs::remove_file(path).unwrap();
     }
 }
@@ -0,0 +1,262 @@
+use std::{fmt::Write, sync::LazyLock};
+
+use chrono::NaiveDate;
+
+use crate::{
+    core::{context, parameter, strategy, validation},
+    sorting_strategies::utils,
+};
+
+const PATTERN_PARAMETER_NAME: &str = "pattern";
+const FORMAT_PARAMETER_NAME: &str = "format";
+const DEFAULT_FORMAT: &str = "%Y";
+
+const YEAR_GROUP: &str = "year";
+const MONTH_GROUP: &str = "month";
+const DAY_GROUP: &str = "day";
+
+/// Dates outside of this range are most likely random digits, such as hashes.
+const MIN_YEAR: i32 = 1900;
+const MAX_YEAR: i32 = 2100;
+
+/// `2022-02-22` or `2023_05_14`, `20230514`, then `14.05.2023` or `14-05-2023`.
+static BUILTIN_PATTERNS: LazyLock<Vec<regex::Regex>> = LazyLock::new(|| {
+    [
+        r"(?:^|\D)(?P<year>\d{4})[-_.](?P<month>\d{2})[-_.](?P<day>\d{2})(?:\D|$)",
+        r"(?:^|\D)(?P<year>\d{4})(?P<month>\d{2})(?P<day>\d{2})(?:\D|$)",
+        r"(?:^|\D)(?P<day>\d{2})[-_.](?P<month>\d{2})[-_.](?P<year>\d{4})(?:\D|$)",
+    ]
+    .iter()
+    .map(|pattern| regex::Regex::new(pattern).unwrap())
+    .collect()
+});
+
+/// Reads the date written in the file name, rather than the modification date.
+/// A custom `pattern` must name its groups `year`, `month` and optionally `day`.
+#[derive(Clone, Debug)]
+pub struct FilenameDateStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, parameter::StrategyParameter>,
+    pattern: Option<regex::Regex>,
+}
+
+impl FilenameDateStrategy {
+    pub fn new() -> Self {
+        let mut format_validation_criteria = validation::ParameterDetail::new(
+            FORMAT_PARAMETER_NAME,
+            parameter::StrategyParameterKind::SingleString,
+            false,
+        );
+        format_validation_criteria.with_default_value(parameter::StrategyParameter::SingleString(
+            DEFAULT_FORMAT.to_string(),
+        ));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                PATTERN_PARAMETER_NAME,
+                parameter::StrategyParameterKind::SingleString,
+                false,
+            ))
+            .add_validator(format_validation_criteria);
+
+        let parameters = validator.default_parameters();
+
+        FilenameDateStrategy {
+            validator,
+            parameters,
+            pattern: None,
+        }
+    }
+
+    /// Date written with `format`, or `None` when the format uses specifiers other than the
+    /// date ones, such as hours or time zones.
+    fn format_date(date: NaiveDate, format: &str) -> Option<String> {
+        let mut folder = String::new();
+        write!(folder, "{}", date.format(format)).ok()?;
+
+        Some(folder)
+    }
+
+    fn find_date(&self, file_name: &str) -> Option<NaiveDate> {
+        let patterns = match &self.pattern {
+            Some(pattern) => std::slice::from_ref(pattern),
+            None => BUILTIN_PATTERNS.as_slice(),
+        };
+
+        patterns.iter().find_map(|pattern| {
+            pattern.captures_iter(file_name).find_map(|captures| {
+                let number = |group: &str| captures.name(group)?.as_str().parse::<u32>().ok();
+                let year = number(YEAR_GROUP)? as i32;
+                let day = match captures.name(DAY_GROUP) {
+                    Some(_) => number(DAY_GROUP)?,
+                    None => 1,
+                };
+
+                (MIN_YEAR..=MAX_YEAR)
+                    .contains(&year)
+                    .then(|| NaiveDate::from_ymd_opt(year, number(MONTH_GROUP)?, day))
+                    .flatten()
+            })
+        })
+    }
+}
+
+impl strategy::Apply for FilenameDateStrategy {
+    fn apply(&self, file_path: &std::path::PathBuf, _: &std::fs::File) -> Option<String> {
+        let file_name = file_path.file_name()?.to_string_lossy();
+        let date = self.find_date(&file_name)?;
+        let format = utils::string_parameter(&self.parameters, FORMAT_PARAMETER_NAME)
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_FORMAT);
+
+        Self::format_date(date, format)
+    }
+}
+
+impl strategy::Name for FilenameDateStrategy {
+    fn name(&self) -> String {
+        "filename date".to_string()
+    }
+}
+
+impl strategy::AddParameter for FilenameDateStrategy {
+    fn add_parameter(&mut self, key: String, value: parameter::StrategyParameter) {
+        if key == PATTERN_PARAMETER_NAME {
+            self.pattern = match &value {
+                parameter::StrategyParameter::SingleString(pattern) => {
+                    regex::Regex::new(pattern).ok()
+                }
+                _ => None,
+            };
+        }
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for FilenameDateStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        if let Some(pattern) = utils::string_parameter(&self.parameters, PATTERN_PARAMETER_NAME) {
+            let pattern = utils::compile_regex(PATTERN_PARAMETER_NAME, pattern)?;
+            let group_names: Vec<&str> = pattern.capture_names().flatten().collect();
+            if !group_names.contains(&YEAR_GROUP) || !group_names.contains(&MONTH_GROUP) {
+                return Err(validation::error::Error::InvalidValue(
+                    PATTERN_PARAMETER_NAME.to_string(),
+                    "the pattern must define the 'year' and 'month' named groups".to_string(),
+                ));
+            }
+        }
+
+        if let Some(format) = utils::string_parameter(&self.parameters, FORMAT_PARAMETER_NAME) {
+            if Self::format_date(NaiveDate::default(), format).is_none() {
+                return Err(validation::error::Error::InvalidValue(
+                    FORMAT_PARAMETER_NAME.to_string(),
+                    format!(
+                        "'{format}' is not a valid date format, it may only use date specifiers"
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for FilenameDateStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for FilenameDateStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for FilenameDateStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, parameter::StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::strategy::Validate;
+
+    #[test]
+    fn should_recognise_common_patterns() {
+        let strategy = utils::with_parameters(
+            FilenameDateStrategy::new(),
+            vec![(FORMAT_PARAMETER_NAME, "%Y-%m-%d")],
+        );
+
+        for (file_name, expected) in [
+            ("file_2022-02-22_F1BDD782", "2022-02-22"),
+            ("IMG_20230514_101010.jpg", "2023-05-14"),
+            ("Scan 14.05.2023.pdf", "2023-05-14"),
+        ] {
+            assert_eq!(
+                utils::apply_to(&strategy, file_name),
+                Some(expected.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn should_ignore_digits_that_are_not_dates() {
+        let strategy = utils::with_parameters(FilenameDateStrategy::new(), vec![]);
+
+        assert_eq!(utils::apply_to(&strategy, "F1BDD782_12345678.bin"), None);
+        assert_eq!(utils::apply_to(&strategy, "report.pdf"), None);
+    }
+
+    #[test]
+    fn should_use_custom_pattern() {
+        let strategy = utils::with_parameters(
+            FilenameDateStrategy::new(),
+            vec![
+                (PATTERN_PARAMETER_NAME, r"(?P<month>\d{2})-(?P<year>\d{4})"),
+                (FORMAT_PARAMETER_NAME, "%Y/%m"),
+            ],
+        );
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "invoice 03-2021.pdf"),
+            Some("2021/03".to_string())
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_pattern_and_format() {
+        let missing_group = utils::with_parameters(
+            FilenameDateStrategy::new(),
+            vec![(PATTERN_PARAMETER_NAME, r"(?P<year>\d{4})")],
+        );
+        assert!(missing_group.validate().is_err());
+
+        let invalid_format = utils::with_parameters(
+            FilenameDateStrategy::new(),
+            vec![(FORMAT_PARAMETER_NAME, "%Q")],
+        );
+        assert!(invalid_format.validate().is_err());
+    }
+
+    #[test]
+    fn should_reject_time_specifiers() {
+        let strategy = utils::with_parameters(
+            FilenameDateStrategy::new(),
+            vec![(FORMAT_PARAMETER_NAME, "%Y/%H")],
+        );
+
+        assert!(strategy.validate().is_err());
+        assert_eq!(utils::apply_to(&strategy, "file_2022-02-22_F1BDD782"), None);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    core::{
+        context,
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{text_transform, utils},
+};
+
+const STRATEGIES: &str = "strategies";
+const ARTICLES: &str = "articles";
+const BUCKET_SIZE: &str = "bucket_size";
+
+const DIGITS: &str = "0-9";
+const OTHERS: &str = "#";
+
+/// Words ignored at the start of the names, such as in `The Beatles`.
+const DEFAULT_ARTICLES: [&str; 13] = [
+    "the", "a", "an", "le", "la", "les", "l'", "un", "une", "der", "die", "das", "el",
+];
+
+/// Names the folder after the first significant character of the file name, or of the folder
+/// given by `strategies`: `A` to `Z` with the accents folded, `0-9` for digits and `#` otherwise.
+/// Leading `articles` and punctuation are ignored, so that `The Beatles` goes to `B`.
+///
+/// With a `bucket_size` above 1 the letters are grouped into ranges such as `A-C`.
+#[derive(Clone, Debug)]
+pub struct LetterStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl LetterStrategy {
+    pub fn new() -> Self {
+        let mut articles_detail =
+            validation::ParameterDetail::new(ARTICLES, StrategyParameterKind::List, false);
+        articles_detail.with_default_value(StrategyParameter::List(
+            DEFAULT_ARTICLES.iter().map(|a| a.to_string()).collect(),
+        ));
+
+        let mut bucket_size_detail =
+            validation::ParameterDetail::new(BUCKET_SIZE, StrategyParameterKind::Number, false);
+        bucket_size_detail.with_default_value(StrategyParameter::Number(1));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                STRATEGIES,
+                StrategyParameterKind::Strategy,
+                false,
+            ))
+            .add_validator(articles_detail)
+            .add_validator(bucket_size_detail);
+
+        let parameters = validator.default_parameters();
+
+        LetterStrategy {
+            validator,
+            parameters,
+        }
+    }
+
+    fn bucket_size(&self) -> usize {
+        match self.parameters.get(BUCKET_SIZE) {
+            Some(StrategyParameter::Number(size)) => *size,
+            _ => 1,
+        }
+    }
+
+    fn articles(&self) -> Vec<String> {
+        match self.parameters.get(ARTICLES) {
+            Some(StrategyParameter::List(articles)) => {
+                articles.iter().map(|a| a.trim().to_lowercase()).collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Name without its leading article, kept whole when nothing would follow the article.
+    fn without_article<'a>(&self, name: &'a str) -> &'a str {
+        let lowercase = name.to_lowercase();
+        self.articles()
+            .iter()
+            .filter(|article| !article.is_empty() && lowercase.starts_with(article.as_str()))
+            .filter_map(|article| {
+                // Lower casing may change the length of other characters, so the article is
+                // measured on the original name.
+                let length = name
+                    .char_indices()
+                    .nth(article.chars().count())
+                    .map_or(name.len(), |(i, _)| i);
+                let rest = &name[length..];
+                let is_word = article.ends_with('\'')
+                    || rest.starts_with(|c: char| c.is_whitespace() || c == '_' || c == '-');
+                let rest = rest.trim_start_matches(|c: char| !c.is_alphanumeric());
+
+                (is_word && !rest.is_empty()).then_some(rest)
+            })
+            .next()
+            .unwrap_or(name)
+    }
+
+    fn bucket(&self, name: &str) -> String {
+        let name = name.trim_start_matches(|c: char| !c.is_alphanumeric());
+        let first = self
+            .without_article(name)
+            .chars()
+            .next()
+            .map(|c| text_transform::transliterate(&c.to_string()))
+            .and_then(|c| c.chars().next())
+            .map(|c| c.to_ascii_uppercase());
+
+        match first {
+            Some(c) if c.is_ascii_digit() => DIGITS.to_string(),
+            Some(c) if c.is_ascii_uppercase() => self.letter_range(c),
+            _ => OTHERS.to_string(),
+        }
+    }
+
+    fn letter_range(&self, letter: char) -> String {
+        // Strategies nested in others may be applied without having been validated.
+        let size = self.bucket_size().clamp(1, 26) as u8;
+        if size == 1 {
+            return letter.to_string();
+        }
+
+        let start = b'A' + (letter as u8 - b'A') / size * size;
+        let end = (start + size - 1).min(b'Z');
+        if start == end {
+            (start as char).to_string()
+        } else {
+            format!("{}-{}", start as char, end as char)
+        }
+    }
+}
+
+impl strategy::Apply for LetterStrategy {
+    fn apply(&self, file_path: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let name = match self.parameters.get(STRATEGIES) {
+            Some(StrategyParameter::Strategy(strategies)) if !strategies.is_empty() => strategies
+                .iter()
+                .filter_map(|strategy| strategy.apply(file_path, f))
+                .collect::<Vec<_>>()
+                .join("/"),
+            _ => file_path.file_stem()?.to_string_lossy().to_string(),
+        };
+
+        Some(self.bucket(&name))
+    }
+}
+
+impl strategy::Name for LetterStrategy {
+    fn name(&self) -> String {
+        "letter".to_string()
+    }
+}
+
+impl strategy::AddParameter for LetterStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for LetterStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        utils::validate_nested_strategies(&self.parameters, STRATEGIES)?;
+
+        if !(1..=26).contains(&self.bucket_size()) {
+            return Err(validation::error::Error::InvalidValue(
+                BUCKET_SIZE.to_string(),
+                "must be between 1 and 26".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for LetterStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for LetterStrategy {
+    fn process_context(
+        &mut self,
+        context: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        if let Some(StrategyParameter::Strategy(strategies)) = self.parameters.get_mut(STRATEGIES) {
+            for strategy in strategies.iter_mut() {
+                strategy.process_context(context.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for LetterStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::strategy::{AddParameter, Validate};
+
+    #[test]
+    fn should_bucket_by_first_significant_character() {
+        let strategy = LetterStrategy::new();
+
+        for (file_name, letter) in [
+            ("contacts/dupont.vcf", "D"),
+            ("The Beatles - Help.mp3", "B"),
+            ("L'Oréal.pdf", "O"),
+            ("Élodie.vcf", "E"),
+            ("(draft) invoice.pdf", "D"),
+            ("Theory.pdf", "T"),
+            ("The.pdf", "T"),
+            ("2024 report.pdf", "0-9"),
+            ("東京.jpg", "#"),
+        ] {
+            assert_eq!(
+                utils::apply_to(&strategy, file_name),
+                Some(letter.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn should_group_letters_into_ranges() {
+        let mut strategy = LetterStrategy::new();
+        strategy.add_parameter(BUCKET_SIZE.to_string(), StrategyParameter::Number(3));
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(
+            utils::apply_to(&strategy, "alice.vcf"),
+            Some("A-C".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "Frank.vcf"),
+            Some("D-F".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "zoe.vcf"),
+            Some("Y-Z".to_string())
+        );
+
+        strategy.add_parameter(BUCKET_SIZE.to_string(), StrategyParameter::Number(5));
+        assert_eq!(utils::apply_to(&strategy, "zoe.vcf"), Some("Z".to_string()));
+
+        strategy.add_parameter(BUCKET_SIZE.to_string(), StrategyParameter::Number(0));
+        assert!(strategy.validate().is_err());
+        assert_eq!(utils::apply_to(&strategy, "zoe.vcf"), Some("Z".to_string()));
+
+        strategy.add_parameter(BUCKET_SIZE.to_string(), StrategyParameter::Number(256));
+        assert!(strategy.validate().is_err());
+        assert_eq!(
+            utils::apply_to(&strategy, "zoe.vcf"),
+            Some("A-Z".to_string())
+        );
+    }
+
+    #[test]
+    fn should_use_the_given_articles() {
+        let mut strategy = LetterStrategy::new();
+        strategy.add_parameter(
+            ARTICLES.to_string(),
+            StrategyParameter::List(vec!["Los".to_string()]),
+        );
+
+        assert_eq!(
+            utils::apply_to(&strategy, "Los Lobos.mp3"),
+            Some("L".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "The Beatles.mp3"),
+            Some("T".to_string())
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    core::{context, parameter, strategy, validation},
+    sorting_strategies::{
+        file::mime::{self, Detection},
+        utils,
+    },
+};
+
+/// Names the folder after the MIME type of the file, such as `image-png`, read from its content
+/// or its extension according to the `detection` parameter. Files that are not recognised are
+/// `application-octet-stream`.
+#[derive(Clone, Debug)]
+pub struct MimeTypeStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, parameter::StrategyParameter>,
+}
+
+impl MimeTypeStrategy {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(mime::detection_parameter_detail());
+
+        let parameters = validator.default_parameters();
+
+        MimeTypeStrategy {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Apply for MimeTypeStrategy {
+    fn apply(&self, file_path: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let detection = Detection::from_parameters(&self.parameters);
+        let mime = mime::detect(detection, file_path, f).unwrap_or(mime::OCTET_STREAM);
+
+        Some(utils::folder_name(mime))
+    }
+}
+
+impl strategy::Validate for MimeTypeStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)
+    }
+}
+
+impl strategy::Name for MimeTypeStrategy {
+    fn name(&self) -> String {
+        "mime type".to_string()
+    }
+}
+
+impl strategy::AddParameter for MimeTypeStrategy {
+    fn add_parameter(&mut self, key: String, value: parameter::StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::ParameterDetails for MimeTypeStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for MimeTypeStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for MimeTypeStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, parameter::StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::strategy::Validate;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n...";
+
+    fn mime_type(detection: Detection) -> MimeTypeStrategy {
+        utils::with_parameters(
+            MimeTypeStrategy::new(),
+            vec![(mime::DETECTION_PARAMETER_NAME, &String::from(detection))],
+        )
+    }
+
+    #[test]
+    fn should_follow_the_detection() {
+        for (detection, folder) in [
+            (Detection::Content, "image-png"),
+            (Detection::Extension, "image-jpeg"),
+            (Detection::ContentThenExtension, "image-png"),
+        ] {
+            let strategy = mime_type(detection);
+
+            assert!(strategy.validate().is_ok());
+            assert_eq!(
+                utils::apply_to_content(&strategy, "photo.jpg", PNG),
+                Some(folder.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn should_put_unknown_files_in_octet_stream() {
+        assert_eq!(
+            utils::apply_to_content(&MimeTypeStrategy::new(), "data.unknown", b"\0\x01\x02"),
+            Some("application-octet-stream".to_string())
+        );
+        assert_eq!(
+            utils::apply_to_content(&mime_type(Detection::Extension), "README", PNG),
+            Some("application-octet-stream".to_string())
+        );
+    }
+}
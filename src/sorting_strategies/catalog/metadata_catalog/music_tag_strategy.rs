@@ -0,0 +1,152 @@
+use crate::{
+    core::{
+        context,
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{
+        file::audio_tags::{AudioField, AudioTags},
+        utils,
+    },
+};
+
+const FIELD: &str = "field";
+
+/// Names the folder after a tag of a music file (ID3, Vorbis comments or MP4 atoms), so that a
+/// stack of `artist` then `album` rebuilds a music library. Years keep their 4 digits and tracks
+/// are padded to 2 digits. Files without the chosen `field` give no folder.
+#[derive(Clone, Debug)]
+pub struct MusicTagStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl MusicTagStrategy {
+    pub fn new() -> Self {
+        let mut field_detail = validation::ParameterDetail::new(
+            FIELD,
+            StrategyParameterKind::Choice(AudioField::all().into_iter().map(Into::into).collect()),
+            false,
+        );
+        field_detail.with_default_value(StrategyParameter::SingleString(
+            AudioField::default().into(),
+        ));
+
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(field_detail);
+
+        let parameters = validator.default_parameters();
+
+        MusicTagStrategy {
+            validator,
+            parameters,
+        }
+    }
+
+    fn field(&self) -> AudioField {
+        match self.parameters.get(FIELD) {
+            Some(StrategyParameter::SingleString(field)) => AudioField::from(field.clone()),
+            _ => AudioField::default(),
+        }
+    }
+}
+
+impl strategy::Apply for MusicTagStrategy {
+    fn apply(&self, _: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let tags = AudioTags::read(f)?;
+
+        tags.get(self.field()).map(utils::folder_name)
+    }
+}
+
+impl strategy::Name for MusicTagStrategy {
+    fn name(&self) -> String {
+        "music tag".to_string()
+    }
+}
+
+impl strategy::AddParameter for MusicTagStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for MusicTagStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)
+    }
+}
+
+impl strategy::ParameterDetails for MusicTagStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for MusicTagStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for MusicTagStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::strategy::{AddParameter, Validate},
+        sorting_strategies::file::audio_tags::tests::flac,
+    };
+
+    #[test]
+    fn should_name_the_folder_after_the_chosen_tag() {
+        let content = flac(&["ARTIST=AC/DC", "ALBUM=Back in Black", "TRACKNUMBER=6"]);
+
+        assert_eq!(
+            utils::apply_to_content(&MusicTagStrategy::new(), "song.flac", &content),
+            Some("AC-DC".to_string())
+        );
+        for (field, folder) in [("album", "Back in Black"), ("track", "06")] {
+            let strategy = utils::with_parameters(MusicTagStrategy::new(), vec![(FIELD, field)]);
+
+            assert!(strategy.validate().is_ok());
+            assert_eq!(
+                utils::apply_to_content(&strategy, "song.flac", &content),
+                Some(folder.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn should_give_no_folder_without_the_tag() {
+        let strategy = utils::with_parameters(MusicTagStrategy::new(), vec![(FIELD, "genre")]);
+
+        assert_eq!(
+            utils::apply_to_content(&strategy, "song.flac", &flac(&["ARTIST=AC/DC"])),
+            None
+        );
+        assert_eq!(
+            utils::apply_to_content(&strategy, "song.mp3", b"not a song"),
+            None
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_fields() {
+        let mut strategy = MusicTagStrategy::new();
+        strategy.add_parameter(
+            FIELD.to_string(),
+            StrategyParameter::SingleString("mood".to_string()),
+        );
+
+        assert!(strategy.validate().is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    core::{
+        context,
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const DEPTH: &str = "depth";
+const SKIP: &str = "skip";
+
+/// Names the folders after the ones holding the file in the input directory, so that the original
+/// structure is kept below the other strategies. The first `skip` folders are dropped and at most
+/// `depth` of the following ones are kept. Files at the root of the input directory give no folder.
+#[derive(Clone, Debug)]
+pub struct RelativePathStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+    input_dir: PathBuf,
+}
+
+impl RelativePathStrategy {
+    pub fn new() -> Self {
+        let mut skip_detail =
+            validation::ParameterDetail::new(SKIP, StrategyParameterKind::Number, false);
+        skip_detail.with_default_value(StrategyParameter::Number(0));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                DEPTH,
+                StrategyParameterKind::Number,
+                false,
+            ))
+            .add_validator(skip_detail);
+
+        let parameters = validator.default_parameters();
+
+        RelativePathStrategy {
+            validator,
+            parameters,
+            input_dir: PathBuf::new(),
+        }
+    }
+
+    /// Folder of the file relative to the input directory, compared as written and else
+    /// canonicalised.
+    fn relative_parent(&self, file_path: &Path) -> Option<PathBuf> {
+        let parent = file_path.parent()?;
+
+        parent
+            .strip_prefix(&self.input_dir)
+            .map(Path::to_path_buf)
+            .ok()
+            .or_else(|| {
+                let parent = parent.canonicalize().ok()?;
+                let input_dir = self.input_dir.canonicalize().ok()?;
+                parent.strip_prefix(input_dir).map(Path::to_path_buf).ok()
+            })
+    }
+}
+
+impl strategy::Apply for RelativePathStrategy {
+    fn apply(&self, file_path: &std::path::PathBuf, _: &std::fs::File) -> Option<String> {
+        let relative = self.relative_parent(file_path)?;
+        let folders = relative
+            .iter()
+            .map(|folder| folder.to_string_lossy().to_string())
+            .skip(utils::number_parameter(&self.parameters, SKIP).unwrap_or_default())
+            .take(utils::number_parameter(&self.parameters, DEPTH).unwrap_or(usize::MAX))
+            .collect::<Vec<_>>();
+
+        (!folders.is_empty()).then(|| folders.join("/"))
+    }
+}
+
+impl strategy::Name for RelativePathStrategy {
+    fn name(&self) -> String {
+        "relative path".to_string()
+    }
+}
+
+impl strategy::AddParameter for RelativePathStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for RelativePathStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        if utils::number_parameter(&self.parameters, DEPTH) == Some(0) {
+            return Err(validation::error::Error::InvalidValue(
+                DEPTH.to_string(),
+                "keeps no folder".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for RelativePathStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for RelativePathStrategy {
+    fn process_context(
+        &mut self,
+        context: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        self.input_dir = context.input_dir();
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for RelativePathStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        context::ProcessContext,
+        strategy::{AddParameter, Validate},
+    };
+
+    fn relative_path(parameters: Vec<(&str, usize)>) -> RelativePathStrategy {
+        let mut strategy = RelativePathStrategy::new();
+        for (key, value) in parameters {
+            strategy.add_parameter(key.to_string(), StrategyParameter::Number(value));
+        }
+        strategy
+            .process_context(
+                context::StrategyContext::new(vec![]).with_input_dir(PathBuf::from("input")),
+            )
+            .unwrap();
+
+        strategy
+    }
+
+    #[test]
+    fn should_keep_the_folders_below_the_input_directory() {
+        assert_eq!(
+            utils::apply_to(&relative_path(vec![]), "input/photos/2023/trip/a.jpg"),
+            Some("photos/2023/trip".to_string())
+        );
+        assert_eq!(utils::apply_to(&relative_path(vec![]), "input/a.jpg"), None);
+        assert_eq!(
+            utils::apply_to(&relative_path(vec![]), "elsewhere/a.jpg"),
+            None
+        );
+    }
+
+    #[test]
+    fn should_slice_the_folders() {
+        let file_path = "input/photos/2023/trip/a.jpg";
+
+        assert_eq!(
+            utils::apply_to(&relative_path(vec![(DEPTH, 1)]), file_path),
+            Some("photos".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&relative_path(vec![(SKIP, 1), (DEPTH, 1)]), file_path),
+            Some("2023".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&relative_path(vec![(SKIP, 1)]), file_path),
+            Some("2023/trip".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&relative_path(vec![(SKIP, 3)]), file_path),
+            None
+        );
+    }
+
+    #[test]
+    fn should_reject_a_depth_of_zero() {
+        let mut strategy = RelativePathStrategy::new();
+        strategy.add_parameter(DEPTH.to_string(), StrategyParameter::Number(0));
+
+        assert!(strategy.validate().is_err());
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{
+    core::{
+        context,
+        parameter::{StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::{file::hash::Algorithm, utils},
+};
+
+const SOURCE: &str = "source";
+const ALGORITHM: &str = "algorithm";
+const LENGTH: &str = "length";
+const LEVELS: &str = "levels";
+
+const NAME: &str = "name";
+const CONTENT: &str = "content";
+
+/// Spreads the files evenly across folders named after the start of a hash of their name or
+/// content, such as `ab/cd` with two `levels` of `length` 2. The same file always goes to the
+/// same folder, and hashing the content also gathers the duplicates.
+#[derive(Clone, Debug)]
+pub struct ShardStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl ShardStrategy {
+    pub fn new() -> Self {
+        let mut source_detail = validation::ParameterDetail::new(
+            SOURCE,
+            StrategyParameterKind::Choice(vec![NAME.to_string(), CONTENT.to_string()]),
+            false,
+        );
+        source_detail.with_default_value(StrategyParameter::SingleString(NAME.to_string()));
+
+        let mut algorithm_detail = validation::ParameterDetail::new(
+            ALGORITHM,
+            StrategyParameterKind::Choice(Algorithm::all().into_iter().map(Into::into).collect()),
+            false,
+        );
+        algorithm_detail
+            .with_default_value(StrategyParameter::SingleString(Algorithm::default().into()));
+
+        let mut length_detail =
+            validation::ParameterDetail::new(LENGTH, StrategyParameterKind::Number, false);
+        length_detail.with_default_value(StrategyParameter::Number(2));
+
+        let mut levels_detail =
+            validation::ParameterDetail::new(LEVELS, StrategyParameterKind::Number, false);
+        levels_detail.with_default_value(StrategyParameter::Number(2));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(source_detail)
+            .add_validator(algorithm_detail)
+            .add_validator(length_detail)
+            .add_validator(levels_detail);
+
+        let parameters = validator.default_parameters();
+
+        ShardStrategy {
+            validator,
+            parameters,
+        }
+    }
+
+    fn algorithm(&self) -> Algorithm {
+        match self.parameters.get(ALGORITHM) {
+            Some(StrategyParameter::SingleString(algorithm)) => Algorithm::from(algorithm.clone()),
+            _ => Algorithm::default(),
+        }
+    }
+}
+
+impl strategy::Apply for ShardStrategy {
+    fn apply(&self, file_path: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        let algorithm = self.algorithm();
+        let digest = match self.parameters.get(SOURCE) {
+            Some(StrategyParameter::SingleString(source)) if source == CONTENT => {
+                algorithm.digest_file(f).ok()?
+            }
+            _ => algorithm.digest(file_path.file_name()?.as_encoded_bytes()),
+        };
+
+        let length = utils::number_parameter(&self.parameters, LENGTH)
+            .unwrap_or(0)
+            .max(1);
+        let folders = digest
+            .as_bytes()
+            .chunks(length)
+            .take(utils::number_parameter(&self.parameters, LEVELS).unwrap_or(0))
+            .map(|chunk| String::from_utf8_lossy(chunk).to_string())
+            .collect::<Vec<_>>();
+
+        (!folders.is_empty()).then(|| folders.join("/"))
+    }
+}
+
+impl strategy::Name for ShardStrategy {
+    fn name(&self) -> String {
+        "shard".to_string()
+    }
+}
+
+impl strategy::AddParameter for ShardStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for ShardStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        for name in [LENGTH, LEVELS] {
+            if utils::number_parameter(&self.parameters, name).unwrap_or(0) == 0 {
+                return Err(validation::error::Error::InvalidValue(
+                    name.to_string(),
+                    "must be at least 1".to_string(),
+                ));
+            }
+        }
+
+        let algorithm = self.algorithm();
+        let characters = utils::number_parameter(&self.parameters, LENGTH)
+            .unwrap_or(0)
+            .checked_mul(utils::number_parameter(&self.parameters, LEVELS).unwrap_or(0));
+        if characters.is_none_or(|characters| characters > algorithm.length()) {
+            return Err(validation::error::Error::InvalidValue(
+                LENGTH.to_string(),
+                format!(
+                    "{} digests only have {} characters to split into levels",
+                    String::from(algorithm),
+                    algorithm.length()
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for ShardStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for ShardStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for ShardStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::strategy::{AddParameter, Validate};
+
+    fn shard(parameters: Vec<(&str, StrategyParameter)>) -> ShardStrategy {
+        let mut strategy = ShardStrategy::new();
+        for (key, value) in parameters {
+            strategy.add_parameter(key.to_string(), value);
+        }
+
+        strategy
+    }
+
+    #[test]
+    fn should_shard_by_name() {
+        let strategy = shard(vec![]);
+
+        assert!(strategy.validate().is_ok());
+        // sha256("abc") starts with ba7816bf.
+        assert_eq!(
+            utils::apply_to(&strategy, "cache/abc"),
+            Some("ba/78".to_string())
+        );
+        assert_eq!(
+            utils::apply_to(&strategy, "elsewhere/abc"),
+            Some("ba/78".to_string())
+        );
+    }
+
+    #[test]
+    fn should_follow_algorithm_length_and_levels() {
+        let strategy = shard(vec![
+            (
+                ALGORITHM,
+                StrategyParameter::SingleString("crc32".to_string()),
+            ),
+            (LENGTH, StrategyParameter::Number(3)),
+            (LEVELS, StrategyParameter::Number(1)),
+        ]);
+
+        assert_eq!(
+            utils::apply_to(&strategy, "123456789"),
+            Some("cbf".to_string())
+        );
+    }
+
+    #[test]
+    fn should_shard_by_content() {
+        let strategy = shard(vec![(
+            SOURCE,
+            StrategyParameter::SingleString(CONTENT.to_string()),
+        )]);
+        let digest = Algorithm::Sha256
+            .digest_file(&utils::manifest_file())
+            .unwrap();
+
+        assert_eq!(
+            utils::apply_to(&strategy, "any name"),
+            Some(format!("{}/{}", &digest[0..2], &digest[2..4]))
+        );
+    }
+
+    #[test]
+    fn should_reject_shards_longer_than_the_digest() {
+        let strategy = shard(vec![
+            (
+                ALGORITHM,
+                StrategyParameter::SingleString("crc32".to_string()),
+            ),
+            (LENGTH, StrategyParameter::Number(3)),
+            (LEVELS, StrategyParameter::Number(3)),
+        ]);
+        assert!(strategy.validate().is_err());
+
+        let strategy = shard(vec![(LEVELS, StrategyParameter::Number(0))]);
+        assert!(strategy.validate().is_err());
+
+        let strategy = shard(vec![
+            (LENGTH, StrategyParameter::Number(usize::MAX)),
+            (LEVELS, StrategyParameter::Number(2)),
+        ]);
+        assert!(strategy.validate().is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    core::{
+        context,
+        parameter::{self, StrategyParameter, StrategyParameterKind},
+        strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const THRESHOLDS: &str = "thresholds";
+const LABELS: &str = "labels";
+
+const DEFAULT_THRESHOLDS: &str = "100KB,10MB,100MB,1GB";
+const DEFAULT_LABELS: &str = "tiny,small,medium,large,huge";
+
+/// Places the files in buckets by size: a file smaller than the first of the `thresholds` goes
+/// to the first of the `labels`, and a file at least as large as the last threshold goes to the
+/// last label. There is therefore one more label than thresholds.
+#[derive(Clone, Debug)]
+pub struct SizeStrategy {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl SizeStrategy {
+    pub fn new() -> Self {
+        let mut thresholds_detail =
+            validation::ParameterDetail::new(THRESHOLDS, StrategyParameterKind::Size, false);
+        thresholds_detail.with_default_value(StrategyParameter::SingleString(
+            DEFAULT_THRESHOLDS.to_string(),
+        ));
+
+        let mut labels_detail =
+            validation::ParameterDetail::new(LABELS, StrategyParameterKind::SingleString, false);
+        labels_detail
+            .with_default_value(StrategyParameter::SingleString(DEFAULT_LABELS.to_string()));
+
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(thresholds_detail)
+            .add_validator(labels_detail);
+
+        let parameters = validator.default_parameters();
+
+        SizeStrategy {
+            validator,
+            parameters,
+        }
+    }
+
+    fn thresholds(&self) -> Vec<u64> {
+        let thresholds = utils::string_parameter(&self.parameters, THRESHOLDS)
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_THRESHOLDS);
+
+        parameter::parse_sizes(thresholds).unwrap_or_default()
+    }
+
+    fn labels(&self) -> Vec<&str> {
+        utils::string_parameter(&self.parameters, LABELS)
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_LABELS)
+            .split(',')
+            .map(str::trim)
+            .collect()
+    }
+
+    fn bucket(&self, size: u64) -> Option<String> {
+        let index = self
+            .thresholds()
+            .iter()
+            .position(|threshold| size < *threshold)
+            .unwrap_or(self.labels().len().saturating_sub(1));
+
+        self.labels().get(index).map(|label| label.to_string())
+    }
+}
+
+impl strategy::Apply for SizeStrategy {
+    fn apply(&self, _: &std::path::PathBuf, f: &std::fs::File) -> Option<String> {
+        self.bucket(f.metadata().ok()?.len())
+    }
+}
+
+impl strategy::Name for SizeStrategy {
+    fn name(&self) -> String {
+        "size".to_string()
+    }
+}
+
+impl strategy::AddParameter for SizeStrategy {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for SizeStrategy {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        let thresholds = self.thresholds();
+        if thresholds.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(validation::error::Error::InvalidValue(
+                THRESHOLDS.to_string(),
+                "the thresholds must be sorted from the smallest to the largest".to_string(),
+            ));
+        }
+
+        let labels = self.labels();
+        if labels.len() != thresholds.len() + 1 || labels.iter().any(|label| label.is_empty()) {
+            return Err(validation::error::Error::InvalidValue(
+                LABELS.to_string(),
+                format!(
+                    "{} thresholds need {} non empty labels",
+                    thresholds.len(),
+                    thresholds.len() + 1
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for SizeStrategy {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl context::ProcessContext for SizeStrategy {
+    fn process_context(
+        &mut self,
+        _: context::StrategyContext,
+    ) -> Result<(), crate::core::error::Error> {
+        Ok(())
+    }
+}
+
+impl strategy::Parameters for SizeStrategy {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::strategy::{AddParameter, Validate};
+
+    fn size(thresholds: &str, labels: &str) -> SizeStrategy {
+        let mut strategy = SizeStrategy::new();
+        strategy.add_parameter(
+            THRESHOLDS.to_string(),
+            StrategyParameter::SingleString(thresholds.to_string()),
+        );
+        strategy.add_parameter(
+            LABELS.to_string(),
+            StrategyParameter::SingleString(labels.to_string()),
+        );
+
+        strategy
+    }
+
+    #[test]
+    fn should_place_files_in_default_buckets() {
+        let strategy = SizeStrategy::new();
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(strategy.bucket(0), Some("tiny".to_string()));
+        assert_eq!(strategy.bucket(100_000), Some("small".to_string()));
+        assert_eq!(strategy.bucket(5_000_000_000), Some("huge".to_string()));
+    }
+
+    #[test]
+    fn should_use_custom_thresholds_and_labels() {
+        let strategy = size("10MB, 1GiB", "keep, review, archive");
+
+        assert!(strategy.validate().is_ok());
+        assert_eq!(strategy.bucket(9_999_999), Some("keep".to_string()));
+        assert_eq!(strategy.bucket(1 << 30), Some("archive".to_string()));
+    }
+
+    #[test]
+    fn should_reject_inconsistent_parameters() {
+        assert!(size("1GB,10MB", "a,b,c").validate().is_err());
+        assert!(size("10MB,1GB", "a,b").validate().is_err());
+        assert!(size("10 parsecs", "a,b").validate().is_err());
+    }
+}
@@ -0,0 +1,753 @@
+//! Reader of the tags of music files, without any dependency.
+//!
+//! MP3 files carry ID3v2 tags at their start and ID3v1 tags in their last 128 bytes, FLAC and
+//! OGG (Vorbis or Opus) files carry Vorbis comments, and MP4 (M4A) files carry `ilst` atoms.
+//! Every read is bounded, so that corrupt or truncated files give no tags rather than a panic.
+
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, SeekFrom},
+};
+
+const ID3V1_LENGTH: u64 = 128;
+const MAX_ID3V2_LENGTH: usize = 1024 * 1024;
+const MAX_FLAC_BLOCKS: usize = 64;
+const MAX_OGG_PAGES: usize = 16;
+const MAX_MP4_ATOMS: usize = 256;
+const MAX_MP4_DEPTH: usize = 5;
+const MAX_TEXT_LENGTH: usize = 64 * 1024;
+
+const FLAC_VORBIS_COMMENT: u8 = 4;
+
+/// Genres of ID3v1, referenced by their index in ID3v2 and MP4 tags.
+const GENRES: [&str; 80] = [
+    "Blues",
+    "Classic Rock",
+    "Country",
+    "Dance",
+    "Disco",
+    "Funk",
+    "Grunge",
+    "Hip-Hop",
+    "Jazz",
+    "Metal",
+    "New Age",
+    "Oldies",
+    "Other",
+    "Pop",
+    "R&B",
+    "Rap",
+    "Reggae",
+    "Rock",
+    "Techno",
+    "Industrial",
+    "Alternative",
+    "Ska",
+    "Death Metal",
+    "Pranks",
+    "Soundtrack",
+    "Euro-Techno",
+    "Ambient",
+    "Trip-Hop",
+    "Vocal",
+    "Jazz+Funk",
+    "Fusion",
+    "Trance",
+    "Classical",
+    "Instrumental",
+    "Acid",
+    "House",
+    "Game",
+    "Sound Clip",
+    "Gospel",
+    "Noise",
+    "Alternative Rock",
+    "Bass",
+    "Soul",
+    "Punk",
+    "Space",
+    "Meditative",
+    "Instrumental Pop",
+    "Instrumental Rock",
+    "Ethnic",
+    "Gothic",
+    "Darkwave",
+    "Techno-Industrial",
+    "Electronic",
+    "Pop-Folk",
+    "Eurodance",
+    "Dream",
+    "Southern Rock",
+    "Comedy",
+    "Cult",
+    "Gangsta",
+    "Top 40",
+    "Christian Rap",
+    "Pop/Funk",
+    "Jungle",
+    "Native American",
+    "Cabaret",
+    "New Wave",
+    "Psychedelic",
+    "Rave",
+    "Showtunes",
+    "Trailer",
+    "Lo-Fi",
+    "Tribal",
+    "Acid Punk",
+    "Acid Jazz",
+    "Polka",
+    "Retro",
+    "Musical",
+    "Rock & Roll",
+    "Hard Rock",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AudioField {
+    #[default]
+    Artist,
+    AlbumArtist,
+    Album,
+    Genre,
+    Year,
+    Track,
+}
+
+impl From<AudioField> for String {
+    fn from(field: AudioField) -> Self {
+        match field {
+            AudioField::Artist => "artist".to_string(),
+            AudioField::AlbumArtist => "album artist".to_string(),
+            AudioField::Album => "album".to_string(),
+            AudioField::Genre => "genre".to_string(),
+            AudioField::Year => "year".to_string(),
+            AudioField::Track => "track".to_string(),
+        }
+    }
+}
+
+impl From<String> for AudioField {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "album artist" => AudioField::AlbumArtist,
+            "album" => AudioField::Album,
+            "genre" => AudioField::Genre,
+            "year" => AudioField::Year,
+            "track" => AudioField::Track,
+            _ => AudioField::Artist,
+        }
+    }
+}
+
+impl AudioField {
+    pub fn all() -> Vec<AudioField> {
+        vec![
+            AudioField::Artist,
+            AudioField::AlbumArtist,
+            AudioField::Album,
+            AudioField::Genre,
+            AudioField::Year,
+            AudioField::Track,
+        ]
+    }
+
+    fn from_id3v2(frame: &[u8]) -> Option<AudioField> {
+        match frame {
+            b"TPE1" | b"TP1" => Some(AudioField::Artist),
+            b"TPE2" | b"TP2" => Some(AudioField::AlbumArtist),
+            b"TALB" | b"TAL" => Some(AudioField::Album),
+            b"TCON" | b"TCO" => Some(AudioField::Genre),
+            b"TDRC" | b"TYER" | b"TYE" => Some(AudioField::Year),
+            b"TRCK" | b"TRK" => Some(AudioField::Track),
+            _ => None,
+        }
+    }
+
+    fn from_vorbis(key: &str) -> Option<AudioField> {
+        match key.to_uppercase().as_str() {
+            "ARTIST" => Some(AudioField::Artist),
+            "ALBUMARTIST" | "ALBUM ARTIST" | "ALBUM_ARTIST" => Some(AudioField::AlbumArtist),
+            "ALBUM" => Some(AudioField::Album),
+            "GENRE" => Some(AudioField::Genre),
+            "DATE" | "YEAR" => Some(AudioField::Year),
+            "TRACKNUMBER" => Some(AudioField::Track),
+            _ => None,
+        }
+    }
+
+    fn from_mp4(atom: &[u8]) -> Option<AudioField> {
+        match atom {
+            b"\xA9ART" => Some(AudioField::Artist),
+            b"aART" => Some(AudioField::AlbumArtist),
+            b"\xA9alb" => Some(AudioField::Album),
+            b"\xA9gen" | b"gnre" => Some(AudioField::Genre),
+            b"\xA9day" => Some(AudioField::Year),
+            b"trkn" => Some(AudioField::Track),
+            _ => None,
+        }
+    }
+}
+
+/// Tags of a music file, such as its artist or its album.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AudioTags {
+    fields: HashMap<AudioField, String>,
+}
+
+impl AudioTags {
+    /// Tags of the music file read by `reader`, or `None` when it doesn't have any.
+    pub fn read<R: Read + Seek>(mut reader: R) -> Option<AudioTags> {
+        let mut start = [0; 12];
+        reader.seek(SeekFrom::Start(0)).ok()?;
+        reader.read_exact(&mut start).ok()?;
+
+        let mut tags = AudioTags::default();
+        match &start {
+            [b'I', b'D', b'3', ..] => {
+                tags.read_id3v2(&mut reader);
+                tags.read_id3v1(&mut reader);
+            }
+            [b'f', b'L', b'a', b'C', ..] => tags.read_flac(&mut reader),
+            [b'O', b'g', b'g', b'S', ..] => tags.read_ogg(&mut reader),
+            [_, _, _, _, b'f', b't', b'y', b'p', ..] => tags.read_mp4(&mut reader),
+            _ => tags.read_id3v1(&mut reader),
+        };
+
+        (!tags.fields.is_empty()).then_some(tags)
+    }
+
+    pub fn get(&self, field: AudioField) -> Option<&str> {
+        self.fields.get(&field).map(String::as_str)
+    }
+
+    /// Keeps the first value read for a field, normalising the genres given by index, the dates
+    /// and the track numbers.
+    fn insert(&mut self, field: AudioField, value: &str) {
+        let value = value.trim_matches(|c: char| c == '\0' || c.is_whitespace());
+        let value = match field {
+            AudioField::Genre => genre(value),
+            AudioField::Year => {
+                let year: String = value.chars().take(4).collect();
+                (year.len() == 4 && year.chars().all(|c| c.is_ascii_digit())).then_some(year)
+            }
+            AudioField::Track => value
+                .split('/')
+                .next()
+                .and_then(|track| track.trim().parse::<u32>().ok())
+                .filter(|track| *track > 0)
+                .map(|track| format!("{track:02}")),
+            _ => Some(value.to_string()),
+        };
+
+        if let Some(value) = value.filter(|value| !value.is_empty()) {
+            self.fields.entry(field).or_insert(value);
+        }
+    }
+
+    fn read_id3v1<R: Read + Seek>(&mut self, reader: &mut R) {
+        let mut tag = [0; ID3V1_LENGTH as usize];
+        let read = reader
+            .seek(SeekFrom::End(-(ID3V1_LENGTH as i64)))
+            .and_then(|_| reader.read_exact(&mut tag));
+        if read.is_err() || &tag[..3] != b"TAG" {
+            return;
+        }
+
+        self.insert(AudioField::Artist, &latin1(&tag[33..63]));
+        self.insert(AudioField::Album, &latin1(&tag[63..93]));
+        self.insert(AudioField::Year, &latin1(&tag[93..97]));
+        // ID3v1.1 stores the track in the last byte of the comment, after a zero.
+        if tag[125] == 0 && tag[126] != 0 {
+            self.insert(AudioField::Track, &tag[126].to_string());
+        }
+        if let Some(genre) = GENRES.get(tag[127] as usize) {
+            self.insert(AudioField::Genre, genre);
+        }
+    }
+
+    fn read_id3v2<R: Read + Seek>(&mut self, reader: &mut R) {
+        let mut header = [0; 10];
+        if reader
+            .seek(SeekFrom::Start(0))
+            .and_then(|_| reader.read_exact(&mut header))
+            .is_err()
+        {
+            return;
+        }
+
+        let version = header[3];
+        let flags = header[5];
+        let length = (syncsafe(&header[6..10]) as usize).min(MAX_ID3V2_LENGTH);
+        let mut body = vec![0; length];
+        if reader.read_exact(&mut body).is_err() {
+            return;
+        }
+        if flags & 0x80 != 0 {
+            body = remove_unsynchronisation(&body);
+        }
+
+        let mut position = 0;
+        if flags & 0x40 != 0 && version >= 3 {
+            position = match (version, body.get(..4)) {
+                (3, Some(size)) => 4 + u32::from_be_bytes([size[0], size[1], size[2], size[3]]),
+                (_, Some(size)) => syncsafe(size),
+                _ => return,
+            } as usize;
+        }
+
+        let (id_length, header_length) = if version == 2 { (3, 6) } else { (4, 10) };
+        while let Some(frame_header) = body.get(position..position + header_length) {
+            let id = &frame_header[..id_length];
+            if id[0] == 0 {
+                break;
+            }
+            let size = match version {
+                2 => u32::from_be_bytes([0, frame_header[3], frame_header[4], frame_header[5]]),
+                3 => u32::from_be_bytes([
+                    frame_header[4],
+                    frame_header[5],
+                    frame_header[6],
+                    frame_header[7],
+                ]),
+                _ => syncsafe(&frame_header[4..8]),
+            } as usize;
+
+            let start = position + header_length;
+            let Some(frame) = start.checked_add(size).and_then(|end| body.get(start..end)) else {
+                break;
+            };
+            if let Some(field) = AudioField::from_id3v2(id) {
+                self.insert(field, &id3v2_text(frame));
+            }
+            position = start + size;
+        }
+    }
+
+    fn read_flac<R: Read + Seek>(&mut self, reader: &mut R) {
+        let mut position = 4;
+        for _ in 0..MAX_FLAC_BLOCKS {
+            let mut header = [0; 4];
+            if reader
+                .seek(SeekFrom::Start(position))
+                .and_then(|_| reader.read_exact(&mut header))
+                .is_err()
+            {
+                return;
+            }
+
+            let length = u32::from_be_bytes([0, header[1], header[2], header[3]]) as u64;
+            if header[0] & 0x7F == FLAC_VORBIS_COMMENT {
+                let mut block = vec![0; length as usize];
+                if reader.read_exact(&mut block).is_ok() {
+                    self.read_vorbis_comment(&block);
+                }
+                return;
+            }
+            if header[0] & 0x80 != 0 {
+                return;
+            }
+            position += 4 + length;
+        }
+    }
+
+    /// Reads the comment packet of an OGG stream, found in the body of its first pages.
+    fn read_ogg<R: Read + Seek>(&mut self, reader: &mut R) {
+        let mut packets = vec![];
+        let mut position = 0;
+        for _ in 0..MAX_OGG_PAGES {
+            let mut header = [0; 27];
+            if reader
+                .seek(SeekFrom::Start(position))
+                .and_then(|_| reader.read_exact(&mut header))
+                .is_err()
+                || &header[..4] != b"OggS"
+            {
+                break;
+            }
+
+            let mut segments = vec![0; header[26] as usize];
+            if reader.read_exact(&mut segments).is_err() {
+                break;
+            }
+            let length: u64 = segments.iter().map(|&segment| segment as u64).sum();
+            let mut body = vec![0; length as usize];
+            if reader.read_exact(&mut body).is_err() {
+                break;
+            }
+            packets.extend(body);
+            position += 27 + segments.len() as u64 + length;
+        }
+
+        for signature in [&b"\x03vorbis"[..], b"OpusTags"] {
+            if let Some(index) = packets
+                .windows(signature.len())
+                .position(|window| window == signature)
+            {
+                self.read_vorbis_comment(&packets[index + signature.len()..]);
+                return;
+            }
+        }
+    }
+
+    fn read_vorbis_comment(&mut self, bytes: &[u8]) {
+        // The vendor string, then the number of comments.
+        let mut position = 0;
+        if vorbis_string(bytes, &mut position).is_none() {
+            return;
+        }
+        let Some(count) = bytes.get(position..position + 4) else {
+            return;
+        };
+        let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]);
+        position += 4;
+
+        for _ in 0..count {
+            let Some(comment) = vorbis_string(bytes, &mut position) else {
+                return;
+            };
+            let comment = String::from_utf8_lossy(&comment[..comment.len().min(MAX_TEXT_LENGTH)]);
+            if let Some((key, value)) = comment.split_once('=') {
+                if let Some(field) = AudioField::from_vorbis(key) {
+                    self.insert(field, value);
+                }
+            }
+        }
+    }
+
+    fn read_mp4<R: Read + Seek>(&mut self, reader: &mut R) {
+        let Ok(end) = reader.seek(SeekFrom::End(0)) else {
+            return;
+        };
+        let mut atoms = 0;
+        self.read_mp4_atoms(reader, 0, end, 0, &mut atoms);
+    }
+
+    /// Walks down `moov/udta/meta/ilst` to the tag atoms, within `start..end`.
+    fn read_mp4_atoms<R: Read + Seek>(
+        &mut self,
+        reader: &mut R,
+        start: u64,
+        end: u64,
+        depth: usize,
+        atoms: &mut usize,
+    ) {
+        let mut position = start;
+        while position + 8 <= end && *atoms < MAX_MP4_ATOMS {
+            *atoms += 1;
+            let mut header = [0; 8];
+            if reader
+                .seek(SeekFrom::Start(position))
+                .and_then(|_| reader.read_exact(&mut header))
+                .is_err()
+            {
+                return;
+            }
+
+            let size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+            let kind = &header[4..8];
+            let atom_end = match size {
+                0 => end,
+                size if size < 8 => return,
+                size => (position + size).min(end),
+            };
+
+            match kind {
+                b"moov" | b"udta" if depth < MAX_MP4_DEPTH => {
+                    self.read_mp4_atoms(reader, position + 8, atom_end, depth + 1, atoms)
+                }
+                // `meta` is a full atom, with a version and flags before its children.
+                b"meta" if depth < MAX_MP4_DEPTH => {
+                    self.read_mp4_atoms(reader, position + 12, atom_end, depth + 1, atoms)
+                }
+                b"ilst" => self.read_mp4_items(reader, position + 8, atom_end, atoms),
+                _ => (),
+            }
+            position = atom_end;
+        }
+    }
+
+    /// Reads the tag items of an `ilst` atom, within `start..end`.
+    fn read_mp4_items<R: Read + Seek>(
+        &mut self,
+        reader: &mut R,
+        start: u64,
+        end: u64,
+        atoms: &mut usize,
+    ) {
+        let mut position = start;
+        while position + 8 <= end && *atoms < MAX_MP4_ATOMS {
+            *atoms += 1;
+            let mut header = [0; 8];
+            if reader
+                .seek(SeekFrom::Start(position))
+                .and_then(|_| reader.read_exact(&mut header))
+                .is_err()
+            {
+                return;
+            }
+
+            let size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+            if size < 8 || position + size > end {
+                return;
+            }
+            if let Some(field) = AudioField::from_mp4(&header[4..8]) {
+                let mut item = vec![0; ((size - 8) as usize).min(MAX_TEXT_LENGTH)];
+                if reader.read_exact(&mut item).is_ok() {
+                    if let Some(value) = mp4_data(field, &header[4..8], &item) {
+                        self.insert(field, &value);
+                    }
+                }
+            }
+            position += size;
+        }
+    }
+}
+
+/// String prefixed with its little endian length, at `position` which is moved past it.
+fn vorbis_string<'a>(bytes: &'a [u8], position: &mut usize) -> Option<&'a [u8]> {
+    let length = bytes.get(*position..*position + 4)?;
+    let length = u32::from_le_bytes([length[0], length[1], length[2], length[3]]) as usize;
+    let start = *position + 4;
+    let value = bytes.get(start..start.checked_add(length)?)?;
+    *position = start + length;
+
+    Some(value)
+}
+
+/// Value of the `data` atom of an `ilst` item: text, or binary numbers for tracks and genres.
+fn mp4_data(field: AudioField, kind: &[u8], item: &[u8]) -> Option<String> {
+    if item.get(4..8)? != b"data" {
+        return None;
+    }
+    let size = u32::from_be_bytes(item.get(..4)?.try_into().ok()?) as usize;
+    let value = item.get(16..size.min(item.len()))?;
+
+    match (field, kind) {
+        (AudioField::Track, _) => {
+            Some(u16::from_be_bytes(value.get(2..4)?.try_into().ok()?).to_string())
+        }
+        (AudioField::Genre, b"gnre") => {
+            let index = u16::from_be_bytes(value.get(..2)?.try_into().ok()?);
+            GENRES
+                .get((index as usize).checked_sub(1)?)
+                .map(|genre| genre.to_string())
+        }
+        _ => Some(String::from_utf8_lossy(value).to_string()),
+    }
+}
+
+/// Genre written by its name, by its ID3v1 index (`17`) or both (`(17)Rock`).
+fn genre(value: &str) -> Option<String> {
+    let (index, name) = match value
+        .strip_prefix('(')
+        .and_then(|rest| rest.split_once(')'))
+    {
+        Some((index, name)) => (index, name),
+        None => (value, ""),
+    };
+
+    match index.parse::<usize>() {
+        Ok(_) if !name.is_empty() => Some(name.to_string()),
+        Ok(index) => GENRES.get(index).map(|genre| genre.to_string()),
+        Err(_) => Some(value.to_string()),
+    }
+}
+
+fn syncsafe(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .take(4)
+        .fold(0, |size, &byte| (size << 7) | (byte & 0x7F) as u32)
+}
+
+/// Removes the zero inserted after every `0xFF` byte of an unsynchronised ID3v2 tag.
+fn remove_unsynchronisation(bytes: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(bytes.len());
+    for (index, &byte) in bytes.iter().enumerate() {
+        if byte == 0 && index > 0 && bytes[index - 1] == 0xFF {
+            continue;
+        }
+        result.push(byte);
+    }
+    result
+}
+
+fn latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&byte| byte as char).collect()
+}
+
+/// Text of an ID3v2 text frame, in the encoding given by its first byte. Only the first value of
+/// a multi-valued frame is kept.
+fn id3v2_text(frame: &[u8]) -> String {
+    let Some((&encoding, text)) = frame.split_first() else {
+        return String::new();
+    };
+
+    let text = match encoding {
+        0 => latin1(text),
+        1 | 2 => {
+            let little_endian = match text.get(..2) {
+                Some([0xFF, 0xFE]) => true,
+                Some([0xFE, 0xFF]) => false,
+                _ => encoding == 1,
+            };
+            let units: Vec<u16> = text
+                .chunks_exact(2)
+                .map(|unit| {
+                    if little_endian {
+                        u16::from_le_bytes([unit[0], unit[1]])
+                    } else {
+                        u16::from_be_bytes([unit[0], unit[1]])
+                    }
+                })
+                .filter(|&unit| unit != 0xFEFF)
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => String::from_utf8_lossy(text).to_string(),
+    };
+
+    text.split('\0').next().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn id3v2_frame(id: &[u8; 4], encoding: u8, text: &[u8]) -> Vec<u8> {
+        let mut frame = id.to_vec();
+        frame.extend((text.len() as u32 + 1).to_be_bytes());
+        frame.extend([0, 0, encoding]);
+        frame.extend(text);
+        frame
+    }
+
+    fn vorbis_comment(comments: &[&str]) -> Vec<u8> {
+        let mut bytes = 6u32.to_le_bytes().to_vec();
+        bytes.extend(b"vendor");
+        bytes.extend((comments.len() as u32).to_le_bytes());
+        for comment in comments {
+            bytes.extend((comment.len() as u32).to_le_bytes());
+            bytes.extend(comment.as_bytes());
+        }
+        bytes
+    }
+
+    /// FLAC file holding the given Vorbis comments, such as `ARTIST=Daft Punk`.
+    pub(crate) fn flac(comments: &[&str]) -> Vec<u8> {
+        let comment = vorbis_comment(comments);
+        let mut flac = b"fLaC".to_vec();
+        flac.extend([0, 0, 0, 2, 0, 0]);
+        flac.extend([0x80 | FLAC_VORBIS_COMMENT, 0, 0, comment.len() as u8]);
+        flac.extend(&comment);
+        flac
+    }
+
+    fn mp4_atom(kind: &[u8], content: &[u8]) -> Vec<u8> {
+        let mut atom = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        atom.extend(kind);
+        atom.extend(content);
+        atom
+    }
+
+    fn mp4_item(kind: &[u8], kind_flags: u32, value: &[u8]) -> Vec<u8> {
+        let mut data = kind_flags.to_be_bytes().to_vec();
+        data.extend([0; 4]);
+        data.extend(value);
+        mp4_atom(kind, &mp4_atom(b"data", &data))
+    }
+
+    #[test]
+    fn should_read_id3v2_and_id3v1_tags() {
+        let mut body = id3v2_frame(b"TPE1", 3, "Björk".as_bytes());
+        body.extend(id3v2_frame(
+            b"TALB",
+            1,
+            &[0xFF, 0xFE, b'H', 0, b'o', 0, b'm', 0, b'o', 0],
+        ));
+        body.extend(id3v2_frame(b"TCON", 0, b"(17)"));
+        body.extend(id3v2_frame(b"TDRC", 0, b"1997-09-22"));
+
+        let mut bytes = b"ID3\x04\x00\x00".to_vec();
+        bytes.extend([0, 0, (body.len() >> 7) as u8, (body.len() & 0x7F) as u8]);
+        bytes.extend(body);
+        bytes.extend([0; 64]);
+        let mut id3v1 = b"TAG".to_vec();
+        id3v1.extend([0; 125]);
+        id3v1[126] = 7;
+        bytes.extend(id3v1);
+
+        let tags = AudioTags::read(Cursor::new(bytes)).unwrap();
+        assert_eq!(tags.get(AudioField::Artist), Some("Björk"));
+        assert_eq!(tags.get(AudioField::Album), Some("Homo"));
+        assert_eq!(tags.get(AudioField::Genre), Some("Rock"));
+        assert_eq!(tags.get(AudioField::Year), Some("1997"));
+        assert_eq!(tags.get(AudioField::Track), Some("07"));
+    }
+
+    #[test]
+    fn should_read_flac_and_ogg_comments() {
+        let comments = [
+            "ARTIST=Daft Punk",
+            "albumartist=Various",
+            "TRACKNUMBER=3/14",
+        ];
+        let flac = flac(&comments);
+
+        let mut packet = b"\x03vorbis".to_vec();
+        packet.extend(vorbis_comment(&comments));
+        let mut ogg = b"OggS".to_vec();
+        ogg.extend([0; 22]);
+        ogg.push(1);
+        ogg.push(packet.len() as u8);
+        ogg.extend(packet);
+
+        for bytes in [flac, ogg] {
+            let tags = AudioTags::read(Cursor::new(bytes)).unwrap();
+            assert_eq!(tags.get(AudioField::Artist), Some("Daft Punk"));
+            assert_eq!(tags.get(AudioField::AlbumArtist), Some("Various"));
+            assert_eq!(tags.get(AudioField::Track), Some("03"));
+        }
+    }
+
+    #[test]
+    fn should_read_mp4_atoms() {
+        let mut ilst = mp4_item(b"\xA9ART", 1, b"Miles Davis");
+        ilst.extend(mp4_item(b"trkn", 0, &[0, 0, 0, 4, 0, 6, 0, 0]));
+        ilst.extend(mp4_item(b"gnre", 0, &[0, 9]));
+        let mut meta = vec![0; 4];
+        meta.extend(mp4_atom(b"hdlr", &[0; 25]));
+        meta.extend(mp4_atom(b"ilst", &ilst));
+
+        let mut bytes = mp4_atom(b"ftyp", b"M4A \0\0\0\0");
+        bytes.extend(mp4_atom(
+            b"moov",
+            &mp4_atom(b"udta", &mp4_atom(b"meta", &meta)),
+        ));
+
+        let tags = AudioTags::read(Cursor::new(bytes)).unwrap();
+        assert_eq!(tags.get(AudioField::Artist), Some("Miles Davis"));
+        assert_eq!(tags.get(AudioField::Track), Some("04"));
+        assert_eq!(tags.get(AudioField::Genre), Some("Jazz"));
+    }
+
+    #[test]
+    fn should_not_panic_on_corrupt_files() {
+        let comment = vorbis_comment(&["ARTIST=Daft Punk"]);
+        let mut flac = b"fLaC".to_vec();
+        flac.extend([0x80 | FLAC_VORBIS_COMMENT, 0, 0, comment.len() as u8]);
+        flac.extend(&comment);
+
+        for header in [&b"ID3\x03\x00\x40\x7F\x7F\x7F\x7F"[..], b"OggS", &flac] {
+            let mut bytes = header.to_vec();
+            bytes.extend([0xFF; 32]);
+            for length in 0..bytes.len() {
+                let _ = AudioTags::read(Cursor::new(&bytes[..length]));
+            }
+        }
+
+        assert_eq!(AudioTags::read(Cursor::new(b"plain text")), None);
+    }
+}
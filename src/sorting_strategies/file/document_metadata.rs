@@ -0,0 +1,715 @@
+//! Reader of the metadata of documents: the Info dictionary and the XMP packet of PDF files, and
+//! the `docProps/core.xml`, `docProps/app.xml` or `meta.xml` parts of Office Open XML (docx, xlsx,
+//! pptx) and OpenDocument (odt, ods, odp) archives.
+//!
+//! Every read is bounded, so that corrupt or truncated files give no metadata rather than a panic.
+//! The Info dictionary of a PDF file is only found in its first four megabytes and its last one,
+//! and when it is not hidden in a compressed object stream.
+
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, SeekFrom},
+    sync::LazyLock,
+};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+use regex::bytes::Regex;
+
+const PDF_SIGNATURE: &[u8] = b"%PDF-";
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+const PDF_HEAD_LENGTH: u64 = 4 * 1024 * 1024;
+const PDF_TAIL_LENGTH: u64 = 1024 * 1024;
+const MAX_ZIP_ENTRIES: usize = 4096;
+const MAX_ZIP_ENTRY_LENGTH: usize = 4 * 1024 * 1024;
+const MAX_XML_LENGTH: usize = 1024 * 1024;
+
+const ZIP_END_OF_CENTRAL_DIRECTORY: &[u8] = b"PK\x05\x06";
+const ZIP_CENTRAL_DIRECTORY_ENTRY: &[u8] = b"PK\x01\x02";
+const ZIP_STORED: u16 = 0;
+const ZIP_DEFLATED: u16 = 8;
+
+static INFO_REFERENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?-u)/Info\s*(\d+)\s+(\d+)\s+R").unwrap());
+static RDF_ITEM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s-u)<rdf:li(?:\s[^>]*)?>(.*?)</rdf:li>").unwrap());
+static XML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?-u)<[^>]*>").unwrap());
+
+/// Value of every Info dictionary key, by key.
+static PDF_ENTRIES: LazyLock<HashMap<&str, Regex>> = LazyLock::new(|| {
+    DocumentField::all()
+        .iter()
+        .map(|field| {
+            let key = field.pdf_key();
+            (key, Regex::new(&format!(r"(?-u)/{key}\s*([(<])")).unwrap())
+        })
+        .collect()
+});
+
+/// Content or attribute value of every XML element, by element.
+static XML_ELEMENTS: LazyLock<HashMap<&str, Regex>> = LazyLock::new(|| {
+    DocumentField::all()
+        .iter()
+        .flat_map(|field| field.xml_elements())
+        .map(|&element| {
+            let escaped = regex::escape(element);
+            let pattern =
+                format!(r#"(?s-u)<{escaped}(?:\s[^>]*)?>(.*?)</{escaped}>|\s{escaped}="([^"]*)""#);
+            (element, Regex::new(&pattern).unwrap())
+        })
+        .collect()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DocumentField {
+    #[default]
+    Author,
+    Title,
+    Subject,
+    /// Application that created the document.
+    Creator,
+    CreationDate,
+    ModificationDate,
+}
+
+impl From<DocumentField> for String {
+    fn from(field: DocumentField) -> Self {
+        match field {
+            DocumentField::Author => "author".to_string(),
+            DocumentField::Title => "title".to_string(),
+            DocumentField::Subject => "subject".to_string(),
+            DocumentField::Creator => "creator".to_string(),
+            DocumentField::CreationDate => "creation date".to_string(),
+            DocumentField::ModificationDate => "modification date".to_string(),
+        }
+    }
+}
+
+impl From<String> for DocumentField {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "title" => DocumentField::Title,
+            "subject" => DocumentField::Subject,
+            "creator" => DocumentField::Creator,
+            "creation date" => DocumentField::CreationDate,
+            "modification date" => DocumentField::ModificationDate,
+            _ => DocumentField::Author,
+        }
+    }
+}
+
+impl DocumentField {
+    pub fn all() -> Vec<DocumentField> {
+        vec![
+            DocumentField::Author,
+            DocumentField::Title,
+            DocumentField::Subject,
+            DocumentField::Creator,
+            DocumentField::CreationDate,
+            DocumentField::ModificationDate,
+        ]
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(
+            self,
+            DocumentField::CreationDate | DocumentField::ModificationDate
+        )
+    }
+
+    fn pdf_key(&self) -> &'static str {
+        match self {
+            DocumentField::Author => "Author",
+            DocumentField::Title => "Title",
+            DocumentField::Subject => "Subject",
+            DocumentField::Creator => "Creator",
+            DocumentField::CreationDate => "CreationDate",
+            DocumentField::ModificationDate => "ModDate",
+        }
+    }
+
+    /// Elements holding the field in XMP packets, OOXML and ODF parts, by order of preference.
+    fn xml_elements(&self) -> &'static [&'static str] {
+        match self {
+            DocumentField::Author => &["dc:creator", "meta:initial-creator"],
+            DocumentField::Title => &["dc:title"],
+            DocumentField::Subject => &["dc:subject", "dc:description"],
+            DocumentField::Creator => &["xmp:CreatorTool", "Application", "meta:generator"],
+            DocumentField::CreationDate => {
+                &["xmp:CreateDate", "dcterms:created", "meta:creation-date"]
+            }
+            DocumentField::ModificationDate => &["xmp:ModifyDate", "dcterms:modified", "dc:date"],
+        }
+    }
+}
+
+/// Metadata of a document, such as its author or its creation date.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentMetadata {
+    fields: HashMap<DocumentField, String>,
+}
+
+impl DocumentMetadata {
+    /// Metadata of the document read by `reader`, or `None` when it doesn't have any.
+    pub fn read<R: Read + Seek>(mut reader: R) -> Option<DocumentMetadata> {
+        let mut start = vec![];
+        reader.seek(SeekFrom::Start(0)).ok()?;
+        (&mut reader).take(1024).read_to_end(&mut start).ok()?;
+
+        let mut metadata = DocumentMetadata::default();
+        if start.starts_with(ZIP_SIGNATURE) {
+            metadata.read_zip(&mut reader);
+        } else if find(&start, PDF_SIGNATURE).is_some() {
+            metadata.read_pdf(&mut reader);
+        }
+
+        (!metadata.fields.is_empty()).then_some(metadata)
+    }
+
+    pub fn get(&self, field: DocumentField) -> Option<&str> {
+        self.fields.get(&field).map(String::as_str)
+    }
+
+    /// Date of a date field, written the PDF way (`D:20230514101010+02'00'`) or the ISO 8601 way.
+    /// Dates without offset are taken as UTC.
+    pub fn date(&self, field: DocumentField) -> Option<DateTime<FixedOffset>> {
+        let value = self.get(field)?;
+        match value.strip_prefix("D:") {
+            Some(pdf_date) => parse_pdf_date(pdf_date),
+            None => parse_iso_date(value),
+        }
+    }
+
+    fn insert(&mut self, field: DocumentField, value: String) {
+        let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !value.is_empty() {
+            self.fields.entry(field).or_insert(value);
+        }
+    }
+
+    fn read_xml(&mut self, xml: &[u8]) {
+        for field in DocumentField::all() {
+            if let Some(value) = field
+                .xml_elements()
+                .iter()
+                .find_map(|element| xml_value(xml, element))
+            {
+                self.insert(field, value);
+            }
+        }
+    }
+
+    fn read_pdf<R: Read + Seek>(&mut self, reader: &mut R) {
+        let Ok(length) = reader.seek(SeekFrom::End(0)) else {
+            return;
+        };
+        let mut bytes = vec![];
+        let head = reader
+            .seek(SeekFrom::Start(0))
+            .and_then(|_| (&mut *reader).take(PDF_HEAD_LENGTH).read_to_end(&mut bytes));
+        if head.is_err() {
+            return;
+        }
+        if length > PDF_HEAD_LENGTH {
+            let tail_start = length.saturating_sub(PDF_TAIL_LENGTH).max(PDF_HEAD_LENGTH);
+            let _ = reader
+                .seek(SeekFrom::Start(tail_start))
+                .and_then(|_| reader.read_to_end(&mut bytes));
+        }
+
+        if let Some(info) = pdf_info_dictionary(&bytes) {
+            for field in DocumentField::all() {
+                if let Some(value) = pdf_entry(info, field.pdf_key()) {
+                    self.insert(field, value);
+                }
+            }
+        }
+
+        if let Some(start) = find(&bytes, b"<x:xmpmeta") {
+            let end = find(&bytes[start..], b"</x:xmpmeta>").map_or(bytes.len(), |end| start + end);
+            self.read_xml(&bytes[start..end.min(start + MAX_XML_LENGTH)]);
+        }
+    }
+
+    fn read_zip<R: Read + Seek>(&mut self, reader: &mut R) {
+        let Some(entries) = zip_entries(reader) else {
+            return;
+        };
+
+        for name in ["docProps/core.xml", "docProps/app.xml", "meta.xml"] {
+            if let Some(xml) = entries
+                .iter()
+                .find(|entry| entry.name == name)
+                .and_then(|entry| entry.read(reader))
+            {
+                self.read_xml(&xml);
+            }
+        }
+    }
+}
+
+fn find(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Dictionary of the object referenced by the `/Info` entry of the trailer.
+fn pdf_info_dictionary(bytes: &[u8]) -> Option<&[u8]> {
+    // The last trailer is the one of the latest revision of the file.
+    let captures = INFO_REFERENCE.captures_iter(bytes).last()?;
+    let number = std::str::from_utf8(captures.get(1)?.as_bytes()).ok()?;
+    let generation = std::str::from_utf8(captures.get(2)?.as_bytes()).ok()?;
+
+    let object = Regex::new(&format!(r"(?-u)(?:^|\D){number}\s+{generation}\s+obj")).ok()?;
+    let start = object.find_iter(bytes).last()?.end();
+    let end = find(&bytes[start..], b"endobj").map_or(bytes.len(), |end| start + end);
+
+    Some(&bytes[start..end])
+}
+
+/// String value of `/key` in a PDF dictionary, written literally or in hexadecimal.
+fn pdf_entry(dictionary: &[u8], key: &str) -> Option<String> {
+    let captures = PDF_ENTRIES.get(key)?.captures(dictionary)?;
+    let start = captures.get(1)?.start();
+
+    let bytes = match dictionary[start] {
+        b'(' => pdf_literal_string(&dictionary[start + 1..]),
+        _ => pdf_hex_string(&dictionary[start + 1..]),
+    };
+
+    Some(pdf_text(&bytes))
+}
+
+fn pdf_literal_string(bytes: &[u8]) -> Vec<u8> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut iter = bytes.iter().copied().peekable();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'(' => {
+                depth += 1;
+                result.push(byte);
+            }
+            b')' if depth == 0 => break,
+            b')' => {
+                depth -= 1;
+                result.push(byte);
+            }
+            b'\\' => match iter.next() {
+                Some(b'n') => result.push(b'\n'),
+                Some(b'r') => result.push(b'\r'),
+                Some(b't') => result.push(b'\t'),
+                Some(b'b') => result.push(0x08),
+                Some(b'f') => result.push(0x0C),
+                Some(digit @ b'0'..=b'7') => {
+                    let mut value = (digit - b'0') as u32;
+                    for _ in 0..2 {
+                        match iter.peek() {
+                            Some(digit @ b'0'..=b'7') => {
+                                value = value * 8 + (digit - b'0') as u32;
+                                iter.next();
+                            }
+                            _ => break,
+                        }
+                    }
+                    result.push(value as u8);
+                }
+                // A backslash at the end of a line continues the string on the next one.
+                Some(b'\r') | Some(b'\n') => (),
+                Some(other) => result.push(other),
+                None => break,
+            },
+            _ => result.push(byte),
+        }
+    }
+
+    result
+}
+
+fn pdf_hex_string(bytes: &[u8]) -> Vec<u8> {
+    let digits: Vec<u8> = bytes
+        .iter()
+        .take_while(|&&byte| byte != b'>')
+        .filter_map(|&byte| (byte as char).to_digit(16).map(|digit| digit as u8))
+        .collect();
+
+    digits
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair.get(1).copied().unwrap_or(0))
+        .collect()
+}
+
+/// Text of a PDF string, in UTF-16 when it starts with a byte order mark.
+fn pdf_text(bytes: &[u8]) -> String {
+    match bytes.strip_prefix(&[0xFE, 0xFF]) {
+        Some(utf16) => {
+            let units: Vec<u16> = utf16
+                .chunks_exact(2)
+                .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        None => bytes.iter().map(|&byte| byte as char).collect(),
+    }
+}
+
+/// `YYYYMMDDHHmmSS` followed by `Z` or an offset such as `+02'00'`, where everything after the
+/// year is optional.
+fn parse_pdf_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    let digits: String = value.chars().take_while(char::is_ascii_digit).collect();
+    let part = |start: usize, default: u32| -> Option<u32> {
+        match digits.get(start..start + 2) {
+            Some(part) => part.parse().ok(),
+            None => Some(default),
+        }
+    };
+    let year = digits.get(..4)?.parse().ok()?;
+    let naive = NaiveDate::from_ymd_opt(year, part(4, 1)?, part(6, 1)?)?.and_hms_opt(
+        part(8, 0)?,
+        part(10, 0)?,
+        part(12, 0)?,
+    )?;
+
+    let offset = &value[digits.len()..];
+    let seconds = match offset.chars().next() {
+        Some(sign @ ('+' | '-')) => {
+            let offset: String = offset.chars().filter(char::is_ascii_digit).collect();
+            let hours: i32 = offset.get(..2)?.parse().ok()?;
+            let minutes: i32 = offset.get(2..4).unwrap_or("0").parse().ok()?;
+            let seconds = hours * 3600 + minutes * 60;
+            if sign == '-' {
+                -seconds
+            } else {
+                seconds
+            }
+        }
+        _ => 0,
+    };
+
+    naive
+        .and_local_timezone(FixedOffset::east_opt(seconds)?)
+        .single()
+}
+
+fn parse_iso_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    let utc = FixedOffset::east_opt(0)?;
+
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .and_then(|naive| naive.and_local_timezone(utc).single())
+        })
+        .or_else(|| {
+            NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)?
+                .and_local_timezone(utc)
+                .single()
+        })
+}
+
+/// Text of the first `element` of an XML document, or of the first item of its RDF list, or its
+/// value when written as an attribute.
+fn xml_value(xml: &[u8], element: &str) -> Option<String> {
+    let captures = XML_ELEMENTS.get(element)?.captures(xml)?;
+    let mut value = captures.get(1).or(captures.get(2))?.as_bytes();
+
+    if let Some(item) = RDF_ITEM
+        .captures(value)
+        .and_then(|captures| captures.get(1))
+    {
+        value = item.as_bytes();
+    }
+
+    let text = XML_TAG.replace_all(value, &b""[..]);
+
+    Some(xml_unescape(&String::from_utf8_lossy(&text)))
+}
+
+fn xml_unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+
+        let entity = &rest[1..end];
+        let character = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match character {
+            Some(character) => {
+                result.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+/// Entry of the central directory of a zip archive.
+struct ZipEntry {
+    name: String,
+    method: u16,
+    compressed_length: usize,
+    offset: u64,
+}
+
+impl ZipEntry {
+    fn read<R: Read + Seek>(&self, reader: &mut R) -> Option<Vec<u8>> {
+        if self.compressed_length > MAX_ZIP_ENTRY_LENGTH {
+            return None;
+        }
+
+        let mut header = [0; 30];
+        reader.seek(SeekFrom::Start(self.offset)).ok()?;
+        reader.read_exact(&mut header).ok()?;
+        if header[..4] != ZIP_SIGNATURE[..] {
+            return None;
+        }
+        let name_length = u16::from_le_bytes([header[26], header[27]]) as i64;
+        let extra_length = u16::from_le_bytes([header[28], header[29]]) as i64;
+
+        let mut data = vec![0; self.compressed_length];
+        reader
+            .seek(SeekFrom::Current(name_length + extra_length))
+            .ok()?;
+        reader.read_exact(&mut data).ok()?;
+
+        match self.method {
+            ZIP_STORED => Some(data),
+            ZIP_DEFLATED => {
+                miniz_oxide::inflate::decompress_to_vec_with_limit(&data, MAX_XML_LENGTH).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Entries listed by the central directory, found through the end of central directory record
+/// at the end of the archive.
+fn zip_entries<R: Read + Seek>(reader: &mut R) -> Option<Vec<ZipEntry>> {
+    let length = reader.seek(SeekFrom::End(0)).ok()?;
+    // The record is 22 bytes long, followed by a comment of at most 65535 bytes.
+    let tail_start = length.saturating_sub(22 + u16::MAX as u64);
+    let mut tail = vec![];
+    reader.seek(SeekFrom::Start(tail_start)).ok()?;
+    reader.read_to_end(&mut tail).ok()?;
+
+    let record_start = tail
+        .windows(4)
+        .rposition(|window| window == ZIP_END_OF_CENTRAL_DIRECTORY)?;
+    let record = tail.get(record_start..record_start + 22)?;
+    let count = (u16::from_le_bytes([record[10], record[11]]) as usize).min(MAX_ZIP_ENTRIES);
+    let directory_length = u32::from_le_bytes([record[12], record[13], record[14], record[15]]);
+    let directory_offset = u32::from_le_bytes([record[16], record[17], record[18], record[19]]);
+
+    let mut directory = vec![0; (directory_length as usize).min(MAX_ZIP_ENTRY_LENGTH)];
+    reader.seek(SeekFrom::Start(directory_offset as u64)).ok()?;
+    reader.read_exact(&mut directory).ok()?;
+
+    let mut entries = vec![];
+    let mut position = 0;
+    for _ in 0..count {
+        let Some(header) = directory.get(position..position + 46) else {
+            break;
+        };
+        if &header[..4] != ZIP_CENTRAL_DIRECTORY_ENTRY {
+            break;
+        }
+
+        let u16_at = |index: usize| u16::from_le_bytes([header[index], header[index + 1]]);
+        let u32_at = |index: usize| {
+            u32::from_le_bytes([
+                header[index],
+                header[index + 1],
+                header[index + 2],
+                header[index + 3],
+            ])
+        };
+        let name_length = u16_at(28) as usize;
+        let Some(name) = directory.get(position + 46..position + 46 + name_length) else {
+            break;
+        };
+
+        entries.push(ZipEntry {
+            name: String::from_utf8_lossy(name).to_string(),
+            method: u16_at(10),
+            compressed_length: u32_at(20) as usize,
+            offset: u32_at(42) as u64,
+        });
+        position += 46 + name_length + u16_at(30) as usize + u16_at(32) as usize;
+    }
+
+    Some(entries)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Zip archive of the given files, deflated when asked to.
+    fn zip(files: &[(&str, &str, bool)]) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut directory = vec![];
+        for (name, content, deflate) in files {
+            let (method, data) = if *deflate {
+                (
+                    ZIP_DEFLATED,
+                    miniz_oxide::deflate::compress_to_vec(content.as_bytes(), 6),
+                )
+            } else {
+                (ZIP_STORED, content.as_bytes().to_vec())
+            };
+
+            let mut header = vec![0; 30];
+            header[..4].copy_from_slice(ZIP_SIGNATURE);
+            header[26..28].copy_from_slice(&(name.len() as u16).to_le_bytes());
+            let mut entry = vec![0; 46];
+            entry[..4].copy_from_slice(ZIP_CENTRAL_DIRECTORY_ENTRY);
+            entry[10..12].copy_from_slice(&method.to_le_bytes());
+            entry[20..24].copy_from_slice(&(data.len() as u32).to_le_bytes());
+            entry[28..30].copy_from_slice(&(name.len() as u16).to_le_bytes());
+            entry[42..46].copy_from_slice(&(bytes.len() as u32).to_le_bytes());
+            entry.extend(name.as_bytes());
+            directory.extend(entry);
+
+            bytes.extend(header);
+            bytes.extend(name.as_bytes());
+            bytes.extend(data);
+        }
+
+        let mut record = vec![0; 22];
+        record[..4].copy_from_slice(ZIP_END_OF_CENTRAL_DIRECTORY);
+        record[10..12].copy_from_slice(&(files.len() as u16).to_le_bytes());
+        record[12..16].copy_from_slice(&(directory.len() as u32).to_le_bytes());
+        record[16..20].copy_from_slice(&(bytes.len() as u32).to_le_bytes());
+        bytes.extend(directory);
+        bytes.extend(record);
+        bytes
+    }
+
+    /// PDF whose info dictionary names the title, author and creation date.
+    pub(crate) const PDF: &str = "%PDF-1.7\n\
+        1 0 obj << /Type /Catalog /Outlines 2 0 R >> endobj\n\
+        2 0 obj << /Title (Chapter 1) >> endobj\n\
+        12 0 obj << /Title <FEFF004E006F00740065> /Author (Jos\\351 \\(Finance\\)) \
+        /CreationDate (D:20230514101010+02'00') >> endobj\n\
+        <x:xmpmeta><rdf:RDF><rdf:Description xmp:CreatorTool=\"Scanner &amp; Co\">\
+        <dc:creator><rdf:Seq><rdf:li>Ignored</rdf:li></rdf:Seq></dc:creator>\
+        </rdf:Description></rdf:RDF></x:xmpmeta>\n\
+        trailer << /Root 1 0 R /Info 12 0 R >>\n%%EOF";
+
+    #[test]
+    fn should_read_pdf_info_dictionary_and_xmp() {
+        let metadata = DocumentMetadata::read(Cursor::new(PDF.as_bytes())).unwrap();
+
+        assert_eq!(metadata.get(DocumentField::Title), Some("Note"));
+        assert_eq!(metadata.get(DocumentField::Author), Some("José (Finance)"));
+        assert_eq!(metadata.get(DocumentField::Creator), Some("Scanner & Co"));
+        assert_eq!(
+            metadata
+                .date(DocumentField::CreationDate)
+                .unwrap()
+                .to_rfc3339(),
+            "2023-05-14T10:10:10+02:00"
+        );
+    }
+
+    #[test]
+    fn should_read_office_and_open_document_archives() {
+        let docx = zip(&[
+            ("word/document.xml", "<w:document/>", true),
+            (
+                "docProps/core.xml",
+                "<cp:coreProperties><dc:title>Budget</dc:title><dc:creator>Ana</dc:creator>\
+                <dcterms:created xsi:type=\"dcterms:W3CDTF\">2021-03-04T05:06:07Z</dcterms:created>\
+                </cp:coreProperties>",
+                true,
+            ),
+            (
+                "docProps/app.xml",
+                "<Properties><Application>Microsoft Office Word</Application></Properties>",
+                false,
+            ),
+        ]);
+        let metadata = DocumentMetadata::read(Cursor::new(docx)).unwrap();
+        assert_eq!(metadata.get(DocumentField::Title), Some("Budget"));
+        assert_eq!(metadata.get(DocumentField::Author), Some("Ana"));
+        assert_eq!(
+            metadata.get(DocumentField::Creator),
+            Some("Microsoft Office Word")
+        );
+        assert_eq!(
+            metadata
+                .date(DocumentField::CreationDate)
+                .unwrap()
+                .to_rfc3339(),
+            "2021-03-04T05:06:07+00:00"
+        );
+
+        let odt = zip(&[(
+            "meta.xml",
+            "<office:meta><meta:initial-creator>Léa</meta:initial-creator>\
+            <meta:creation-date>2020-01-02T03:04:05.123</meta:creation-date></office:meta>",
+            true,
+        )]);
+        let metadata = DocumentMetadata::read(Cursor::new(odt)).unwrap();
+        assert_eq!(metadata.get(DocumentField::Author), Some("Léa"));
+        assert_eq!(
+            metadata
+                .date(DocumentField::CreationDate)
+                .unwrap()
+                .to_rfc3339(),
+            "2020-01-02T03:04:05.123+00:00"
+        );
+    }
+
+    #[test]
+    fn should_parse_partial_pdf_dates() {
+        assert_eq!(
+            parse_pdf_date("2023").unwrap().to_rfc3339(),
+            "2023-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_pdf_date("20231231235959-05'30").unwrap().to_rfc3339(),
+            "2023-12-31T23:59:59-05:30"
+        );
+        assert_eq!(parse_pdf_date("20231340"), None);
+    }
+
+    #[test]
+    fn should_not_panic_on_corrupt_files() {
+        let docx = zip(&[("docProps/core.xml", "<dc:title>Budget</dc:title>", true)]);
+        for bytes in [docx, PDF.as_bytes().to_vec()] {
+            for length in 0..bytes.len() {
+                let _ = DocumentMetadata::read(Cursor::new(&bytes[..length]));
+            }
+        }
+
+        assert_eq!(DocumentMetadata::read(Cursor::new(b"plain text")), None);
+    }
+}
@@ -0,0 +1,392 @@
+//! Reader of the EXIF tags embedded in photos, without any dependency.
+//!
+//! JPEG files carry their tags in an `APP1` segment, TIFF based RAW files (CR2, NEF, ARW, DNG,
+//! ORF, RW2, PEF...) are TIFF files themselves, and the tags of the other containers (HEIC, CR3...)
+//! are found by looking for a TIFF header at the start of the file. Every read is bounded, so
+//! that corrupt or truncated files give no tags rather than a panic.
+
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, SeekFrom},
+};
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+
+pub const MAKE_TAG: u16 = 0x010F;
+pub const MODEL_TAG: u16 = 0x0110;
+pub const DATE_TIME_ORIGINAL_TAG: u16 = 0x9003;
+pub const OFFSET_TIME_ORIGINAL_TAG: u16 = 0x9011;
+pub const LENS_MODEL_TAG: u16 = 0xA434;
+
+const EXIF_IFD_POINTER_TAG: u16 = 0x8769;
+const ASCII_TYPE: u16 = 2;
+
+const EXIF_HEADER: &[u8] = b"Exif\0\0";
+const JPEG_SOI: [u8; 2] = [0xFF, 0xD8];
+const JPEG_APP1: u8 = 0xE1;
+const JPEG_SOS: u8 = 0xDA;
+const JPEG_EOI: u8 = 0xD9;
+
+/// Header magic of TIFF (42), Olympus ORF (`RO` and `RS`) and Panasonic RW2 (`U`) files.
+const TIFF_MAGICS: [u16; 4] = [42, 0x4F52, 0x5352, 0x55];
+
+/// Bounds of what is read, far above what a sane file needs.
+const MAX_JPEG_SEGMENTS: usize = 256;
+const MAX_IFD_ENTRIES: usize = 512;
+const MAX_STRING_LENGTH: usize = 256;
+const MAX_TIFF_HEADERS: usize = 16;
+const SCAN_LENGTH: u64 = 1024 * 1024;
+
+/// ASCII tags of a photo, such as its camera model or the date it was taken.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Exif {
+    tags: HashMap<u16, String>,
+}
+
+impl Exif {
+    /// Tags of the photo read by `reader`, or `None` when it doesn't have any.
+    pub fn read<R: Read + Seek>(mut reader: R) -> Option<Exif> {
+        let mut start = [0; 4];
+        reader.seek(SeekFrom::Start(0)).ok()?;
+        reader.read_exact(&mut start).ok()?;
+
+        let mut exif = Exif::default();
+        if start[..2] == JPEG_SOI {
+            let base = find_jpeg_exif(&mut reader)?;
+            exif.read_tiff(&mut reader, base);
+        } else if tiff_byte_order(&start).is_some() {
+            exif.read_tiff(&mut reader, 0);
+        } else {
+            for base in find_tiff_headers(&mut reader)? {
+                exif.read_tiff(&mut reader, base);
+            }
+        }
+
+        (!exif.tags.is_empty()).then_some(exif)
+    }
+
+    pub fn get(&self, tag: u16) -> Option<&str> {
+        self.tags.get(&tag).map(String::as_str)
+    }
+
+    /// Date the photo was taken, with its offset from UTC when the camera recorded it.
+    pub fn date_time_original(&self) -> Option<DateTime<FixedOffset>> {
+        let value = self.get(DATE_TIME_ORIGINAL_TAG)?;
+        let naive = ["%Y:%m:%d %H:%M:%S", "%Y-%m-%d %H:%M:%S"]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())?;
+        let offset = self
+            .get(OFFSET_TIME_ORIGINAL_TAG)
+            .and_then(|offset| offset.parse::<FixedOffset>().ok())
+            .unwrap_or(FixedOffset::east_opt(0)?);
+
+        naive.and_local_timezone(offset).single()
+    }
+
+    /// Reads the tags of the TIFF structure starting at `base`, keeping the ones already read.
+    fn read_tiff<R: Read + Seek>(&mut self, reader: &mut R, base: u64) {
+        let Some(mut tiff) = TiffReader::new(reader, base) else {
+            return;
+        };
+        let Some(ifd0) = tiff.u32(4) else {
+            return;
+        };
+
+        let mut pending = vec![ifd0 as u64];
+        let mut visited = vec![];
+        while let Some(offset) = pending.pop() {
+            if visited.contains(&offset) {
+                continue;
+            }
+            visited.push(offset);
+
+            for entry in tiff.entries(offset).unwrap_or_default() {
+                if entry.tag == EXIF_IFD_POINTER_TAG {
+                    pending.extend(tiff.u32_value(&entry).map(u64::from));
+                } else if entry.kind == ASCII_TYPE && !self.tags.contains_key(&entry.tag) {
+                    if let Some(value) = tiff.string_value(&entry) {
+                        self.tags.insert(entry.tag, value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Offset of the TIFF structure of the `APP1` segment of a JPEG file.
+fn find_jpeg_exif<R: Read + Seek>(reader: &mut R) -> Option<u64> {
+    let mut position: u64 = 2;
+    for _ in 0..MAX_JPEG_SEGMENTS {
+        let mut header = [0; 4];
+        reader.seek(SeekFrom::Start(position)).ok()?;
+        reader.read_exact(&mut header).ok()?;
+        if header[0] != 0xFF || header[1] == JPEG_SOS || header[1] == JPEG_EOI {
+            return None;
+        }
+
+        let length = u16::from_be_bytes([header[2], header[3]]) as u64;
+        if header[1] == JPEG_APP1 {
+            let mut identifier = [0; 6];
+            reader.read_exact(&mut identifier).ok()?;
+            if identifier == EXIF_HEADER {
+                return Some(position + 4 + EXIF_HEADER.len() as u64);
+            }
+        }
+        position = position.checked_add(2 + length)?;
+    }
+
+    None
+}
+
+/// Offsets of the TIFF headers found at the start of a file, those following an EXIF header
+/// first.
+fn find_tiff_headers<R: Read + Seek>(reader: &mut R) -> Option<Vec<u64>> {
+    let mut bytes = vec![];
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    reader.take(SCAN_LENGTH).read_to_end(&mut bytes).ok()?;
+
+    let headers: Vec<usize> = (0..bytes.len())
+        .filter(|&index| tiff_byte_order(&bytes[index..]).is_some())
+        .collect();
+    let (mut bases, others): (Vec<usize>, Vec<usize>) = headers
+        .into_iter()
+        .partition(|&index| index >= 6 && &bytes[index - 6..index] == EXIF_HEADER);
+    bases.extend(others);
+    bases.truncate(MAX_TIFF_HEADERS);
+
+    Some(bases.into_iter().map(|base| base as u64).collect())
+}
+
+/// Whether the bytes start with a little endian TIFF header.
+fn tiff_byte_order(bytes: &[u8]) -> Option<bool> {
+    let little_endian = match bytes.get(..2)? {
+        b"II" => true,
+        b"MM" => false,
+        _ => return None,
+    };
+    let magic = bytes.get(2..4)?;
+    let magic = if little_endian {
+        u16::from_le_bytes([magic[0], magic[1]])
+    } else {
+        u16::from_be_bytes([magic[0], magic[1]])
+    };
+
+    TIFF_MAGICS.contains(&magic).then_some(little_endian)
+}
+
+struct IfdEntry {
+    tag: u16,
+    kind: u16,
+    count: u32,
+    value: [u8; 4],
+}
+
+/// Reads values at offsets relative to the start of a TIFF structure.
+struct TiffReader<'a, R> {
+    reader: &'a mut R,
+    base: u64,
+    little_endian: bool,
+}
+
+impl<'a, R: Read + Seek> TiffReader<'a, R> {
+    fn new(reader: &'a mut R, base: u64) -> Option<Self> {
+        let mut tiff = TiffReader {
+            reader,
+            base,
+            little_endian: true,
+        };
+        tiff.little_endian = tiff_byte_order(&tiff.bytes(0, 4)?)?;
+
+        Some(tiff)
+    }
+
+    fn bytes(&mut self, offset: u64, length: usize) -> Option<Vec<u8>> {
+        let mut bytes = vec![0; length];
+        self.reader
+            .seek(SeekFrom::Start(self.base.checked_add(offset)?))
+            .ok()?;
+        self.reader.read_exact(&mut bytes).ok()?;
+
+        Some(bytes)
+    }
+
+    fn to_u16(&self, bytes: [u8; 2]) -> u16 {
+        if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        }
+    }
+
+    fn to_u32(&self, bytes: [u8; 4]) -> u32 {
+        if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        }
+    }
+
+    fn u32(&mut self, offset: u64) -> Option<u32> {
+        let bytes = self.bytes(offset, 4)?;
+        Some(self.to_u32(bytes.try_into().ok()?))
+    }
+
+    fn entries(&mut self, offset: u64) -> Option<Vec<IfdEntry>> {
+        let count = self.bytes(offset, 2)?;
+        let count = (self.to_u16([count[0], count[1]]) as usize).min(MAX_IFD_ENTRIES);
+        let bytes = self.bytes(offset + 2, count * 12)?;
+
+        Some(
+            bytes
+                .chunks_exact(12)
+                .map(|entry| IfdEntry {
+                    tag: self.to_u16([entry[0], entry[1]]),
+                    kind: self.to_u16([entry[2], entry[3]]),
+                    count: self.to_u32([entry[4], entry[5], entry[6], entry[7]]),
+                    value: [entry[8], entry[9], entry[10], entry[11]],
+                })
+                .collect(),
+        )
+    }
+
+    fn u32_value(&self, entry: &IfdEntry) -> Option<u32> {
+        (entry.count == 1).then(|| self.to_u32(entry.value))
+    }
+
+    fn string_value(&mut self, entry: &IfdEntry) -> Option<String> {
+        let length = (entry.count as usize).min(MAX_STRING_LENGTH);
+        let bytes = if length <= 4 {
+            entry.value[..length].to_vec()
+        } else {
+            let offset = self.to_u32(entry.value);
+            self.bytes(offset as u64, length)?
+        };
+
+        let end = bytes.iter().position(|&byte| byte == 0).unwrap_or(length);
+        let value = String::from_utf8_lossy(&bytes[..end]).trim().to_string();
+
+        (!value.is_empty()).then_some(value)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Little endian TIFF structure with the given ASCII tags, half of them in the EXIF IFD.
+    pub(crate) fn tiff(tags: &[(u16, &str)]) -> Vec<u8> {
+        let (ifd0_tags, exif_tags) = tags.split_at(tags.len() / 2);
+        let ifd0_length = 2 + (ifd0_tags.len() + 1) * 12 + 4;
+        let exif_length = 2 + exif_tags.len() * 12 + 4;
+        let mut strings_offset = 8 + ifd0_length + exif_length;
+        let mut strings = vec![];
+
+        let mut ifd = |tags: &[(u16, &str)], exif_offset: Option<usize>| {
+            let mut bytes = vec![];
+            let count = tags.len() + exif_offset.iter().count();
+            bytes.extend((count as u16).to_le_bytes());
+            for (tag, value) in tags {
+                let mut value = value.as_bytes().to_vec();
+                value.push(0);
+                bytes.extend(tag.to_le_bytes());
+                bytes.extend(ASCII_TYPE.to_le_bytes());
+                bytes.extend((value.len() as u32).to_le_bytes());
+                bytes.extend((strings_offset as u32).to_le_bytes());
+                strings_offset += value.len();
+                strings.extend(value);
+            }
+            if let Some(offset) = exif_offset {
+                bytes.extend(EXIF_IFD_POINTER_TAG.to_le_bytes());
+                bytes.extend(4u16.to_le_bytes());
+                bytes.extend(1u32.to_le_bytes());
+                bytes.extend((offset as u32).to_le_bytes());
+            }
+            bytes.extend(0u32.to_le_bytes());
+            bytes
+        };
+
+        let mut bytes = b"II*\0".to_vec();
+        bytes.extend(8u32.to_le_bytes());
+        bytes.extend(ifd(ifd0_tags, Some(8 + ifd0_length)));
+        bytes.extend(ifd(exif_tags, None));
+        bytes.extend(strings);
+        bytes
+    }
+
+    /// JPEG file whose `APP1` segment holds the given tags, after an unrelated segment.
+    pub(crate) fn jpeg(tags: &[(u16, &str)]) -> Vec<u8> {
+        let mut app1 = EXIF_HEADER.to_vec();
+        app1.extend(tiff(tags));
+
+        let mut bytes = JPEG_SOI.to_vec();
+        bytes.extend([0xFF, 0xE0, 0, 4, 0, 0]);
+        bytes.extend([0xFF, JPEG_APP1]);
+        bytes.extend(((app1.len() + 2) as u16).to_be_bytes());
+        bytes.extend(app1);
+        bytes.extend([0xFF, JPEG_SOS, 0, 2, 0xFF, JPEG_EOI]);
+        bytes
+    }
+
+    const TAGS: [(u16, &str); 2] = [
+        (MAKE_TAG, "Canon"),
+        (DATE_TIME_ORIGINAL_TAG, "2021:07:14 18:30:00"),
+    ];
+
+    #[test]
+    fn should_read_tags_of_jpeg_and_tiff_files() {
+        for bytes in [jpeg(&TAGS), tiff(&TAGS)] {
+            let exif = Exif::read(Cursor::new(bytes)).unwrap();
+
+            assert_eq!(exif.get(MAKE_TAG), Some("Canon"));
+            assert_eq!(
+                exif.date_time_original().unwrap().to_rfc3339(),
+                "2021-07-14T18:30:00+00:00"
+            );
+        }
+    }
+
+    #[test]
+    fn should_find_the_exif_header_of_other_containers() {
+        let mut bytes = b"\0\0\0\x18ftypheic".to_vec();
+        bytes.extend([0; 32]);
+        bytes.extend(EXIF_HEADER);
+        bytes.extend(tiff(&[
+            (DATE_TIME_ORIGINAL_TAG, "2020:01:02 03:04:05"),
+            (OFFSET_TIME_ORIGINAL_TAG, "+02:00"),
+        ]));
+
+        let exif = Exif::read(Cursor::new(bytes)).unwrap();
+        assert_eq!(
+            exif.date_time_original().unwrap().to_rfc3339(),
+            "2020-01-02T03:04:05+02:00"
+        );
+    }
+
+    #[test]
+    fn should_not_panic_on_corrupt_files() {
+        let jpeg = jpeg(&TAGS);
+        for length in 0..jpeg.len() {
+            let _ = Exif::read(Cursor::new(&jpeg[..length]));
+        }
+
+        let mut corrupt = tiff(&TAGS);
+        for index in 4..corrupt.len() {
+            corrupt[index] = 0xFF;
+            let _ = Exif::read(Cursor::new(&corrupt));
+        }
+
+        assert_eq!(Exif::read(Cursor::new(b"plain text")), None);
+    }
+
+    #[test]
+    fn should_ignore_unset_dates() {
+        let exif = Exif::read(Cursor::new(tiff(&[(
+            DATE_TIME_ORIGINAL_TAG,
+            "0000:00:00 00:00:00",
+        )])))
+        .unwrap();
+
+        assert_eq!(exif.date_time_original(), None);
+    }
+}
@@ -1,8 +1,148 @@
+use std::path::Path;
+
+use crate::core::{
+    parameter::{StrategyParameter, StrategyParameterKind},
+    validation,
+};
+
+pub const CASE_PARAMETER_NAME: &str = "case";
+pub const NO_EXTENSION_PARAMETER_NAME: &str = "no_extension";
+
+/// Label of the files without extension.
+pub const NO_EXTENSION: &str = "unknown";
+
+/// Extensions made of several parts, recognised as a whole rather than by their last part.
+const COMPOUND_EXTENSIONS: [&str; 11] = [
+    "tar.gz", "tar.bz2", "tar.xz", "tar.zst", "tar.lz", "tar.lz4", "tar.lzma", "tar.br", "user.js",
+    "user.css", "d.ts",
+];
+
+/// Case of the extensions given to the folders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+    #[default]
+    Preserve,
+    Lower,
+    Upper,
+}
+
+impl From<Case> for String {
+    fn from(case: Case) -> Self {
+        match case {
+            Case::Preserve => "preserve".to_string(),
+            Case::Lower => "lower".to_string(),
+            Case::Upper => "upper".to_string(),
+        }
+    }
+}
+
+impl From<String> for Case {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "lower" => Case::Lower,
+            "upper" => Case::Upper,
+            _ => Case::Preserve,
+        }
+    }
+}
+
+impl Case {
+    pub fn all() -> Vec<Case> {
+        vec![Case::Preserve, Case::Lower, Case::Upper]
+    }
+
+    pub fn apply(&self, extension: &str) -> String {
+        match self {
+            Case::Preserve => extension.to_string(),
+            Case::Lower => extension.to_lowercase(),
+            Case::Upper => extension.to_uppercase(),
+        }
+    }
+}
+
+/// Parameters choosing the case of the extensions and the label of the files without one.
+pub fn parameter_details() -> Vec<validation::ParameterDetail> {
+    let mut case = validation::ParameterDetail::new(
+        CASE_PARAMETER_NAME,
+        StrategyParameterKind::Choice(Case::all().into_iter().map(Into::into).collect()),
+        false,
+    );
+    case.with_default_value(StrategyParameter::SingleString(Case::default().into()));
+
+    let mut no_extension = validation::ParameterDetail::new(
+        NO_EXTENSION_PARAMETER_NAME,
+        StrategyParameterKind::SingleString,
+        false,
+    );
+    no_extension.with_default_value(StrategyParameter::SingleString(NO_EXTENSION.to_string()));
+
+    vec![case, no_extension]
+}
+
+/// Extension of the file name as it's written, without its dot, such as `tar.gz` for
+/// `backup.tar.gz`. Hidden files such as `.bashrc` and names ending with a dot have none.
+pub fn extension(file_path: &Path) -> Option<String> {
+    let file_name = file_path.file_name()?.to_str()?;
+    let last = file_path.extension()?.to_str()?;
+    if last.is_empty() {
+        return None;
+    }
+
+    let compound = COMPOUND_EXTENSIONS.iter().find_map(|compound| {
+        let start = file_name.len().checked_sub(compound.len())?;
+        let found = file_name.get(start..)?;
+        (start > 1 && file_name[..start].ends_with('.') && found.eq_ignore_ascii_case(compound))
+            .then(|| found.to_string())
+    });
+
+    Some(compound.unwrap_or_else(|| last.to_string()))
+}
+
+/// Extensions to look a file up by, from the most to the least specific: `tar.gz` then `gz`.
+pub fn candidates(extension: &str) -> impl Iterator<Item = &str> {
+    std::iter::once(extension).chain(extension.rsplit_once('.').map(|(_, last)| last))
+}
+
 pub fn file_ext(file_path: &std::path::PathBuf) -> String {
-    file_path
-        .extension()
-        .map(|os_str| os_str.to_str())
-        .flatten()
-        .unwrap_or("unknown")
-        .to_string()
+    extension(file_path).unwrap_or(NO_EXTENSION.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_recognise_compound_extensions() {
+        for (file_name, expected) in [
+            ("backup.tar.gz", Some("tar.gz")),
+            ("Backup.TAR.ZST", Some("TAR.ZST")),
+            ("script.user.js", Some("user.js")),
+            ("archive.gz", Some("gz")),
+            ("photo.JPG", Some("JPG")),
+            (".tar.gz", Some("gz")),
+            (".bashrc", None),
+            ("README", None),
+            ("notes.", None),
+        ] {
+            assert_eq!(
+                extension(Path::new(file_name)).as_deref(),
+                expected,
+                "{file_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_look_up_from_the_most_specific_extension() {
+        assert_eq!(candidates("tar.gz").collect::<Vec<_>>(), ["tar.gz", "gz"]);
+        assert_eq!(candidates("jpg").collect::<Vec<_>>(), ["jpg"]);
+    }
+
+    #[test]
+    fn should_normalise_the_case() {
+        assert_eq!(Case::Preserve.apply("Tar.GZ"), "Tar.GZ");
+        assert_eq!(Case::Lower.apply("Tar.GZ"), "tar.gz");
+        assert_eq!(Case::Upper.apply("Tar.GZ"), "TAR.GZ");
+        assert_eq!(Case::from("UPPER".to_string()), Case::Upper);
+    }
 }
@@ -0,0 +1,263 @@
+//! Date of a file, as read by the date strategies through their `time.source` and `time.zone`
+//! parameters.
+
+use std::{collections::HashMap, fs::File, io, path::Path, time::SystemTime};
+
+use chrono::{DateTime, FixedOffset, Local, Utc};
+
+use crate::core::{
+    error,
+    parameter::{StrategyParameter, StrategyParameterKind},
+    validation,
+};
+
+pub const TIME_SOURCE_PARAMETER_NAME: &str = "time.source";
+pub const TIME_ZONE_PARAMETER_NAME: &str = "time.zone";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeSource {
+    #[default]
+    Modified,
+    /// Birth time of the file, where the filesystem records it.
+    Created,
+    Accessed,
+    /// Earliest of the times above that the filesystem supports.
+    Earliest,
+}
+
+impl From<TimeSource> for String {
+    fn from(source: TimeSource) -> Self {
+        match source {
+            TimeSource::Modified => "modified".to_string(),
+            TimeSource::Created => "created".to_string(),
+            TimeSource::Accessed => "accessed".to_string(),
+            TimeSource::Earliest => "earliest".to_string(),
+        }
+    }
+}
+
+impl From<String> for TimeSource {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "created" => TimeSource::Created,
+            "accessed" => TimeSource::Accessed,
+            "earliest" => TimeSource::Earliest,
+            _ => TimeSource::Modified,
+        }
+    }
+}
+
+impl TimeSource {
+    pub fn all() -> Vec<TimeSource> {
+        vec![
+            TimeSource::Modified,
+            TimeSource::Created,
+            TimeSource::Accessed,
+            TimeSource::Earliest,
+        ]
+    }
+
+    fn read(&self, file: &File) -> io::Result<SystemTime> {
+        let metadata = file.metadata()?;
+        self.pick(metadata.modified(), metadata.created(), metadata.accessed())
+    }
+
+    /// Time of the source among the ones read from the filesystem. The birth time falls back to
+    /// the modification time on the filesystems that don't record it.
+    fn pick(
+        &self,
+        modified: io::Result<SystemTime>,
+        created: io::Result<SystemTime>,
+        accessed: io::Result<SystemTime>,
+    ) -> io::Result<SystemTime> {
+        match self {
+            TimeSource::Modified => modified,
+            TimeSource::Created => match created {
+                Err(error) if error.kind() == io::ErrorKind::Unsupported => modified,
+                created => created,
+            },
+            TimeSource::Accessed => accessed,
+            TimeSource::Earliest => [modified, created, accessed]
+                .into_iter()
+                .flatten()
+                .min()
+                .ok_or(io::Error::from(io::ErrorKind::Unsupported)),
+        }
+    }
+}
+
+/// Time zone in which the date of a file is read, so that the same file always lands in the
+/// same folder whatever the time zone of the machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeZone {
+    #[default]
+    Local,
+    Utc,
+    /// Fixed offset from UTC, written `+02:00`, `-0530` or `+2`.
+    Fixed(FixedOffset),
+}
+
+impl std::str::FromStr for TimeZone {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || format!("'{value}' is not 'local', 'utc' or an offset such as '+02:00' or '-0530'");
+
+        match value.to_lowercase().as_str() {
+            "local" => return Ok(TimeZone::Local),
+            "utc" | "z" => return Ok(TimeZone::Utc),
+            _ => (),
+        }
+
+        let sign = match value.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => return Err(invalid()),
+        };
+        let digits = value[1..].replace(':', "");
+        let (hours, minutes) = match digits.len() {
+            1 | 2 => (digits.as_str(), "0"),
+            4 => digits.split_at(2),
+            _ => return Err(invalid()),
+        };
+        let hours: i32 = hours.parse().map_err(|_| invalid())?;
+        let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+        if minutes >= 60 {
+            return Err(invalid());
+        }
+
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(TimeZone::Fixed)
+            .ok_or_else(invalid)
+    }
+}
+
+impl TimeZone {
+    fn convert(&self, time: SystemTime) -> DateTime<FixedOffset> {
+        match self {
+            TimeZone::Local => DateTime::<Local>::from(time).fixed_offset(),
+            TimeZone::Utc => DateTime::<Utc>::from(time).fixed_offset(),
+            TimeZone::Fixed(offset) => DateTime::<Utc>::from(time).with_timezone(offset),
+        }
+    }
+}
+
+/// Parameters shared by every strategy relying on the date of the file.
+pub fn time_parameter_details() -> Vec<validation::ParameterDetail> {
+    let mut source_validation_criteria = validation::ParameterDetail::new(
+        TIME_SOURCE_PARAMETER_NAME,
+        StrategyParameterKind::Choice(TimeSource::all().into_iter().map(Into::into).collect()),
+        false,
+    );
+    source_validation_criteria.with_default_value(StrategyParameter::SingleString(
+        TimeSource::default().into(),
+    ));
+
+    let mut zone_validation_criteria = validation::ParameterDetail::new(
+        TIME_ZONE_PARAMETER_NAME,
+        StrategyParameterKind::SingleString,
+        false,
+    );
+    zone_validation_criteria
+        .with_default_value(StrategyParameter::SingleString("local".to_string()));
+
+    vec![source_validation_criteria, zone_validation_criteria]
+}
+
+pub fn validate_time_parameters(
+    parameters: &HashMap<String, StrategyParameter>,
+) -> Result<(), validation::error::Error> {
+    match parameters.get(TIME_ZONE_PARAMETER_NAME) {
+        Some(StrategyParameter::SingleString(zone)) => {
+            zone.parse::<TimeZone>().map(|_| ()).map_err(|reason| {
+                validation::error::Error::InvalidValue(TIME_ZONE_PARAMETER_NAME.to_string(), reason)
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Date of the file at `file_path` read from the source, and in the time zone, given by the
+/// parameters.
+pub fn file_time(
+    file_path: &Path,
+    file: &File,
+    parameters: &HashMap<String, StrategyParameter>,
+) -> error::Result<DateTime<FixedOffset>> {
+    let source = match parameters.get(TIME_SOURCE_PARAMETER_NAME) {
+        Some(StrategyParameter::SingleString(source)) => TimeSource::from(source.clone()),
+        _ => TimeSource::default(),
+    };
+    let zone = match parameters.get(TIME_ZONE_PARAMETER_NAME) {
+        Some(StrategyParameter::SingleString(zone)) => zone.parse().unwrap_or_default(),
+        _ => TimeZone::default(),
+    };
+
+    source
+        .read(file)
+        .map(|time| zone.convert(time))
+        .map_err(|err| error::Error::io(file_path, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, TimeZone as _};
+
+    use crate::sorting_strategies::utils;
+
+    #[test]
+    fn should_parse_time_zones() {
+        assert_eq!("UTC".parse(), Ok(TimeZone::Utc));
+        assert_eq!(
+            "+02:00".parse(),
+            Ok(TimeZone::Fixed(FixedOffset::east_opt(7200).unwrap()))
+        );
+        assert_eq!(
+            "-0530".parse(),
+            Ok(TimeZone::Fixed(FixedOffset::west_opt(19800).unwrap()))
+        );
+        assert!("+25".parse::<TimeZone>().is_err());
+        assert!("Europe/Paris".parse::<TimeZone>().is_err());
+    }
+
+    #[test]
+    fn should_convert_new_year_eve_in_the_given_zone() {
+        let time: SystemTime = Utc
+            .with_ymd_and_hms(2023, 12, 31, 23, 30, 0)
+            .unwrap()
+            .into();
+
+        assert_eq!(TimeZone::Utc.convert(time).year(), 2023);
+        assert_eq!(
+            "+01:00".parse::<TimeZone>().unwrap().convert(time).year(),
+            2024
+        );
+    }
+
+    #[test]
+    fn should_read_the_earliest_time() {
+        let file = utils::manifest_file();
+        let earliest = TimeSource::Earliest.read(&file).unwrap();
+
+        assert!(earliest <= TimeSource::Modified.read(&file).unwrap());
+        assert!(earliest <= TimeSource::Accessed.read(&file).unwrap());
+    }
+
+    #[test]
+    fn should_fall_back_when_the_birth_time_is_unsupported() {
+        let modified = SystemTime::UNIX_EPOCH;
+        let unsupported = || Err(io::Error::from(io::ErrorKind::Unsupported));
+
+        assert_eq!(
+            TimeSource::Created
+                .pick(Ok(modified), unsupported(), Ok(SystemTime::now()))
+                .unwrap(),
+            modified
+        );
+        assert!(TimeSource::Accessed
+            .pick(Ok(modified), Ok(modified), unsupported())
+            .is_err());
+    }
+}
@@ -1,59 +1,236 @@
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
-pub enum FileType {
-    Image,
-    Video,
-    Audio,
-    Document,
-    Archive,
-    Executable,
-    #[default]
-    Other,
-}
-
-impl Into<String> for FileType {
-    fn into(self) -> String {
-        match self {
-            FileType::Image => "image".to_string(),
-            FileType::Video => "video".to_string(),
-            FileType::Audio => "audio".to_string(),
-            FileType::Document => "document".to_string(),
-            FileType::Archive => "archive".to_string(),
-            FileType::Executable => "executable".to_string(),
-            FileType::Other => "other".to_string(),
-        }
+use std::{fs::File, path::Path, sync::LazyLock};
+
+use serde::Deserialize;
+
+use super::{
+    file_ext::{candidates, extension},
+    mime::{self, Detection},
+};
+
+/// Type of the files that match no category of the taxonomy.
+pub const OTHER: &str = "other";
+
+static BUILTIN_TAXONOMY: LazyLock<Taxonomy> = LazyLock::new(|| {
+    toml::from_str(include_str!("../../../rsc/taxonomy/file_types.toml"))
+        .expect("The built-in file type taxonomy should be valid.")
+});
+
+/// Category of a file in a [`Taxonomy`], such as `image` or `spreadsheet`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileType(String);
+
+impl Default for FileType {
+    fn default() -> Self {
+        FileType(OTHER.to_string())
+    }
+}
+
+impl From<FileType> for String {
+    fn from(file_type: FileType) -> Self {
+        file_type.0
     }
 }
 
 impl From<String> for FileType {
     fn from(value: String) -> Self {
-        match value.to_lowercase().as_str() {
-            "image" => FileType::Image,
-            "video" => FileType::Video,
-            "audio" => FileType::Audio,
-            "document" => FileType::Document,
-            "archive" => FileType::Archive,
-            "executable" => FileType::Executable,
-            _ => FileType::Other,
-        }
+        FileType(value.trim().to_lowercase())
+    }
+}
+
+impl std::fmt::Display for FileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
-impl FileType {
-    pub fn from_extension(extension: &str) -> Self {
-        match extension.to_lowercase().as_str() {
-            "jpg" | "jpeg" | "png" | "gif" | "bmp" => FileType::Image,
-            "mp4" | "avi" | "mkv" | "mov" => FileType::Video,
-            "mp3" | "wav" | "flac" => FileType::Audio,
-            "pdf" | "docx" | "txt" => FileType::Document,
-            "zip" | "rar" | "tar.gz" => FileType::Archive,
-            _ if extension.is_empty() => FileType::Other,
-            _ => FileType::Executable,
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Category {
+    name: String,
+    #[serde(default)]
+    extensions: Vec<String>,
+    #[serde(default)]
+    mime_types: Vec<String>,
+}
+
+/// Categories of files, by extension and by MIME type. The built-in taxonomy can be overridden by
+/// a TOML file with the same layout, whose categories come first and may be new ones.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Taxonomy {
+    #[serde(rename = "category", default)]
+    categories: Vec<Category>,
+}
+
+impl Taxonomy {
+    pub fn builtin() -> &'static Taxonomy {
+        &BUILTIN_TAXONOMY
+    }
+
+    /// Built-in taxonomy overridden by the file at `path`.
+    pub fn load(path: &Path) -> Result<Taxonomy, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read '{}': {error}", path.display()))?;
+        let overrides: Taxonomy = toml::from_str(&content)
+            .map_err(|error| format!("'{}' is not a valid taxonomy: {error}", path.display()))?;
+
+        if overrides
+            .categories
+            .iter()
+            .any(|category| category.name.trim().is_empty())
+        {
+            return Err(format!("a category of '{}' has no name", path.display()));
         }
+
+        Ok(Taxonomy::builtin().overridden_by(overrides))
+    }
+
+    /// Categories of `overrides` first, extended with the entries of the built-in category of the
+    /// same name, then the remaining built-in categories.
+    fn overridden_by(&self, overrides: Taxonomy) -> Taxonomy {
+        let mut remaining = self.categories.clone();
+        let mut categories = vec![];
+        for mut category in overrides.categories {
+            category.name = FileType::from(category.name).0;
+            if let Some(index) = remaining.iter().position(|c| c.name == category.name) {
+                let builtin = remaining.remove(index);
+                category.extensions.extend(builtin.extensions);
+                category.mime_types.extend(builtin.mime_types);
+            }
+            categories.push(category);
+        }
+        categories.extend(remaining);
+
+        Taxonomy { categories }
+    }
+
+    /// Names of the categories, `other` included.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        for category in &self.categories {
+            if !names.contains(&category.name) {
+                names.push(category.name.clone());
+            }
+        }
+        names.push(OTHER.to_string());
+
+        names
+    }
+
+    pub fn from_extension(&self, extension: &str) -> FileType {
+        let extension = extension.trim_start_matches('.').to_lowercase();
+
+        let category = candidates(&extension).find_map(|candidate| {
+            self.categories.iter().find(|category| {
+                category
+                    .extensions
+                    .iter()
+                    .any(|known| known.eq_ignore_ascii_case(candidate))
+            })
+        });
+
+        category
+            .map(|category| FileType(category.name.clone()))
+            .unwrap_or_default()
+    }
+
+    pub fn from_mime(&self, mime: &str) -> FileType {
+        let mime = mime.to_lowercase();
+        let exact = self.categories.iter().find(|category| {
+            category
+                .mime_types
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(&mime))
+        });
+        let wildcard = || {
+            self.categories.iter().find(|category| {
+                category.mime_types.iter().any(|known| {
+                    known
+                        .strip_suffix('*')
+                        .is_some_and(|prefix| mime.starts_with(&prefix.to_lowercase()))
+                })
+            })
+        };
+
+        exact
+            .or_else(wildcard)
+            .map(|category| FileType(category.name.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Type of the file at `file_path`, read from its content or its extension.
+    pub fn file_type(&self, detection: Detection, file_path: &Path, file: &File) -> FileType {
+        let from_extension = || {
+            Some(self.from_extension(&extension(file_path)?))
+                .filter(|file_type| file_type.0 != OTHER)
+        };
+        if detection == Detection::Extension {
+            return from_extension().unwrap_or_default();
+        }
+
+        let bytes = mime::read_start(file).unwrap_or_default();
+        let sniffed = mime::sniff(&bytes).map(|mime| self.from_mime(mime));
+        let text = || mime::is_text(&bytes).then(|| self.from_mime(mime::PLAIN_TEXT));
+
+        match detection {
+            Detection::Content => sniffed.or_else(text),
+            _ => sniffed.or_else(from_extension).or_else(text),
+        }
+        .unwrap_or_default()
     }
 }
 
-impl std::fmt::Display for FileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.to_string())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_classify_builtin_categories() {
+        let taxonomy = Taxonomy::builtin();
+
+        for (extension, file_type) in [
+            ("JPG", "image"),
+            ("xlsx", "spreadsheet"),
+            ("rs", "code"),
+            ("woff2", "font"),
+            ("iso", "disk image"),
+            ("stl", "3d model"),
+            ("csv", "spreadsheet"),
+            ("TAR.GZ", "archive"),
+            ("user.js", "code"),
+            ("unknown", OTHER),
+        ] {
+            assert_eq!(taxonomy.from_extension(extension).to_string(), file_type);
+        }
+
+        assert_eq!(taxonomy.from_mime("image/png").to_string(), "image");
+        assert_eq!(taxonomy.from_mime("text/csv").to_string(), "spreadsheet");
+        assert_eq!(taxonomy.from_mime("text/x-go").to_string(), "document");
+        assert_eq!(
+            taxonomy.from_mime("application/x-unknown").to_string(),
+            OTHER
+        );
+    }
+
+    #[test]
+    fn should_override_the_builtin_taxonomy() {
+        let overrides: Taxonomy = toml::from_str(
+            r#"
+            [[category]]
+            name = "Raw"
+            extensions = ["cr2", "nef"]
+
+            [[category]]
+            name = "code"
+            extensions = ["zig"]
+            "#,
+        )
+        .unwrap();
+        let taxonomy = Taxonomy::builtin().overridden_by(overrides);
+
+        assert_eq!(taxonomy.from_extension("nef").to_string(), "raw");
+        assert_eq!(taxonomy.from_extension("jpg").to_string(), "image");
+        assert_eq!(taxonomy.from_extension("zig").to_string(), "code");
+        assert_eq!(taxonomy.from_extension("rs").to_string(), "code");
+        assert_eq!(taxonomy.names()[..2], ["raw", "code"]);
+        assert_eq!(taxonomy.names().last().map(String::as_str), Some(OTHER));
     }
 }
@@ -0,0 +1,162 @@
+//! Digests of the file names and contents, written in lower case hexadecimal.
+
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+};
+
+use sha2::Digest;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Algorithm {
+    /// 64 bits FNV-1a, the fastest.
+    Fnv,
+    Crc32,
+    #[default]
+    Sha256,
+}
+
+impl From<Algorithm> for String {
+    fn from(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Fnv => "fnv".to_string(),
+            Algorithm::Crc32 => "crc32".to_string(),
+            Algorithm::Sha256 => "sha256".to_string(),
+        }
+    }
+}
+
+impl From<String> for Algorithm {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "fnv" => Algorithm::Fnv,
+            "crc32" => Algorithm::Crc32,
+            _ => Algorithm::Sha256,
+        }
+    }
+}
+
+impl Algorithm {
+    pub fn all() -> Vec<Algorithm> {
+        vec![Algorithm::Fnv, Algorithm::Crc32, Algorithm::Sha256]
+    }
+
+    /// Number of hexadecimal characters of the digests.
+    pub fn length(&self) -> usize {
+        match self {
+            Algorithm::Fnv => 16,
+            Algorithm::Crc32 => 8,
+            Algorithm::Sha256 => 64,
+        }
+    }
+
+    pub fn digest(&self, bytes: &[u8]) -> String {
+        let mut hasher = Hasher::new(*self);
+        hasher.update(bytes);
+        hasher.finish()
+    }
+
+    /// Digest of the whole content of `file`, read by chunks from its start.
+    pub fn digest_file(&self, mut file: &File) -> io::Result<String> {
+        let mut hasher = Hasher::new(*self);
+        let mut buffer = vec![0; 64 * 1024];
+        file.seek(SeekFrom::Start(0))?;
+        loop {
+            match file.read(&mut buffer)? {
+                0 => break,
+                read => hasher.update(&buffer[..read]),
+            }
+        }
+
+        Ok(hasher.finish())
+    }
+}
+
+enum Hasher {
+    Fnv(u64),
+    Crc32(crc32fast::Hasher),
+    Sha256(sha2::Sha256),
+}
+
+impl Hasher {
+    fn new(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Fnv => Hasher::Fnv(FNV_OFFSET_BASIS),
+            Algorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+            Algorithm::Sha256 => Hasher::Sha256(sha2::Sha256::new()),
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Hasher::Fnv(hash) => {
+                for byte in bytes {
+                    *hash = (*hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME);
+                }
+            }
+            Hasher::Crc32(crc) => crc.update(bytes),
+            Hasher::Sha256(sha256) => sha256.update(bytes),
+        }
+    }
+
+    fn finish(self) -> String {
+        match self {
+            Hasher::Fnv(hash) => format!("{hash:016x}"),
+            Hasher::Crc32(crc) => format!("{:08x}", crc.finalize()),
+            Hasher::Sha256(sha256) => sha256
+                .finalize()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_reference_digests() {
+        assert_eq!(Algorithm::Fnv.digest(b""), "cbf29ce484222325");
+        assert_eq!(Algorithm::Fnv.digest(b"a"), "af63dc4c8601ec8c");
+        assert_eq!(Algorithm::Crc32.digest(b"123456789"), "cbf43926");
+        assert_eq!(
+            Algorithm::Sha256.digest(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            Algorithm::Sha256.digest(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            Algorithm::Sha256.digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn should_digest_files_by_chunks() {
+        let content = vec![b'a'; 1_000_000];
+        let path = std::env::temp_dir().join("hash_digest_file.bin");
+        std::fs::write(&path, &content).unwrap();
+
+        assert_eq!(
+            Algorithm::Sha256
+                .digest_file(&File::open(&path).unwrap())
+                .unwrap(),
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+        assert_eq!(
+            Algorithm::Crc32
+                .digest_file(&File::open(&path).unwrap())
+                .unwrap(),
+            Algorithm::Crc32.digest(&content)
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,422 @@
+//! MIME type of a file, read from its first bytes or guessed from its extension, as chosen by the
+//! `detection` parameter of the strategies.
+
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use crate::core::{
+    parameter::{StrategyParameter, StrategyParameterKind},
+    validation,
+};
+
+use super::file_ext::{candidates, extension};
+
+pub const DETECTION_PARAMETER_NAME: &str = "detection";
+
+pub const OCTET_STREAM: &str = "application/octet-stream";
+pub const PLAIN_TEXT: &str = "text/plain";
+
+/// Number of bytes read to recognise a file, enough to reach the `ustar` magic of tar archives.
+const SNIFF_LENGTH: u64 = 4096;
+
+/// Signatures found at the start of files: offset, magic bytes and MIME type.
+const SIGNATURES: [(usize, &[u8], &str); 37] = [
+    (0, b"\x89PNG\r\n\x1a\n", "image/png"),
+    (0, b"\xFF\xD8\xFF", "image/jpeg"),
+    (0, b"GIF87a", "image/gif"),
+    (0, b"GIF89a", "image/gif"),
+    (0, b"II*\0", "image/tiff"),
+    (0, b"MM\0*", "image/tiff"),
+    (0, b"\0\0\x01\0", "image/vnd.microsoft.icon"),
+    (0, b"8BPS", "image/vnd.adobe.photoshop"),
+    (0, b"\x1A\x45\xDF\xA3", "video/x-matroska"),
+    (0, b"FLV", "video/x-flv"),
+    (0, b"\0\0\x01\xBA", "video/mpeg"),
+    (0, b"ID3", "audio/mpeg"),
+    (0, b"\xFF\xFB", "audio/mpeg"),
+    (0, b"\xFF\xF3", "audio/mpeg"),
+    (0, b"fLaC", "audio/flac"),
+    (0, b"OggS", "audio/ogg"),
+    (0, b"MThd", "audio/midi"),
+    (0, b"%PDF-", "application/pdf"),
+    (0, b"{\\rtf", "application/rtf"),
+    (
+        0,
+        b"\xD0\xCF\x11\xE0\xA1\xB1\x1A\xE1",
+        "application/x-ole-storage",
+    ),
+    (0, b"\x1F\x8B", "application/gzip"),
+    (0, b"BZh", "application/x-bzip2"),
+    (0, b"\xFD7zXZ\0", "application/x-xz"),
+    (0, b"\x28\xB5\x2F\xFD", "application/zstd"),
+    (0, b"7z\xBC\xAF\x27\x1C", "application/x-7z-compressed"),
+    (0, b"Rar!\x1A\x07", "application/vnd.rar"),
+    (257, b"ustar", "application/x-tar"),
+    (0, b"\x7FELF", "application/x-executable"),
+    (0, b"MZ", "application/vnd.microsoft.portable-executable"),
+    (0, b"\xCF\xFA\xED\xFE", "application/x-mach-binary"),
+    (0, b"\xCE\xFA\xED\xFE", "application/x-mach-binary"),
+    (0, b"\xCA\xFE\xBA\xBE", "application/x-mach-binary"),
+    (0, b"\0asm", "application/wasm"),
+    (0, b"SQLite format 3\0", "application/vnd.sqlite3"),
+    (0, b"wOFF", "font/woff"),
+    (0, b"wOF2", "font/woff2"),
+    (0, b"OTTO", "font/otf"),
+];
+
+/// MIME types by extension, for files that are not recognised by their content.
+const EXTENSIONS: [(&str, &str); 62] = [
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("png", "image/png"),
+    ("gif", "image/gif"),
+    ("bmp", "image/bmp"),
+    ("webp", "image/webp"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("heic", "image/heic"),
+    ("svg", "image/svg+xml"),
+    ("ico", "image/vnd.microsoft.icon"),
+    ("mp4", "video/mp4"),
+    ("m4v", "video/mp4"),
+    ("mov", "video/quicktime"),
+    ("avi", "video/x-msvideo"),
+    ("mkv", "video/x-matroska"),
+    ("webm", "video/webm"),
+    ("mp3", "audio/mpeg"),
+    ("m4a", "audio/mp4"),
+    ("wav", "audio/wav"),
+    ("flac", "audio/flac"),
+    ("ogg", "audio/ogg"),
+    ("opus", "audio/ogg"),
+    ("aac", "audio/aac"),
+    ("pdf", "application/pdf"),
+    ("doc", "application/msword"),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    ),
+    ("xls", "application/vnd.ms-excel"),
+    (
+        "xlsx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    ),
+    ("ppt", "application/vnd.ms-powerpoint"),
+    (
+        "pptx",
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    ),
+    ("odt", "application/vnd.oasis.opendocument.text"),
+    ("ods", "application/vnd.oasis.opendocument.spreadsheet"),
+    ("odp", "application/vnd.oasis.opendocument.presentation"),
+    ("epub", "application/epub+zip"),
+    ("rtf", "application/rtf"),
+    ("txt", "text/plain"),
+    ("md", "text/markdown"),
+    ("csv", "text/csv"),
+    ("html", "text/html"),
+    ("htm", "text/html"),
+    ("css", "text/css"),
+    ("xml", "application/xml"),
+    ("json", "application/json"),
+    ("js", "text/javascript"),
+    ("rs", "text/x-rust"),
+    ("py", "text/x-python"),
+    ("sh", "application/x-sh"),
+    ("zip", "application/zip"),
+    ("gz", "application/gzip"),
+    ("tgz", "application/gzip"),
+    ("bz2", "application/x-bzip2"),
+    ("xz", "application/x-xz"),
+    ("zst", "application/zstd"),
+    ("7z", "application/x-7z-compressed"),
+    ("rar", "application/vnd.rar"),
+    ("tar", "application/x-tar"),
+    ("exe", "application/vnd.microsoft.portable-executable"),
+    ("dll", "application/vnd.microsoft.portable-executable"),
+    ("msi", "application/x-msi"),
+    ("apk", "application/vnd.android.package-archive"),
+    ("wasm", "application/wasm"),
+];
+
+/// Interpreters of the `#!` line of scripts, and the MIME type of their scripts.
+const INTERPRETERS: [(&str, &str); 6] = [
+    ("python", "text/x-python"),
+    ("node", "text/javascript"),
+    ("perl", "text/x-perl"),
+    ("ruby", "text/x-ruby"),
+    ("bash", "application/x-sh"),
+    ("sh", "application/x-sh"),
+];
+
+/// Where the type of a file is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Detection {
+    Extension,
+    Content,
+    /// Content of the file, then its extension when the content is not recognised.
+    #[default]
+    ContentThenExtension,
+}
+
+impl From<Detection> for String {
+    fn from(detection: Detection) -> Self {
+        match detection {
+            Detection::Extension => "extension".to_string(),
+            Detection::Content => "content".to_string(),
+            Detection::ContentThenExtension => "content then extension".to_string(),
+        }
+    }
+}
+
+impl From<String> for Detection {
+    fn from(value: String) -> Self {
+        match value.to_lowercase().as_str() {
+            "extension" => Detection::Extension,
+            "content" => Detection::Content,
+            _ => Detection::ContentThenExtension,
+        }
+    }
+}
+
+impl Detection {
+    pub fn all() -> Vec<Detection> {
+        vec![
+            Detection::Extension,
+            Detection::Content,
+            Detection::ContentThenExtension,
+        ]
+    }
+
+    pub fn from_parameters(
+        parameters: &std::collections::HashMap<String, StrategyParameter>,
+    ) -> Self {
+        match parameters.get(DETECTION_PARAMETER_NAME) {
+            Some(StrategyParameter::SingleString(detection)) => Detection::from(detection.clone()),
+            _ => Detection::default(),
+        }
+    }
+}
+
+/// Parameter choosing the detection, shared by the strategies relying on the type of the file.
+pub fn detection_parameter_detail() -> validation::ParameterDetail {
+    let mut detail = validation::ParameterDetail::new(
+        DETECTION_PARAMETER_NAME,
+        StrategyParameterKind::Choice(Detection::all().into_iter().map(Into::into).collect()),
+        false,
+    );
+    detail.with_default_value(StrategyParameter::SingleString(Detection::default().into()));
+
+    detail
+}
+
+/// MIME type of the file at `file_path`, or `None` when the detection doesn't recognise it.
+/// Text files without known extension are `text/plain` when their content is read.
+pub fn detect(detection: Detection, file_path: &Path, file: &File) -> Option<&'static str> {
+    let from_extension = || from_extension(&extension(file_path)?);
+    let text = |bytes: &[u8]| is_text(bytes).then_some(PLAIN_TEXT);
+
+    match detection {
+        Detection::Extension => from_extension(),
+        Detection::Content => {
+            let bytes = read_start(file)?;
+            sniff(&bytes).or_else(|| text(&bytes))
+        }
+        Detection::ContentThenExtension => {
+            let bytes = read_start(file).unwrap_or_default();
+            sniff(&bytes)
+                .or_else(from_extension)
+                .or_else(|| text(&bytes))
+        }
+    }
+}
+
+/// First bytes of the file, enough to recognise it.
+pub fn read_start(mut file: &File) -> Option<Vec<u8>> {
+    let mut bytes = vec![];
+    file.seek(SeekFrom::Start(0)).ok()?;
+    file.take(SNIFF_LENGTH).read_to_end(&mut bytes).ok()?;
+
+    Some(bytes)
+}
+
+pub fn from_extension(extension: &str) -> Option<&'static str> {
+    let extension = extension.to_lowercase();
+
+    let mime = candidates(&extension).find_map(|candidate| {
+        EXTENSIONS
+            .iter()
+            .find(|(known, _)| *known == candidate)
+            .map(|(_, mime)| *mime)
+    });
+
+    mime
+}
+
+/// MIME type recognised from the first bytes of a file.
+pub fn sniff(bytes: &[u8]) -> Option<&'static str> {
+    let at = |offset: usize, magic: &[u8]| {
+        bytes
+            .get(offset..offset + magic.len())
+            .is_some_and(|found| found == magic)
+    };
+
+    if at(0, b"RIFF") {
+        return match bytes.get(8..12)? {
+            b"WEBP" => Some("image/webp"),
+            b"WAVE" => Some("audio/wav"),
+            b"AVI " => Some("video/x-msvideo"),
+            _ => None,
+        };
+    }
+    if at(4, b"ftyp") {
+        return Some(match bytes.get(8..12)? {
+            b"heic" | b"heix" | b"mif1" | b"msf1" => "image/heic",
+            b"avif" => "image/avif",
+            b"M4A " => "audio/mp4",
+            b"qt  " => "video/quicktime",
+            _ => "video/mp4",
+        });
+    }
+    // Bitmaps have reserved zeros after their size, which plain text starting with `BM` has not.
+    if at(0, b"BM") && at(6, &[0; 4]) {
+        return Some("image/bmp");
+    }
+    if at(0, b"PK\x03\x04") {
+        return Some(zip_mime(bytes));
+    }
+    if at(0, b"#!") {
+        let line = bytes.split(|&byte| byte == b'\n').next()?;
+        let line = String::from_utf8_lossy(line);
+        return INTERPRETERS
+            .iter()
+            .find(|(interpreter, _)| {
+                line.split(['/', ' '])
+                    .any(|word| word.starts_with(interpreter))
+            })
+            .map(|(_, mime)| *mime)
+            .or(Some("text/x-script"));
+    }
+
+    SIGNATURES
+        .iter()
+        .find(|(offset, magic, _)| at(*offset, magic))
+        .map(|(_, _, mime)| *mime)
+}
+
+/// Type of a zip based document, told by its `mimetype` entry (OpenDocument, EPUB) or by the
+/// folders of its first entries (Office Open XML, Java and Android archives).
+fn zip_mime(bytes: &[u8]) -> &'static str {
+    if bytes.get(30..38) == Some(b"mimetype") {
+        let mimetype = bytes.get(38..).unwrap_or_default();
+        for mime in [
+            "application/vnd.oasis.opendocument.text",
+            "application/vnd.oasis.opendocument.spreadsheet",
+            "application/vnd.oasis.opendocument.presentation",
+            "application/epub+zip",
+        ] {
+            if mimetype.starts_with(mime.as_bytes()) {
+                return mime;
+            }
+        }
+    }
+
+    let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|window| window == needle);
+    if contains(b"word/") {
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+    } else if contains(b"xl/") {
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    } else if contains(b"ppt/") {
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation"
+    } else if contains(b"AndroidManifest.xml") {
+        "application/vnd.android.package-archive"
+    } else if contains(b"META-INF/MANIFEST.MF") {
+        "application/java-archive"
+    } else {
+        "application/zip"
+    }
+}
+
+/// Whether the bytes look like text: valid UTF-8, possibly cut in the middle of a character, and
+/// without control characters other than whitespace.
+pub fn is_text(bytes: &[u8]) -> bool {
+    let valid = match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(error) if error.error_len().is_none() => {
+            std::str::from_utf8(&bytes[..error.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return false,
+    };
+
+    !bytes.is_empty()
+        && valid
+            .chars()
+            .all(|c| !c.is_control() || c.is_whitespace() || c == '\u{1B}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_recognise_signatures() {
+        let mut tar = vec![0; 512];
+        tar[257..262].copy_from_slice(b"ustar");
+        let mut docx = b"PK\x03\x04".to_vec();
+        docx.extend([0; 26]);
+        docx.extend(b"word/document.xml");
+        let mut odt = b"PK\x03\x04".to_vec();
+        odt.extend([0; 26]);
+        odt.extend(b"mimetypeapplication/vnd.oasis.opendocument.text");
+
+        for (bytes, mime) in [
+            (b"\x89PNG\r\n\x1a\n...".to_vec(), "image/png"),
+            (b"RIFF\0\0\0\0WEBPVP8".to_vec(), "image/webp"),
+            (b"\0\0\0\x18ftypheic".to_vec(), "image/heic"),
+            (b"\x7FELF\x02\x01".to_vec(), "application/x-executable"),
+            (b"#!/usr/bin/env python3\nprint()".to_vec(), "text/x-python"),
+            (tar, "application/x-tar"),
+            (
+                docx,
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            ),
+            (odt, "application/vnd.oasis.opendocument.text"),
+        ] {
+            assert_eq!(sniff(&bytes), Some(mime));
+        }
+
+        assert_eq!(sniff(b"name,age\nAda,36\n"), None);
+        assert_eq!(sniff(b""), None);
+    }
+
+    #[test]
+    fn should_tell_text_from_binary() {
+        assert!(is_text("fn main() {}\n".as_bytes()));
+        assert!(is_text(&"é".as_bytes()[..1]));
+        assert!(!is_text(b"\0\x01\x02"));
+        assert!(!is_text(b""));
+    }
+
+    #[test]
+    fn should_fall_back_on_the_extension() {
+        let file_path = Path::new("Cargo.toml");
+        let file = File::open(file_path).unwrap();
+
+        assert_eq!(detect(Detection::Extension, file_path, &file), None);
+        assert_eq!(
+            detect(Detection::Content, file_path, &file),
+            Some(PLAIN_TEXT)
+        );
+        assert_eq!(
+            detect(
+                Detection::ContentThenExtension,
+                Path::new("data.csv"),
+                &file
+            ),
+            Some("text/csv")
+        );
+    }
+}
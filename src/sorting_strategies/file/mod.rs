@@ -1,2 +1,8 @@
+pub mod audio_tags;
+pub mod document_metadata;
+pub mod exif;
 pub mod file_ext;
+pub mod file_time;
 pub mod filetype;
+pub mod hash;
+pub mod mime;
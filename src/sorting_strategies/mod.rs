@@ -1,3 +1,6 @@
 pub mod catalog;
 pub mod file;
+pub mod predicate;
+pub mod template;
+pub mod text_transform;
 pub mod utils;
@@ -0,0 +1,50 @@
+mod and_predicate;
+mod extension_predicate;
+mod file_type_predicate;
+mod modified_predicate;
+mod name_predicate;
+mod not_predicate;
+mod or_predicate;
+mod path_contains_predicate;
+mod predicate_catalog;
+mod size_predicate;
+
+use std::collections::HashMap;
+
+use crate::core::{parameter::StrategyParameter, predicate::Predicate, validation};
+
+pub use predicate_catalog::PredicateCatalog;
+
+pub fn all_predicates() -> PredicateCatalog {
+    PredicateCatalog::new(vec![
+        Box::new(extension_predicate::ExtensionPredicate::new()),
+        Box::new(file_type_predicate::FileTypePredicate::new()),
+        Box::new(name_predicate::NamePredicate::new()),
+        Box::new(size_predicate::SizePredicate::new()),
+        Box::new(modified_predicate::ModifiedPredicate::new()),
+        Box::new(path_contains_predicate::PathContainsPredicate::new()),
+        Box::new(and_predicate::AndPredicate::new()),
+        Box::new(or_predicate::OrPredicate::new()),
+        Box::new(not_predicate::NotPredicate::new()),
+    ])
+}
+
+/// Predicates given to the `name` parameter, or none if it has not been set.
+pub fn nested_predicates<'a>(
+    parameters: &'a HashMap<String, StrategyParameter>,
+    name: &str,
+) -> &'a [Box<dyn Predicate>] {
+    match parameters.get(name) {
+        Some(StrategyParameter::Predicate(predicates)) => predicates,
+        _ => &[],
+    }
+}
+
+pub fn validate_nested_predicates(
+    parameters: &HashMap<String, StrategyParameter>,
+    name: &str,
+) -> Result<(), validation::error::Error> {
+    nested_predicates(parameters, name)
+        .iter()
+        .try_for_each(|predicate| predicate.validate())
+}
@@ -0,0 +1,131 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::{
+        predicate::{nested_predicates, validate_nested_predicates},
+        utils,
+    },
+};
+
+const PREDICATES: &str = "predicates";
+
+/// Matches the files matched by every given predicate.
+#[derive(Clone, Debug)]
+pub struct AndPredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl AndPredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            PREDICATES,
+            StrategyParameterKind::Predicate,
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        AndPredicate {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for AndPredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for AndPredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for AndPredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        validate_nested_predicates(&self.parameters, PREDICATES)
+    }
+}
+
+impl strategy::ParameterDetails for AndPredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for AndPredicate {
+    fn name(&self) -> String {
+        "and".to_string()
+    }
+}
+
+impl predicate::Matches for AndPredicate {
+    fn matches(&self, file_path: &Path, file: &std::fs::File) -> bool {
+        nested_predicates(&self.parameters, PREDICATES)
+            .iter()
+            .all(|predicate| predicate.matches(file_path, file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            predicate::Matches,
+            strategy::{AddParameter, Validate},
+        },
+        sorting_strategies::predicate::{
+            extension_predicate::ExtensionPredicate, path_contains_predicate::PathContainsPredicate,
+        },
+    };
+
+    #[test]
+    fn should_match_files_matched_by_every_predicate() {
+        let mut extension = ExtensionPredicate::new();
+        extension.add_parameter(
+            "extensions".to_string(),
+            StrategyParameter::SingleString("pdf".to_string()),
+        );
+        let mut path_contains = PathContainsPredicate::new();
+        path_contains.add_parameter(
+            "value".to_string(),
+            StrategyParameter::SingleString("invoices".to_string()),
+        );
+        let mut and = AndPredicate::new();
+        and.add_parameter(
+            PREDICATES.to_string(),
+            StrategyParameter::Predicate(vec![Box::new(extension), Box::new(path_contains)]),
+        );
+        let file = utils::manifest_file();
+
+        assert!(and.validate().is_ok());
+        assert!(and.matches(Path::new("/invoices/a.pdf"), &file));
+        assert!(!and.matches(Path::new("/invoices/a.png"), &file));
+        assert!(!and.matches(Path::new("/receipts/a.pdf"), &file));
+    }
+
+    #[test]
+    fn should_validate_nested_predicates() {
+        let mut and = AndPredicate::new();
+        and.add_parameter(
+            PREDICATES.to_string(),
+            StrategyParameter::Predicate(vec![Box::new(PathContainsPredicate::new())]),
+        );
+
+        assert!(matches!(
+            and.validate(),
+            Err(validation::error::Error::MissingMandatoryParameter(_))
+        ));
+    }
+}
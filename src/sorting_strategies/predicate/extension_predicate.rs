@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::{
+        file::file_ext::{candidates, file_ext},
+        utils,
+    },
+};
+
+const EXTENSIONS: &str = "extensions";
+
+/// Matches the files whose extension is in the comma separated `extensions` list.
+#[derive(Clone, Debug)]
+pub struct ExtensionPredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl ExtensionPredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            EXTENSIONS,
+            StrategyParameterKind::SingleString,
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        ExtensionPredicate {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for ExtensionPredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for ExtensionPredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for ExtensionPredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)
+    }
+}
+
+impl strategy::ParameterDetails for ExtensionPredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for ExtensionPredicate {
+    fn name(&self) -> String {
+        "extension".to_string()
+    }
+}
+
+impl predicate::Matches for ExtensionPredicate {
+    fn matches(&self, file_path: &Path, _: &std::fs::File) -> bool {
+        let Some(StrategyParameter::SingleString(extensions)) = self.parameters.get(EXTENSIONS)
+        else {
+            return false;
+        };
+
+        let extension = file_ext(&file_path.to_path_buf()).to_lowercase();
+        extensions
+            .split(',')
+            .map(|e| e.trim().trim_start_matches('.').to_lowercase())
+            .any(|e| candidates(&extension).any(|candidate| candidate == e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{predicate::Matches, strategy::AddParameter};
+
+    #[test]
+    fn should_match_listed_extensions_regardless_of_case_and_dot() {
+        let mut predicate = ExtensionPredicate::new();
+        predicate.add_parameter(
+            EXTENSIONS.to_string(),
+            StrategyParameter::SingleString("jpg, .PNG".to_string()),
+        );
+        let file = utils::manifest_file();
+
+        assert!(predicate.matches(Path::new("a.png"), &file));
+        assert!(predicate.matches(Path::new("a.JPG"), &file));
+        assert!(!predicate.matches(Path::new("a.gif"), &file));
+    }
+}
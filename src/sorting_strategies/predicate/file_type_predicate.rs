@@ -0,0 +1,118 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::{
+        file::{
+            file_ext::file_ext,
+            filetype::{FileType, Taxonomy},
+        },
+        utils,
+    },
+};
+
+const TYPE: &str = "type";
+
+/// Matches the files of the given [`FileType`].
+#[derive(Clone, Debug)]
+pub struct FileTypePredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl FileTypePredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            TYPE,
+            StrategyParameterKind::Choice(Taxonomy::builtin().names()),
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        FileTypePredicate {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for FileTypePredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for FileTypePredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for FileTypePredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)
+    }
+}
+
+impl strategy::ParameterDetails for FileTypePredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for FileTypePredicate {
+    fn name(&self) -> String {
+        "file type".to_string()
+    }
+}
+
+impl predicate::Matches for FileTypePredicate {
+    fn matches(&self, file_path: &Path, _: &std::fs::File) -> bool {
+        match self.parameters.get(TYPE) {
+            Some(StrategyParameter::SingleString(file_type)) => {
+                FileType::from(file_type.clone())
+                    == Taxonomy::builtin().from_extension(&file_ext(&file_path.to_path_buf()))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        predicate::Matches,
+        strategy::{AddParameter, Validate},
+    };
+
+    fn file_type(file_type: &str) -> FileTypePredicate {
+        let mut predicate = FileTypePredicate::new();
+        predicate.add_parameter(
+            TYPE.to_string(),
+            StrategyParameter::SingleString(file_type.to_string()),
+        );
+
+        predicate
+    }
+
+    #[test]
+    fn should_match_the_type_of_the_extension() {
+        let file = utils::manifest_file();
+
+        assert!(file_type("image").matches(Path::new("a.JPG"), &file));
+        assert!(!file_type("image").matches(Path::new("a.mp4"), &file));
+        assert!(file_type("other").matches(Path::new("a.unknown"), &file));
+    }
+
+    #[test]
+    fn should_only_accept_known_types() {
+        assert!(file_type("video").validate().is_ok());
+        assert!(file_type("hologram").validate().is_err());
+    }
+}
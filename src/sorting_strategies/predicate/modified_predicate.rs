@@ -0,0 +1,152 @@
+use std::path::Path;
+
+use chrono::NaiveDate;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const BEFORE: &str = "before";
+const AFTER: &str = "after";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Matches the files last modified strictly before and/or after the given `YYYY-MM-DD` days,
+/// in local time.
+#[derive(Clone, Debug)]
+pub struct ModifiedPredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl ModifiedPredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                BEFORE,
+                StrategyParameterKind::SingleString,
+                false,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                AFTER,
+                StrategyParameterKind::SingleString,
+                false,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        ModifiedPredicate {
+            validator,
+            parameters,
+        }
+    }
+
+    fn date(&self, name: &str) -> Option<Result<NaiveDate, validation::error::Error>> {
+        match self.parameters.get(name) {
+            Some(StrategyParameter::SingleString(value)) => Some(
+                NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|err| {
+                    validation::error::Error::InvalidValue(
+                        name.to_string(),
+                        format!("'{value}' is not a YYYY-MM-DD date ({err})"),
+                    )
+                }),
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl strategy::Parameters for ModifiedPredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for ModifiedPredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for ModifiedPredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        [BEFORE, AFTER]
+            .into_iter()
+            .filter_map(|name| self.date(name))
+            .try_for_each(|date| date.map(|_| ()))
+    }
+}
+
+impl strategy::ParameterDetails for ModifiedPredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for ModifiedPredicate {
+    fn name(&self) -> String {
+        "modified".to_string()
+    }
+}
+
+impl predicate::Matches for ModifiedPredicate {
+    fn matches(&self, _: &Path, file: &std::fs::File) -> bool {
+        let Some(modified) = file.metadata().and_then(|m| m.modified()).ok() else {
+            return false;
+        };
+        let modified = chrono::DateTime::<chrono::Local>::from(modified).date_naive();
+
+        let is_before = match self.date(BEFORE) {
+            Some(Ok(before)) => modified < before,
+            Some(Err(_)) => false,
+            None => true,
+        };
+        let is_after = match self.date(AFTER) {
+            Some(Ok(after)) => modified > after,
+            Some(Err(_)) => false,
+            None => true,
+        };
+
+        is_before && is_after
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        predicate::Matches,
+        strategy::{AddParameter, Validate},
+    };
+
+    fn modified(name: &str, date: &str) -> ModifiedPredicate {
+        let mut predicate = ModifiedPredicate::new();
+        predicate.add_parameter(
+            name.to_string(),
+            StrategyParameter::SingleString(date.to_string()),
+        );
+
+        predicate
+    }
+
+    #[test]
+    fn should_compare_the_modification_day() {
+        let file = utils::manifest_file();
+        let path = Path::new("Cargo.toml");
+
+        assert!(modified(AFTER, "1970-01-01").matches(path, &file));
+        assert!(!modified(BEFORE, "1970-01-01").matches(path, &file));
+    }
+
+    #[test]
+    fn should_reject_malformed_dates() {
+        assert!(modified(BEFORE, "2024-02-30").validate().is_err());
+        assert!(modified(AFTER, "2024-02-29").validate().is_ok());
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const PATTERN: &str = "pattern";
+
+/// Matches the files whose name, extension included, matches the regular expression `pattern`.
+#[derive(Clone, Debug)]
+pub struct NamePredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+    pattern: Option<regex::Regex>,
+}
+
+impl NamePredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            PATTERN,
+            StrategyParameterKind::SingleString,
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        NamePredicate {
+            validator,
+            parameters,
+            pattern: None,
+        }
+    }
+}
+
+impl strategy::Parameters for NamePredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for NamePredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        if key == PATTERN {
+            self.pattern = match &value {
+                StrategyParameter::SingleString(pattern) => regex::Regex::new(pattern).ok(),
+                _ => None,
+            };
+        }
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for NamePredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        if let Some(StrategyParameter::SingleString(pattern)) = self.parameters.get(PATTERN) {
+            utils::compile_regex(PATTERN, pattern)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl strategy::ParameterDetails for NamePredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for NamePredicate {
+    fn name(&self) -> String {
+        "name".to_string()
+    }
+}
+
+impl predicate::Matches for NamePredicate {
+    fn matches(&self, file_path: &Path, _: &std::fs::File) -> bool {
+        let (Some(pattern), Some(file_name)) = (&self.pattern, file_path.file_name()) else {
+            return false;
+        };
+
+        pattern.is_match(&file_name.to_string_lossy())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        predicate::Matches,
+        strategy::{AddParameter, Validate},
+    };
+
+    #[test]
+    fn should_match_the_file_name_only() {
+        let mut predicate = NamePredicate::new();
+        predicate.add_parameter(
+            PATTERN.to_string(),
+            StrategyParameter::SingleString("^invoice_".to_string()),
+        );
+        let file = utils::manifest_file();
+
+        assert!(predicate.matches(Path::new("/in/invoice_01.pdf"), &file));
+        assert!(!predicate.matches(Path::new("/invoice_/receipt.pdf"), &file));
+    }
+
+    #[test]
+    fn should_reject_invalid_pattern() {
+        let mut predicate = NamePredicate::new();
+        predicate.add_parameter(
+            PATTERN.to_string(),
+            StrategyParameter::SingleString("(".to_string()),
+        );
+
+        assert!(matches!(
+            predicate.validate(),
+            Err(validation::error::Error::InvalidValue(name, _)) if name == PATTERN
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::{
+        predicate::{nested_predicates, validate_nested_predicates},
+        utils,
+    },
+};
+
+const PREDICATE: &str = "predicate";
+
+/// Matches the files not matched by the given predicate. When several predicates are given,
+/// matches the files that don't match all of them.
+#[derive(Clone, Debug)]
+pub struct NotPredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl NotPredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            PREDICATE,
+            StrategyParameterKind::Predicate,
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        NotPredicate {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for NotPredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for NotPredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for NotPredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        validate_nested_predicates(&self.parameters, PREDICATE)
+    }
+}
+
+impl strategy::ParameterDetails for NotPredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for NotPredicate {
+    fn name(&self) -> String {
+        "not".to_string()
+    }
+}
+
+impl predicate::Matches for NotPredicate {
+    fn matches(&self, file_path: &Path, file: &std::fs::File) -> bool {
+        !nested_predicates(&self.parameters, PREDICATE)
+            .iter()
+            .all(|predicate| predicate.matches(file_path, file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            predicate::Matches,
+            strategy::{AddParameter, Validate},
+        },
+        sorting_strategies::predicate::all_predicates,
+    };
+
+    fn predicate(name: &str, key: &str, value: StrategyParameter) -> Box<dyn predicate::Predicate> {
+        let mut predicate = all_predicates().get_predicate(&name.to_string()).unwrap();
+        predicate.add_parameter(key.to_string(), value);
+
+        predicate
+    }
+
+    fn extension(extensions: &str) -> Box<dyn predicate::Predicate> {
+        predicate(
+            "extension",
+            "extensions",
+            StrategyParameter::SingleString(extensions.to_string()),
+        )
+    }
+
+    #[test]
+    fn should_negate_nested_combinators() {
+        let or = predicate(
+            "or",
+            "predicates",
+            StrategyParameter::Predicate(vec![extension("jpg"), extension("png")]),
+        );
+        let mut not = NotPredicate::new();
+        not.add_parameter(
+            PREDICATE.to_string(),
+            StrategyParameter::Predicate(vec![or]),
+        );
+        let file = utils::manifest_file();
+
+        assert!(not.validate().is_ok());
+        assert!(!not.matches(Path::new("a.png"), &file));
+        assert!(not.matches(Path::new("a.pdf"), &file));
+    }
+
+    #[test]
+    fn should_validate_nested_predicates() {
+        let and = predicate(
+            "and",
+            "predicates",
+            StrategyParameter::Predicate(vec![all_predicates()
+                .get_predicate(&"extension".to_string())
+                .unwrap()]),
+        );
+        let mut not = NotPredicate::new();
+        not.add_parameter(
+            PREDICATE.to_string(),
+            StrategyParameter::Predicate(vec![and]),
+        );
+
+        assert!(matches!(
+            not.validate(),
+            Err(validation::error::Error::MissingMandatoryParameter(_))
+        ));
+    }
+}
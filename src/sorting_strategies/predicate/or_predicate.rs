@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::{
+        predicate::{nested_predicates, validate_nested_predicates},
+        utils,
+    },
+};
+
+const PREDICATES: &str = "predicates";
+
+/// Matches the files matched by at least one of the given predicates.
+#[derive(Clone, Debug)]
+pub struct OrPredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl OrPredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            PREDICATES,
+            StrategyParameterKind::Predicate,
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        OrPredicate {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for OrPredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for OrPredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for OrPredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+        validate_nested_predicates(&self.parameters, PREDICATES)
+    }
+}
+
+impl strategy::ParameterDetails for OrPredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for OrPredicate {
+    fn name(&self) -> String {
+        "or".to_string()
+    }
+}
+
+impl predicate::Matches for OrPredicate {
+    fn matches(&self, file_path: &Path, file: &std::fs::File) -> bool {
+        nested_predicates(&self.parameters, PREDICATES)
+            .iter()
+            .any(|predicate| predicate.matches(file_path, file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{
+            predicate::Matches,
+            strategy::{AddParameter, Validate},
+        },
+        sorting_strategies::predicate::{
+            extension_predicate::ExtensionPredicate, name_predicate::NamePredicate,
+        },
+    };
+
+    #[test]
+    fn should_match_files_matched_by_any_predicate() {
+        let mut extension = ExtensionPredicate::new();
+        extension.add_parameter(
+            "extensions".to_string(),
+            StrategyParameter::SingleString("pdf".to_string()),
+        );
+        let mut name = NamePredicate::new();
+        name.add_parameter(
+            "pattern".to_string(),
+            StrategyParameter::SingleString("^scan_".to_string()),
+        );
+        let mut or = OrPredicate::new();
+        or.add_parameter(
+            PREDICATES.to_string(),
+            StrategyParameter::Predicate(vec![Box::new(extension), Box::new(name)]),
+        );
+        let file = utils::manifest_file();
+
+        assert!(or.validate().is_ok());
+        assert!(or.matches(Path::new("a.pdf"), &file));
+        assert!(or.matches(Path::new("scan_01.png"), &file));
+        assert!(!or.matches(Path::new("a.png"), &file));
+    }
+
+    #[test]
+    fn should_validate_nested_predicates() {
+        let mut name = NamePredicate::new();
+        name.add_parameter(
+            "pattern".to_string(),
+            StrategyParameter::SingleString("(".to_string()),
+        );
+        let mut or = OrPredicate::new();
+        or.add_parameter(
+            PREDICATES.to_string(),
+            StrategyParameter::Predicate(vec![Box::new(name)]),
+        );
+
+        assert!(matches!(
+            or.validate(),
+            Err(validation::error::Error::InvalidValue(name, _)) if name == "pattern"
+        ));
+    }
+}
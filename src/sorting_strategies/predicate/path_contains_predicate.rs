@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const VALUE: &str = "value";
+
+/// Matches the files whose full path contains `value`.
+#[derive(Clone, Debug)]
+pub struct PathContainsPredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl PathContainsPredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator.add_validator(validation::ParameterDetail::new(
+            VALUE,
+            StrategyParameterKind::SingleString,
+            true,
+        ));
+
+        let parameters = validator.default_parameters();
+
+        PathContainsPredicate {
+            validator,
+            parameters,
+        }
+    }
+}
+
+impl strategy::Parameters for PathContainsPredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for PathContainsPredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for PathContainsPredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)
+    }
+}
+
+impl strategy::ParameterDetails for PathContainsPredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for PathContainsPredicate {
+    fn name(&self) -> String {
+        "path contains".to_string()
+    }
+}
+
+impl predicate::Matches for PathContainsPredicate {
+    fn matches(&self, file_path: &Path, _: &std::fs::File) -> bool {
+        match self.parameters.get(VALUE) {
+            Some(StrategyParameter::SingleString(value)) => {
+                file_path.to_string_lossy().contains(value.as_str())
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{predicate::Matches, strategy::AddParameter};
+
+    #[test]
+    fn should_match_any_part_of_the_path() {
+        let mut predicate = PathContainsPredicate::new();
+        predicate.add_parameter(
+            VALUE.to_string(),
+            StrategyParameter::SingleString("2023/".to_string()),
+        );
+        let file = utils::manifest_file();
+
+        assert!(predicate.matches(Path::new("/photos/2023/a.jpg"), &file));
+        assert!(predicate.matches(Path::new("/photos/holidays_2023/a.jpg"), &file));
+        assert!(!predicate.matches(Path::new("/photos/2023_a.jpg"), &file));
+    }
+}
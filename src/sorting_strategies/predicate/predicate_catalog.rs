@@ -0,0 +1,55 @@
+use crate::core::predicate::Predicate;
+
+#[derive(Default, Clone, Debug)]
+pub struct PredicateCatalog {
+    predicates: Vec<Box<dyn Predicate>>,
+}
+
+impl PredicateCatalog {
+    pub fn new(predicates: Vec<Box<dyn Predicate>>) -> Self {
+        PredicateCatalog { predicates }
+    }
+
+    pub fn get_predicate(&self, predicate_name: &String) -> Option<Box<dyn Predicate>> {
+        self.predicates
+            .iter()
+            .find(|predicate| predicate.name() == *predicate_name)
+            .cloned()
+    }
+
+    pub fn get_names(&self) -> Vec<String> {
+        self.predicates
+            .iter()
+            .map(|predicate| predicate.name())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sorting_strategies::predicate::all_predicates;
+
+    #[test]
+    fn should_find_predicates_by_name() {
+        let catalog = all_predicates();
+
+        assert_eq!(
+            catalog
+                .get_predicate(&"path contains".to_string())
+                .map(|predicate| predicate.name()),
+            Some("path contains".to_string())
+        );
+        assert!(catalog.get_predicate(&"unknown".to_string()).is_none());
+    }
+
+    #[test]
+    fn should_list_every_predicate_once() {
+        let names = all_predicates().get_names();
+
+        assert_eq!(names.len(), 9);
+        assert!(names
+            .iter()
+            .enumerate()
+            .all(|(index, name)| !names[..index].contains(name)));
+    }
+}
@@ -0,0 +1,147 @@
+use std::path::Path;
+
+use crate::{
+    core::{
+        parameter::{parse_size, StrategyParameter, StrategyParameterKind},
+        predicate, strategy, validation,
+    },
+    sorting_strategies::utils,
+};
+
+const MIN: &str = "min";
+const MAX: &str = "max";
+
+/// Matches the files whose size is between `min` and `max` (both included), which are written
+/// with their unit, such as `10MB`, or in bytes.
+#[derive(Clone, Debug)]
+pub struct SizePredicate {
+    validator: utils::BaseValidator,
+    parameters: std::collections::HashMap<String, StrategyParameter>,
+}
+
+impl SizePredicate {
+    pub fn new() -> Self {
+        let mut validator = utils::BaseValidator::new();
+        validator
+            .add_validator(validation::ParameterDetail::new(
+                MIN,
+                StrategyParameterKind::Size,
+                false,
+            ))
+            .add_validator(validation::ParameterDetail::new(
+                MAX,
+                StrategyParameterKind::Size,
+                false,
+            ));
+
+        let parameters = validator.default_parameters();
+
+        SizePredicate {
+            validator,
+            parameters,
+        }
+    }
+
+    fn bound(&self, name: &str) -> Result<Option<u64>, validation::error::Error> {
+        utils::string_parameter(&self.parameters, name)
+            .map(|size| parse_size(size))
+            .transpose()
+            .map_err(|reason| validation::error::Error::InvalidValue(name.to_string(), reason))
+    }
+}
+
+impl strategy::Parameters for SizePredicate {
+    fn parameters(&self) -> std::collections::HashMap<String, StrategyParameter> {
+        self.parameters.clone()
+    }
+}
+
+impl strategy::AddParameter for SizePredicate {
+    fn add_parameter(&mut self, key: String, value: StrategyParameter) {
+        self.parameters.insert(key, value);
+    }
+}
+
+impl strategy::Validate for SizePredicate {
+    fn validate(&self) -> Result<(), validation::error::Error> {
+        self.validator.validate(&self.parameters)?;
+
+        match (self.bound(MIN)?, self.bound(MAX)?) {
+            (Some(min), Some(max)) if min > max => Err(validation::error::Error::InvalidValue(
+                MIN.to_string(),
+                format!("{min} is greater than {MAX} ({max})"),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl strategy::ParameterDetails for SizePredicate {
+    fn parameter_details(&self) -> Vec<validation::ParameterDetail> {
+        self.validator.parameter_details()
+    }
+}
+
+impl strategy::Name for SizePredicate {
+    fn name(&self) -> String {
+        "size".to_string()
+    }
+}
+
+impl predicate::Matches for SizePredicate {
+    fn matches(&self, _: &Path, file: &std::fs::File) -> bool {
+        let Ok(metadata) = file.metadata() else {
+            return false;
+        };
+
+        let size = metadata.len();
+        match (self.bound(MIN), self.bound(MAX)) {
+            (Ok(min), Ok(max)) => {
+                min.is_none_or(|min| size >= min) && max.is_none_or(|max| size <= max)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        predicate::Matches,
+        strategy::{AddParameter, Validate},
+    };
+
+    fn size(min: Option<&str>, max: Option<&str>) -> SizePredicate {
+        let mut predicate = SizePredicate::new();
+        for (name, value) in [(MIN, min), (MAX, max)] {
+            if let Some(value) = value {
+                predicate.add_parameter(
+                    name.to_string(),
+                    StrategyParameter::SingleString(value.to_string()),
+                );
+            }
+        }
+
+        predicate
+    }
+
+    #[test]
+    fn should_match_sizes_within_bounds() {
+        let file = utils::manifest_file();
+        let path = Path::new("Cargo.toml");
+
+        assert!(size(None, None).matches(path, &file));
+        assert!(size(Some("1"), None).matches(path, &file));
+        assert!(!size(None, Some("1")).matches(path, &file));
+        assert!(size(None, Some("1MB")).matches(path, &file));
+        assert!(!size(Some("1 KiB"), Some("0.5MB")).matches(path, &file));
+    }
+
+    #[test]
+    fn should_reject_min_greater_than_max() {
+        assert!(size(Some("10KB"), Some("10000")).validate().is_ok());
+        assert!(size(Some("11KB"), Some("10KB")).validate().is_err());
+        assert!(size(Some("10 parsecs"), None).validate().is_err());
+    }
+}
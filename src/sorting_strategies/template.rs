@@ -0,0 +1,421 @@
+//! Path templates such as `{year}/{month:%m}/{ext}` or `{exif.model|unknown}/{name[0]}`.
+//!
+//! A placeholder names a strategy of the catalog, or a short name such as `ext`, `type` or
+//! `exif.model`, and may be followed by:
+//! - a character range of its value, `[0]`, `[0..3]`, `[2..]` or `[..3]`;
+//! - a format, `:%m`, given to the `format` parameter of the strategy. `year` and `month` accept
+//!   one too, which makes them the `date` strategy;
+//! - a list of `|` separated filters, the operations of [`Operation`] such as `slug` or
+//!   `truncate:10`, and fallbacks, tried in order when the value is missing. A fallback naming
+//!   a strategy is applied, any other fallback is kept as text.
+//!
+//! `name` is the file name without its extension, `a.b` gives the strategy `a` with its `field`
+//! parameter set to `b`, and `{{` and `}}` are literal braces. `/` separates the folder levels.
+
+use std::path::PathBuf;
+
+use crate::{
+    core::{context, error, parameter::StrategyParameter, strategy::Strategy},
+    sorting_strategies::{catalog::all_catalog, text_transform::Operation},
+};
+
+const FORMAT: &str = "format";
+const FIELD: &str = "field";
+const FILE_NAME: &str = "name";
+
+/// Short names of the strategies, with the value of their `field` parameter.
+const ALIASES: [(&str, &str, Option<&str>); 10] = [
+    ("ext", "file extension", None),
+    ("type", "file type", None),
+    ("mime", "mime type", None),
+    ("path", "relative path", None),
+    ("exif", "camera", None),
+    ("exif.make", "camera", Some("make")),
+    ("exif.model", "camera", Some("model")),
+    ("exif.lens", "camera", Some("lens")),
+    ("exif.date", "exif date", None),
+    ("music", "music tag", None),
+];
+
+/// Strategies taking a preset format, replaced by the `date` strategy when given a format.
+const PRESET_DATES: [&str; 2] = ["year", "month"];
+
+#[derive(Clone, Debug)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Clone, Debug)]
+enum Part {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+#[derive(Clone, Debug)]
+struct Placeholder {
+    alternatives: Vec<Alternative>,
+    filters: Vec<Operation>,
+}
+
+#[derive(Clone, Debug)]
+enum Alternative {
+    Value(Source, CharRange),
+    Text(String),
+}
+
+#[derive(Clone, Debug)]
+enum Source {
+    FileName,
+    Strategy(Box<dyn Strategy>),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct CharRange {
+    start: usize,
+    end: Option<usize>,
+}
+
+impl Template {
+    /// Template built from `template`, or the reason why it is invalid.
+    pub fn parse(template: &str) -> Result<Template, String> {
+        let mut parts = vec![];
+        let mut text = String::new();
+        let mut chars = template.char_indices().peekable();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '{' if chars.next_if(|(_, c)| *c == '{').is_some() => text.push('{'),
+                '}' if chars.next_if(|(_, c)| *c == '}').is_some() => text.push('}'),
+                '{' => {
+                    let start = index + 1;
+                    let end = chars
+                        .by_ref()
+                        .find(|(_, c)| *c == '}')
+                        .map(|(end, _)| end)
+                        .ok_or(format!(
+                            "unclosed placeholder at position {}",
+                            position(template, index)
+                        ))?;
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Placeholder(Placeholder::parse(
+                        &template[start..end],
+                    )?));
+                }
+                '}' => {
+                    return Err(format!(
+                        "unexpected '}}' at position {}, write '}}}}' for a brace",
+                        position(template, index)
+                    ))
+                }
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+
+        Ok(Template { parts })
+    }
+
+    /// Folders of the template for the file, without the empty levels.
+    pub fn render(&self, file_path: &PathBuf, file: &std::fs::File) -> Option<String> {
+        let rendered = self
+            .parts
+            .iter()
+            .map(|part| match part {
+                Part::Text(text) => text.clone(),
+                Part::Placeholder(placeholder) => placeholder.render(file_path, file),
+            })
+            .collect::<String>();
+
+        let folders = rendered
+            .split('/')
+            .map(str::trim)
+            .filter(|folder| !folder.is_empty())
+            .collect::<Vec<_>>();
+
+        (!folders.is_empty()).then(|| folders.join("/"))
+    }
+
+    pub fn process_context(
+        &mut self,
+        context: context::StrategyContext,
+    ) -> Result<(), error::Error> {
+        for part in self.parts.iter_mut() {
+            let Part::Placeholder(placeholder) = part else {
+                continue;
+            };
+            for alternative in placeholder.alternatives.iter_mut() {
+                if let Alternative::Value(Source::Strategy(strategy), _) = alternative {
+                    strategy.process_context(context.clone())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 1-based position of the character at `index`.
+fn position(template: &str, index: usize) -> usize {
+    template[..index].chars().count() + 1
+}
+
+impl Placeholder {
+    fn parse(placeholder: &str) -> Result<Placeholder, String> {
+        let mut segments = placeholder.split('|').map(str::trim);
+        let first = segments.next().unwrap_or_default();
+        if first.is_empty() {
+            return Err(format!("empty placeholder '{{{placeholder}}}'"));
+        }
+
+        let mut alternatives =
+            vec![Alternative::parse_value(first)?.ok_or(format!("unknown placeholder '{first}'"))?];
+        let mut filters = vec![];
+        for segment in segments {
+            if Operation::is_operation(segment) {
+                filters.push(Operation::parse(segment)?);
+            } else if let Some(alternative) = Alternative::parse_value(segment)? {
+                alternatives.push(alternative);
+            } else {
+                alternatives.push(Alternative::Text(segment.to_string()));
+            }
+        }
+
+        Ok(Placeholder {
+            alternatives,
+            filters,
+        })
+    }
+
+    fn render(&self, file_path: &PathBuf, file: &std::fs::File) -> String {
+        let value = self
+            .alternatives
+            .iter()
+            .find_map(|alternative| alternative.value(file_path, file))
+            .unwrap_or_default();
+
+        self.filters
+            .iter()
+            .fold(value, |value, filter| filter.apply(&value))
+    }
+}
+
+impl Alternative {
+    /// Value of a source such as `month:%m` or `name[0]`, `None` when no source has this name.
+    fn parse_value(expression: &str) -> Result<Option<Alternative>, String> {
+        let (head, format) = match expression.split_once(':') {
+            Some((head, format)) => (head.trim(), Some(format)),
+            None => (expression, None),
+        };
+        let (name, range) = match head.strip_suffix(']').and_then(|head| head.split_once('[')) {
+            Some((name, range)) => (name.trim(), CharRange::parse(range)?),
+            None => (head, CharRange::default()),
+        };
+
+        let Some(source) = Source::resolve(name, format)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Alternative::Value(source, range)))
+    }
+
+    fn value(&self, file_path: &PathBuf, file: &std::fs::File) -> Option<String> {
+        let value = match self {
+            Alternative::Text(text) => return Some(text.clone()),
+            Alternative::Value(Source::FileName, range) => range.slice(
+                &file_path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())?,
+            ),
+            Alternative::Value(Source::Strategy(strategy), range) => {
+                range.slice(&strategy.apply(file_path, file)?)
+            }
+        };
+
+        (!value.is_empty()).then_some(value)
+    }
+}
+
+impl Source {
+    fn resolve(name: &str, format: Option<&str>) -> Result<Option<Source>, String> {
+        if name == FILE_NAME {
+            return match format {
+                Some(_) => Err(format!("'{name}' takes no format")),
+                None => Ok(Some(Source::FileName)),
+            };
+        }
+
+        let catalog = all_catalog();
+        let names = catalog.get_names();
+        let known = |name: &str| -> Option<(String, Option<String>)> {
+            if let Some((_, strategy, field)) = ALIASES.iter().find(|(alias, _, _)| *alias == name)
+            {
+                return Some((strategy.to_string(), field.map(str::to_string)));
+            }
+            names
+                .iter()
+                .any(|known| known == name)
+                .then(|| (name.to_string(), None))
+        };
+        let Some((mut strategy_name, field)) = known(name).or_else(|| {
+            let (base, field) = name.rsplit_once('.')?;
+            let (strategy, _) = known(base)?;
+            Some((strategy, Some(field.to_string())))
+        }) else {
+            return Ok(None);
+        };
+
+        if format.is_some() && PRESET_DATES.contains(&strategy_name.as_str()) {
+            strategy_name = "date".to_string();
+        }
+        let Some(mut strategy) = catalog.get_strategy(&strategy_name) else {
+            return Ok(None);
+        };
+
+        for (parameter, value) in [(FIELD, field.as_deref()), (FORMAT, format)] {
+            let Some(value) = value else {
+                continue;
+            };
+            if !strategy
+                .parameter_details()
+                .iter()
+                .any(|detail| detail.name == parameter)
+            {
+                return Err(format!("'{name}' takes no {parameter}"));
+            }
+            strategy.add_parameter(
+                parameter.to_string(),
+                StrategyParameter::SingleString(value.to_string()),
+            );
+        }
+        strategy
+            .validate()
+            .map_err(|err| format!("'{name}': {err}"))?;
+
+        Ok(Some(Source::Strategy(strategy)))
+    }
+}
+
+impl CharRange {
+    /// Range written as `0`, `0..3`, `2..` or `..3`.
+    fn parse(range: &str) -> Result<CharRange, String> {
+        let number = |value: &str| {
+            value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("'[{range}]' is not a range such as '[0]' or '[0..3]'"))
+        };
+
+        match range.split_once("..") {
+            Some((start, end)) => Ok(CharRange {
+                start: if start.trim().is_empty() {
+                    0
+                } else {
+                    number(start)?
+                },
+                end: if end.trim().is_empty() {
+                    None
+                } else {
+                    Some(number(end)?)
+                },
+            }),
+            None => {
+                let start = number(range)?;
+                Ok(CharRange {
+                    start,
+                    end: Some(start + 1),
+                })
+            }
+        }
+    }
+
+    fn slice(&self, value: &str) -> String {
+        value
+            .chars()
+            .skip(self.start)
+            .take(self.end.unwrap_or(usize::MAX).saturating_sub(self.start))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sorting_strategies::utils;
+
+    fn render(template: &str, file_path: &str) -> Option<String> {
+        Template::parse(template)
+            .unwrap()
+            .render(&PathBuf::from(file_path), &utils::manifest_file())
+    }
+
+    #[test]
+    fn should_interpolate_placeholders() {
+        assert_eq!(
+            render("{ext}/{name[0]}", "dir/Report 2024.PDF"),
+            Some("PDF/R".to_string())
+        );
+        assert_eq!(
+            render("{{{ext|lower}}}-{name[..6]|slug}", "Report 2024.PDF"),
+            Some("{pdf}-report".to_string())
+        );
+        assert_eq!(
+            render("{name|upper|truncate:3}", "photo.jpg"),
+            Some("PHO".to_string())
+        );
+    }
+
+    #[test]
+    fn should_fall_back_when_a_value_is_missing() {
+        assert_eq!(
+            render("{exif.model|unknown}/{ext}", "photo.jpg"),
+            Some("unknown/jpg".to_string())
+        );
+        assert_eq!(
+            render("{exif.model|ext}", "photo.jpg"),
+            Some("jpg".to_string())
+        );
+        assert_eq!(render("{exif.model}//{name[9]}", "photo.jpg"), None);
+    }
+
+    #[test]
+    fn should_give_formats_to_the_strategies() {
+        let Part::Placeholder(placeholder) = &Template::parse("{month:%m}").unwrap().parts[0]
+        else {
+            panic!("a placeholder is expected");
+        };
+        let Alternative::Value(Source::Strategy(strategy), _) = &placeholder.alternatives[0] else {
+            panic!("a strategy is expected");
+        };
+
+        assert_eq!(strategy.name(), "date");
+        assert_eq!(
+            strategy.parameters().get(FORMAT),
+            Some(&StrategyParameter::SingleString("%m".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_report_parse_errors() {
+        for (template, error) in [
+            ("{year", "unclosed placeholder at position 1"),
+            ("a}", "unexpected '}' at position 2, write '}}' for a brace"),
+            ("{}", "empty placeholder '{}'"),
+            ("{unknown}", "unknown placeholder 'unknown'"),
+            (
+                "{name[a]}",
+                "'[a]' is not a range such as '[0]' or '[0..3]'",
+            ),
+            ("{ext:%m}", "'ext' takes no format"),
+            (
+                "{name|truncate}",
+                "'truncate' expects a length such as 'truncate:20'",
+            ),
+        ] {
+            assert_eq!(Template::parse(template).unwrap_err(), error, "{template}");
+        }
+    }
+}
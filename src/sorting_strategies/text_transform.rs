@@ -0,0 +1,240 @@
+//! Operations normalising the folder names given by the strategies, written as `name` or
+//! `name:argument`: `lower`, `upper`, `title`, `slug`, `ascii`, `trim`, `trim:_-`, `truncate:20`,
+//! `strip digits` and `replace:from:to`.
+
+/// Latin letters and the ASCII text they are written with.
+const TRANSLITERATIONS: [(char, &str); 62] = [
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('ä', "a"),
+    ('å', "a"),
+    ('ā', "a"),
+    ('ą', "a"),
+    ('æ', "ae"),
+    ('ç', "c"),
+    ('ć', "c"),
+    ('č', "c"),
+    ('ď', "d"),
+    ('đ', "d"),
+    ('ð', "d"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ē', "e"),
+    ('ę', "e"),
+    ('ě', "e"),
+    ('ğ', "g"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ī', "i"),
+    ('ı', "i"),
+    ('ł', "l"),
+    ('ľ', "l"),
+    ('ñ', "n"),
+    ('ń', "n"),
+    ('ň', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ö', "o"),
+    ('ø', "o"),
+    ('ō', "o"),
+    ('ő', "o"),
+    ('œ', "oe"),
+    ('ŕ', "r"),
+    ('ř', "r"),
+    ('ś', "s"),
+    ('š', "s"),
+    ('ş', "s"),
+    ('ß', "ss"),
+    ('ť', "t"),
+    ('ţ', "t"),
+    ('þ', "th"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ü', "u"),
+    ('ū', "u"),
+    ('ů', "u"),
+    ('ű', "u"),
+    ('ý', "y"),
+    ('ÿ', "y"),
+    ('ž', "z"),
+];
+
+/// Names of the operations.
+pub const OPERATIONS: [&str; 9] = [
+    "lower",
+    "upper",
+    "title",
+    "slug",
+    "ascii",
+    "trim",
+    "truncate",
+    "strip digits",
+    "replace",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation {
+    Lower,
+    Upper,
+    /// Upper case first letter of each word, lower case other letters.
+    Title,
+    Slug,
+    Ascii,
+    /// Removes the given characters, whitespace by default, from both ends.
+    Trim(Option<String>),
+    Truncate(usize),
+    StripDigits,
+    Replace(String, String),
+}
+
+impl Operation {
+    /// Whether `operation` names an operation, whatever its argument.
+    pub fn is_operation(operation: &str) -> bool {
+        let name = operation.split(':').next().unwrap_or_default();
+        OPERATIONS.contains(&name.trim().to_lowercase().as_str())
+    }
+
+    pub fn parse(operation: &str) -> Result<Operation, String> {
+        let (name, argument) = match operation.split_once(':') {
+            Some((name, argument)) => (name.trim(), Some(argument)),
+            None => (operation.trim(), None),
+        };
+
+        match (name.to_lowercase().as_str(), argument) {
+            ("lower", None) => Ok(Operation::Lower),
+            ("upper", None) => Ok(Operation::Upper),
+            ("title", None) => Ok(Operation::Title),
+            ("slug", None) => Ok(Operation::Slug),
+            ("ascii", None) => Ok(Operation::Ascii),
+            ("trim", characters) => Ok(Operation::Trim(
+                characters.filter(|c| !c.is_empty()).map(str::to_string),
+            )),
+            ("truncate", length) => length
+                .and_then(|length| length.trim().parse().ok())
+                .map(Operation::Truncate)
+                .ok_or(format!(
+                    "'{operation}' expects a length such as 'truncate:20'"
+                )),
+            ("strip digits", None) => Ok(Operation::StripDigits),
+            ("replace", Some(argument)) => argument
+                .split_once(':')
+                .filter(|(from, _)| !from.is_empty())
+                .map(|(from, to)| Operation::Replace(from.to_string(), to.to_string()))
+                .ok_or(format!("'{operation}' expects 'replace:from:to'")),
+            _ => Err(format!("unknown operation '{operation}'")),
+        }
+    }
+
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            Operation::Lower => value.to_lowercase(),
+            Operation::Upper => value.to_uppercase(),
+            Operation::Title => title_case(value),
+            Operation::Slug => slugify(value),
+            Operation::Ascii => transliterate(value),
+            Operation::Trim(None) => value.trim().to_string(),
+            Operation::Trim(Some(characters)) => {
+                value.trim_matches(|c| characters.contains(c)).to_string()
+            }
+            Operation::Truncate(length) => value.chars().take(*length).collect(),
+            Operation::StripDigits => value.chars().filter(|c| !c.is_ascii_digit()).collect(),
+            Operation::Replace(from, to) => value.replace(from, to),
+        }
+    }
+}
+
+fn title_case(value: &str) -> String {
+    let mut previous = None;
+    value
+        .chars()
+        .flat_map(|c| {
+            let starts_word = !previous.is_some_and(char::is_alphanumeric);
+            previous = Some(c);
+            if starts_word {
+                c.to_uppercase().collect::<Vec<_>>()
+            } else {
+                c.to_lowercase().collect::<Vec<_>>()
+            }
+        })
+        .collect()
+}
+
+/// Text with the accented Latin letters written in ASCII, other characters are kept.
+pub fn transliterate(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            let lowercase = c.to_lowercase().next().unwrap_or(c);
+            match TRANSLITERATIONS
+                .iter()
+                .find(|(letter, _)| *letter == lowercase)
+            {
+                Some((_, ascii)) if c.is_uppercase() => title_case(ascii),
+                Some((_, ascii)) => ascii.to_string(),
+                None => c.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Lower case ASCII words separated by dashes, such as `ete-2024` for `Été 2024!`.
+pub fn slugify(value: &str) -> String {
+    transliterate(value)
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(operation: &str, value: &str) -> String {
+        Operation::parse(operation).unwrap().apply(value)
+    }
+
+    #[test]
+    fn should_apply_operations() {
+        assert_eq!(apply("title", "05_mai du PRINTEMPS"), "05_Mai Du Printemps");
+        assert_eq!(
+            apply("slug", "Été 2024: Côte d'Azur!"),
+            "ete-2024-cote-d-azur"
+        );
+        assert_eq!(apply("ascii", "Ærøskøbing Straße"), "Aeroskobing Strasse");
+        assert_eq!(apply("trim:_-", "_05_mai-"), "05_mai");
+        assert_eq!(apply("strip digits", "05_mai"), "_mai");
+        assert_eq!(apply("replace:_: ", "05_mai"), "05 mai");
+        assert_eq!(apply("truncate:3", "février"), "fév");
+        assert_eq!(apply("Upper", "jpg"), "JPG");
+    }
+
+    #[test]
+    fn should_reject_invalid_operations() {
+        for (operation, error) in [
+            ("shout", "unknown operation 'shout'"),
+            ("lower:x", "unknown operation 'lower:x'"),
+            (
+                "truncate",
+                "'truncate' expects a length such as 'truncate:20'",
+            ),
+            (
+                "truncate:x",
+                "'truncate:x' expects a length such as 'truncate:20'",
+            ),
+            ("replace:_", "'replace:_' expects 'replace:from:to'"),
+        ] {
+            assert_eq!(Operation::parse(operation), Err(error.to_string()));
+        }
+    }
+}
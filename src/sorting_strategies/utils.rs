@@ -60,10 +60,140 @@ impl BaseValidator {
     }
 }
 
+/// Validates the strategies given to the `name` parameter: the pipeline only validates the top
+/// level ones, so each strategy holding others checks them itself.
+pub fn validate_nested_strategies(
+    parameters: &std::collections::HashMap<String, crate::core::parameter::StrategyParameter>,
+    name: &str,
+) -> Result<(), crate::core::validation::error::Error> {
+    match parameters.get(name) {
+        Some(crate::core::parameter::StrategyParameter::Strategy(strategies)) => strategies
+            .iter()
+            .try_for_each(|strategy| strategy.validate()),
+        _ => Ok(()),
+    }
+}
+
+/// Text given to the parameter `name`, if any.
+pub fn string_parameter<'a>(
+    parameters: &'a std::collections::HashMap<String, crate::core::parameter::StrategyParameter>,
+    name: &str,
+) -> Option<&'a String> {
+    match parameters.get(name) {
+        Some(crate::core::parameter::StrategyParameter::SingleString(value)) => Some(value),
+        _ => None,
+    }
+}
+
+/// Number given to the parameter `name`, if any.
+pub fn number_parameter(
+    parameters: &std::collections::HashMap<String, crate::core::parameter::StrategyParameter>,
+    name: &str,
+) -> Option<usize> {
+    match parameters.get(name) {
+        Some(crate::core::parameter::StrategyParameter::Number(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+/// Compiles the regular expression given to `parameter`. Syntax errors point to the 1-based
+/// position of the offending character in the pattern.
+pub fn compile_regex(
+    parameter: &str,
+    pattern: &str,
+) -> Result<regex::Regex, crate::core::validation::error::Error> {
+    regex::Regex::new(pattern).map_err(|err| {
+        let located = |kind: &dyn std::fmt::Display, span: &regex_syntax::ast::Span| {
+            let position = pattern[..span.start.offset].chars().count() + 1;
+            format!("{} at position {}", kind, position)
+        };
+        let reason = match regex_syntax::Parser::new().parse(pattern) {
+            Err(regex_syntax::Error::Parse(err)) => located(err.kind(), err.span()),
+            Err(regex_syntax::Error::Translate(err)) => located(err.kind(), err.span()),
+            _ => err.to_string(),
+        };
+
+        crate::core::validation::error::Error::InvalidValue(parameter.to_string(), reason)
+    })
+}
+
+/// Value read from a file, such as a lens or an artist, as a single folder name: `AC/DC` must not
+/// create sub folders.
+pub fn folder_name(value: &str) -> String {
+    value.replace(['/', '\\'], "-")
+}
+
+/// Handle to the manifest of the crate, for tests needing an open file without caring for its
+/// content.
+#[cfg(test)]
+pub fn manifest_file() -> std::fs::File {
+    std::fs::File::open("Cargo.toml").unwrap()
+}
+
+/// Folder given by the strategy to the file at `file_path`, whose content is the manifest.
+#[cfg(test)]
+pub fn apply_to(strategy: &dyn crate::core::strategy::Apply, file_path: &str) -> Option<String> {
+    strategy.apply(&std::path::PathBuf::from(file_path), &manifest_file())
+}
+
+/// Folder given by the strategy to a temporary file named after `file_name`, holding `content`.
+#[cfg(test)]
+pub fn apply_to_content(
+    strategy: &dyn crate::core::strategy::Apply,
+    file_name: &str,
+    content: &[u8],
+) -> Option<String> {
+    let path = std::env::temp_dir().join(format!(
+        "{}_{file_name}",
+        crate::utils::string_manipulator::random_string(10)
+    ));
+    std::fs::write(&path, content).unwrap();
+    let folder = strategy.apply(&path, &std::fs::File::open(&path).unwrap());
+    std::fs::remove_file(&path).unwrap();
+
+    folder
+}
+
+/// Strategy given the text `parameters`.
+#[cfg(test)]
+pub fn with_parameters<S: crate::core::strategy::AddParameter>(
+    mut strategy: S,
+    parameters: Vec<(&str, &str)>,
+) -> S {
+    for (key, value) in parameters {
+        strategy.add_parameter(
+            key.to_string(),
+            crate::core::parameter::StrategyParameter::SingleString(value.to_string()),
+        );
+    }
+
+    strategy
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn should_locate_regex_syntax_errors() {
+        assert_eq!(
+            compile_regex("pattern", "a{2,1}").unwrap_err(),
+            crate::core::validation::error::Error::InvalidValue(
+                "pattern".to_string(),
+                "invalid repetition count range, the start must be <= the end at position 2"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            compile_regex("pattern", "é(").unwrap_err(),
+            crate::core::validation::error::Error::InvalidValue(
+                "pattern".to_string(),
+                "unclosed group at position 2".to_string()
+            )
+        );
+        assert!(compile_regex("pattern", "^(?P<client>\\w+)_").is_ok());
+    }
+
     mod test_sorting_strategy_validate {
         use crate::{
             core::{
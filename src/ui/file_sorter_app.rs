@@ -5,6 +5,7 @@ use crate::{
     ui::{
         screen::{
             sorter_form::{self, SorterForm},
+            summary_panel::{self, SummaryPanel},
             tree_preview::{self, TreePreview},
         },
         shared,
@@ -15,32 +16,38 @@ use crate::{
 pub struct FileSorterApp {
     sorter_form: SorterForm,
     tree_preview: Option<TreePreview>,
+    summary_panel: Option<SummaryPanel>,
     pipeline: Option<crate::core::SortPipeline>,
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum Message {
     TreePreviewMessage(tree_preview::Message),
     SorterFormMessage(sorter_form::Message),
+    SummaryPanelMessage(summary_panel::Message),
 }
 
 #[derive(Debug, Clone)]
 pub enum LogMessage {
-    Warning(String),
-    Error(String),
+    /// A message, and an optional hint telling the user how to fix the issue.
+    Warning(String, Option<String>),
+    Error(String, Option<String>),
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::enum_variant_names)]
 pub enum EventWrapper {
     SorterFormEvent(sorter_form::Event),
     TreePreviewEvent(tree_preview::Event),
+    SummaryPanelEvent(summary_panel::Event),
 }
 
 impl Into<AlertSeverity> for &LogMessage {
     fn into(self) -> AlertSeverity {
         match self {
-            LogMessage::Warning(_) => AlertSeverity::Warning,
-            LogMessage::Error(_) => AlertSeverity::Error,
+            LogMessage::Warning(_, _) => AlertSeverity::Warning,
+            LogMessage::Error(_, _) => AlertSeverity::Error,
         }
     }
 }
@@ -56,6 +63,7 @@ impl FileSorterApp {
         FileSorterApp {
             sorter_form: SorterForm::default(),
             tree_preview: None,
+            summary_panel: None,
             pipeline: None,
         }
     }
@@ -76,11 +84,14 @@ impl FileSorterApp {
         let pipeline = self.pipeline.as_mut().unwrap();
         match pipeline.process() {
             Err(e) => {
-                log_messages.push(LogMessage::Error(e.to_string()));
+                log_messages.push(LogMessage::Error(e.to_string(), e.hint()));
             }
             Ok(Some(reports)) => {
                 if pipeline.has_next() {
                     self.tree_preview = Some(TreePreview::new(reports.clone()));
+                    self.summary_panel = None;
+                } else {
+                    self.summary_panel = Some(SummaryPanel::new(pipeline.summary(&reports)));
                 }
 
                 self.handle_report(reports);
@@ -88,6 +99,7 @@ impl FileSorterApp {
             Ok(None) => {
                 log_messages.push(LogMessage::Error(
                     "Pipeline didn't reach expected step".to_string(),
+                    None,
                 ));
             }
         }
@@ -101,11 +113,14 @@ impl FileSorterApp {
         for report in reports {
             match &report.result {
                 Err(e) => {
-                    log_messages.push(LogMessage::Warning(format!(
-                        "Error processing file {}: {}",
-                        report.input_filename.display(),
-                        e
-                    )));
+                    log_messages.push(LogMessage::Warning(
+                        format!(
+                            "Error processing file {}: {}",
+                            report.input_filename.display(),
+                            e
+                        ),
+                        e.hint(),
+                    ));
                 }
                 _ => (),
             }
@@ -121,6 +136,11 @@ impl FileSorterApp {
                     .as_ref()
                     .map(|tree_preview| tree_preview.view().map(Message::TreePreviewMessage)),
             )
+            .push_maybe(
+                self.summary_panel
+                    .as_ref()
+                    .map(|summary_panel| summary_panel.view().map(Message::SummaryPanelMessage)),
+            )
             .into()
     }
 
@@ -135,6 +155,11 @@ impl FileSorterApp {
                 .sorter_form
                 .update(sorter_form_message)
                 .map(EventWrapper::SorterFormEvent),
+            Message::SummaryPanelMessage(summary_panel_message) => self
+                .summary_panel
+                .as_mut()
+                .and_then(|summary_panel| summary_panel.update(summary_panel_message))
+                .map(EventWrapper::SummaryPanelEvent),
         };
 
         if let Some(event) = maybe_event {
@@ -145,6 +170,9 @@ impl FileSorterApp {
                 EventWrapper::TreePreviewEvent(tree_preview::Event::Apply) => {
                     self.process_sort_pipeline();
                 }
+                EventWrapper::SummaryPanelEvent(summary_panel::Event::Close) => {
+                    self.summary_panel = None;
+                }
             }
         }
     }
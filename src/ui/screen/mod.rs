@@ -1,2 +1,3 @@
 pub mod sorter_form;
+pub mod summary_panel;
 pub mod tree_preview;
@@ -97,8 +97,8 @@ impl SorterForm {
             .log_messages
             .iter()
             .map(|msg| match msg.clone() {
-                LogMessage::Warning(text) | LogMessage::Error(text) => {
-                    alert::alert(msg.into(), text.clone()).into()
+                LogMessage::Warning(text, hint) | LogMessage::Error(text, hint) => {
+                    alert::alert_with_detail(msg.into(), text, hint).into()
                 }
             })
             .collect();
@@ -192,10 +192,10 @@ impl SorterForm {
                             if let Err(err) =
                                 crate::ui::template::manager::TemplateManager::save(template)
                             {
-                                self.log_messages.push(LogMessage::Error(format!(
-                                    "Failed to save template: {}",
-                                    err
-                                )));
+                                self.log_messages.push(LogMessage::Error(
+                                    format!("Failed to save template: {}", err),
+                                    None,
+                                ));
                             }
                         }
                         template_manager::Event::LoadTemplate(template_name) => {
@@ -2,7 +2,7 @@ use iced::Element;
 
 use crate::{
     core::{
-        parameter::{StrategyParameter, StrategyParameterKind},
+        parameter::{format_map, parse_map, StrategyParameter, StrategyParameterKind},
         validation::ParameterDetail,
     },
     sorting_strategies::catalog::StrategyCatalog,
@@ -12,6 +12,8 @@ use crate::{
             editable_tree_item_combo_box::EditableTreeItemComboBox,
             editable_tree_item_number::EditableTreeItemNumber,
             editable_tree_item_text_input::EditableTreeItemTextInput,
+            list_editor::ListEditor,
+            predicate_editor::PredicateEditor,
             shared::{ParameterInput, TreeInputMessage, TreeItemMessage},
         },
         template::strategy_payload::ParameterValue,
@@ -23,20 +25,27 @@ pub enum ChildElement {
     StrategyParameter(EditableTree),
     StringParameter(Box<dyn ParameterInput<String>>),
     NumberParameter(Box<dyn ParameterInput<usize>>),
+    PredicateParameter(PredicateEditor),
+    /// Entries written as `key -> value`, separated by semicolons.
+    MapParameter(Box<dyn ParameterInput<String>>),
+    ListParameter(ListEditor),
 }
 
 impl ChildElement {
     pub fn view<'a>(&'a self, name: &'a String) -> Element<'a, TreeItemMessage> {
+        self.view_input().map(move |child_message| {
+            TreeItemMessage::ParameterChanged(name.to_string(), Box::new(child_message))
+        })
+    }
+
+    pub fn view_input(&self) -> Element<'_, TreeInputMessage> {
         match self {
-            ChildElement::StrategyParameter(element) => {
-                element.view().map(map_message(name.to_string()))
-            }
-            ChildElement::StringParameter(element) => {
-                element.view().map(map_message(name.to_string()))
-            }
-            ChildElement::NumberParameter(element) => {
-                element.view().map(map_message(name.to_string()))
-            }
+            ChildElement::StrategyParameter(element) => element.view().map(Into::into),
+            ChildElement::StringParameter(element) => element.view().map(Into::into),
+            ChildElement::NumberParameter(element) => element.view().map(Into::into),
+            ChildElement::PredicateParameter(element) => element.view().map(Into::into),
+            ChildElement::MapParameter(element) => element.view().map(Into::into),
+            ChildElement::ListParameter(element) => element.view().map(Into::into),
         }
     }
 
@@ -54,8 +63,21 @@ impl ChildElement {
                 ChildElement::NumberParameter(element) => {
                     element.update(tree_text_input_message);
                 }
+                ChildElement::MapParameter(element) => {
+                    element.update(tree_text_input_message);
+                }
                 _ => (),
             },
+            TreeInputMessage::Predicate(predicate_message) => {
+                if let ChildElement::PredicateParameter(element) = self {
+                    element.update(predicate_message);
+                }
+            }
+            TreeInputMessage::List(list_message) => {
+                if let ChildElement::ListParameter(element) = self {
+                    element.update(list_message);
+                }
+            }
         }
     }
 
@@ -88,6 +110,24 @@ impl ChildElement {
                     default_value,
                 )))
             }
+            StrategyParameterKind::Predicate => {
+                ChildElement::PredicateParameter(PredicateEditor::default())
+            }
+            StrategyParameterKind::Size => ChildElement::StringParameter(Box::new(
+                EditableTreeItemTextInput::new("Insert sizes such as 10MB,1GB".to_string()),
+            )),
+            StrategyParameterKind::Map => {
+                ChildElement::MapParameter(Box::new(EditableTreeItemTextInput::new(
+                    "Insert entries such as jpg|png -> Photos".to_string(),
+                )))
+            }
+            StrategyParameterKind::List => {
+                let default_value = match validator.default_value {
+                    Some(StrategyParameter::List(values)) => values,
+                    _ => vec![],
+                };
+                ChildElement::ListParameter(ListEditor::from(default_value))
+            }
         }
     }
 
@@ -102,16 +142,19 @@ impl ChildElement {
             ChildElement::NumberParameter(screen) => {
                 screen.get_value().map(StrategyParameter::Number)
             }
+            ChildElement::PredicateParameter(screen) => {
+                Some(StrategyParameter::Predicate(screen.get_predicates()))
+            }
+            ChildElement::MapParameter(screen) => screen
+                .get_value()
+                .map(|entries| StrategyParameter::Map(parse_map(&entries))),
+            ChildElement::ListParameter(screen) => {
+                Some(StrategyParameter::List(screen.get_values()))
+            }
         }
     }
 }
 
-fn map_message<T: Into<TreeInputMessage>>(name: String) -> impl Fn(T) -> TreeItemMessage {
-    move |child_message| {
-        TreeItemMessage::ParameterChanged(name.clone(), Box::new(child_message.into()))
-    }
-}
-
 impl From<ParameterValue> for ChildElement {
     fn from(value: ParameterValue) -> Self {
         match value {
@@ -126,7 +169,31 @@ impl From<ParameterValue> for ChildElement {
             ParameterValue::Integer(num) => ChildElement::NumberParameter(Box::new(
                 EditableTreeItemNumber::new("Insert a number here".to_string(), Some(num)),
             )),
+            ParameterValue::Predicates(payloads) => {
+                ChildElement::PredicateParameter(PredicateEditor::from(payloads))
+            }
+            ParameterValue::Array(arr)
+                if matches!(arr.first(), Some(ParameterValue::String(_))) =>
+            {
+                let values = arr
+                    .into_iter()
+                    .filter_map(|value| match value {
+                        ParameterValue::String(value) => Some(value),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                ChildElement::ListParameter(ListEditor::from(values))
+            }
             ParameterValue::Array(arr) => ChildElement::StrategyParameter(EditableTree::from(arr)),
+            ParameterValue::Map(entries) => {
+                let mut el = EditableTreeItemTextInput::new(
+                    "Insert entries such as jpg|png -> Photos".to_string(),
+                );
+                el.update(super::shared::TreeTextInputMessage::ValueUpdate(
+                    format_map(&entries),
+                ));
+                ChildElement::MapParameter(Box::new(el))
+            }
             _ => {
                 panic!("Unsupported ParameterValue type for ChildElement conversion");
             }
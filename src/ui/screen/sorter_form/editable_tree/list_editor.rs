@@ -0,0 +1,100 @@
+use iced::{
+    widget::{column, row, text_input},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    ui::{
+        custom_theme,
+        widget::{
+            button::{icon_button::icon_button, primary_button::primary_button},
+            icon,
+        },
+    },
+    utils::string_manipulator::random_string,
+};
+
+use super::shared::ListMessage;
+
+/// Edits the texts of a list parameter, such as the operations of the `transform` strategy.
+#[derive(Debug, Clone, Default)]
+pub struct ListEditor {
+    items: Vec<ListItem>,
+}
+
+#[derive(Debug, Clone)]
+struct ListItem {
+    id: String,
+    value: String,
+}
+
+impl ListItem {
+    fn new(value: String) -> Self {
+        ListItem {
+            id: random_string(10),
+            value,
+        }
+    }
+}
+
+impl ListEditor {
+    pub fn view(&self) -> Element<'_, ListMessage> {
+        let items = self.items.iter().map(|item| {
+            let id = item.id.clone();
+            let input: Element<'_, ListMessage> = text_input("Insert a value here", &item.value)
+                .on_input(move |value| ListMessage::ItemChanged(id.clone(), value))
+                .style(custom_theme::TextInput::style)
+                .into();
+            let delete_btn: Element<'_, ListMessage> = icon_button(icon::DELETE)
+                .on_press(ListMessage::ItemRemoved(item.id.clone()))
+                .into();
+
+            row![input, delete_btn]
+                .align_y(Alignment::Center)
+                .spacing(24)
+                .width(Length::Fill)
+                .into()
+        });
+
+        let add_btn: Element<'_, ListMessage> = primary_button("Add Item")
+            .on_press(ListMessage::AddEmptyItem)
+            .width(Length::Fill)
+            .into();
+
+        column(items)
+            .push(add_btn)
+            .spacing(10)
+            .width(Length::Fill)
+            .height(Length::Shrink)
+            .into()
+    }
+
+    pub fn update(&mut self, message: ListMessage) {
+        match message {
+            ListMessage::AddEmptyItem => self.items.push(ListItem::new(String::new())),
+            ListMessage::ItemRemoved(id) => self.items.retain(|item| item.id != id),
+            ListMessage::ItemChanged(id, value) => {
+                if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+                    item.value = value;
+                }
+            }
+        }
+    }
+
+    /// Items of the list, without the empty ones.
+    pub fn get_values(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .map(|item| item.value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+}
+
+impl From<Vec<String>> for ListEditor {
+    fn from(values: Vec<String>) -> Self {
+        ListEditor {
+            items: values.into_iter().map(ListItem::new).collect(),
+        }
+    }
+}
@@ -4,4 +4,6 @@ pub mod editable_tree_item;
 pub mod editable_tree_item_combo_box;
 pub mod editable_tree_item_number;
 pub mod editable_tree_item_text_input;
+pub mod list_editor;
+pub mod predicate_editor;
 pub mod shared;
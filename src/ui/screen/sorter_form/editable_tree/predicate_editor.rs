@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use iced::{
+    widget::{column, combo_box, container, row, text, Column, ComboBox},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    core::predicate::Predicate,
+    sorting_strategies::{
+        catalog::all_catalog,
+        predicate::{all_predicates, PredicateCatalog},
+    },
+    ui::{
+        custom_theme,
+        template::strategy_payload::PredicatePayload,
+        widget::{
+            button::{icon_button::icon_button, primary_button::primary_button},
+            icon,
+        },
+    },
+    utils::string_manipulator::random_string,
+};
+
+use super::{child_element::ChildElement, shared::PredicateMessage};
+
+/// Edits the list of predicates given to a predicate parameter. A file has to match all of them.
+#[derive(Debug, Clone)]
+pub struct PredicateEditor {
+    items: Vec<PredicateItem>,
+    predicate_catalog: PredicateCatalog,
+}
+
+#[derive(Debug, Clone)]
+struct PredicateItem {
+    id: String,
+    selected_predicate: Option<String>,
+    predicate_options: combo_box::State<String>,
+    child_elements: HashMap<String, ChildElement>,
+}
+
+impl Default for PredicateEditor {
+    fn default() -> Self {
+        PredicateEditor {
+            items: vec![],
+            predicate_catalog: all_predicates(),
+        }
+    }
+}
+
+impl PredicateEditor {
+    pub fn view(&self) -> Element<'_, PredicateMessage> {
+        let items = self.items.iter().map(|item| item.view());
+
+        let add_btn: Element<'_, PredicateMessage> = primary_button("Add Condition")
+            .on_press(PredicateMessage::AddEmptyItem)
+            .width(Length::Fill)
+            .into();
+
+        column(items)
+            .push(add_btn)
+            .spacing(10)
+            .width(Length::Fill)
+            .height(Length::Shrink)
+            .into()
+    }
+
+    pub fn update(&mut self, message: PredicateMessage) {
+        match message {
+            PredicateMessage::AddEmptyItem => {
+                self.items.push(PredicateItem::new(&self.predicate_catalog))
+            }
+            PredicateMessage::ItemRemoved(id) => self.items.retain(|item| item.id != id),
+            PredicateMessage::PredicateChanged(id, name) => {
+                if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+                    item.select(&self.predicate_catalog, name);
+                }
+            }
+            PredicateMessage::ParameterChanged(id, parameter_name, parameter_message) => {
+                if let Some(child_element) = self
+                    .items
+                    .iter_mut()
+                    .find(|item| item.id == id)
+                    .and_then(|item| item.child_elements.get_mut(&parameter_name))
+                {
+                    child_element.update(*parameter_message);
+                }
+            }
+        }
+    }
+
+    pub fn get_predicates(&self) -> Vec<Box<dyn Predicate>> {
+        self.items
+            .iter()
+            .filter_map(|item| item.get_predicate(&self.predicate_catalog))
+            .collect()
+    }
+}
+
+impl PredicateItem {
+    fn new(predicate_catalog: &PredicateCatalog) -> Self {
+        PredicateItem {
+            id: random_string(10),
+            selected_predicate: None,
+            predicate_options: combo_box::State::new(predicate_catalog.get_names()),
+            child_elements: HashMap::new(),
+        }
+    }
+
+    fn select(&mut self, predicate_catalog: &PredicateCatalog, name: String) {
+        self.child_elements.clear();
+
+        if let Some(predicate) = predicate_catalog.get_predicate(&name) {
+            for validator in predicate.parameter_details() {
+                self.child_elements.insert(
+                    validator.name.clone(),
+                    ChildElement::create(validator, all_catalog()),
+                );
+            }
+        }
+
+        self.selected_predicate = Some(name);
+    }
+
+    fn view(&self) -> Element<'_, PredicateMessage> {
+        let id = self.id.clone();
+        let predicate_input: Element<'_, PredicateMessage> = ComboBox::new(
+            &self.predicate_options,
+            "Select a condition",
+            self.selected_predicate.as_ref(),
+            move |name| PredicateMessage::PredicateChanged(id.clone(), name),
+        )
+        .input_style(custom_theme::TextInput::style)
+        .into();
+
+        let delete_btn: Element<'_, PredicateMessage> = icon_button(icon::DELETE)
+            .on_press(PredicateMessage::ItemRemoved(self.id.clone()))
+            .into();
+
+        let header = row![predicate_input, delete_btn]
+            .align_y(Alignment::Center)
+            .spacing(24)
+            .width(Length::Fill);
+
+        let mut body = Column::new().spacing(4);
+        for (name, child_element) in &self.child_elements {
+            let id = self.id.clone();
+            let input = child_element.view_input().map(move |child_message| {
+                PredicateMessage::ParameterChanged(
+                    id.clone(),
+                    name.clone(),
+                    Box::new(child_message),
+                )
+            });
+            body = body.push(text(name)).push(input);
+        }
+
+        container(column![header, body].spacing(8))
+            .width(Length::Fill)
+            .padding(16)
+            .style(|_| container::Style {
+                border: custom_theme::border_style(),
+                ..Default::default()
+            })
+            .into()
+    }
+
+    fn get_predicate(&self, predicate_catalog: &PredicateCatalog) -> Option<Box<dyn Predicate>> {
+        let name = self.selected_predicate.as_ref()?;
+        let mut predicate = predicate_catalog.get_predicate(name)?;
+
+        for (key, child_element) in &self.child_elements {
+            if let Some(value) = child_element.strategy_parameter() {
+                predicate.add_parameter(key.clone(), value);
+            }
+        }
+
+        Some(predicate)
+    }
+}
+
+impl From<Vec<PredicatePayload>> for PredicateEditor {
+    fn from(payloads: Vec<PredicatePayload>) -> Self {
+        let predicate_catalog = all_predicates();
+        let mut items = vec![];
+        for payload in payloads {
+            let mut item = PredicateItem::new(&predicate_catalog);
+            item.select(&predicate_catalog, payload.predicate_name);
+            for param in payload.parameters {
+                item.child_elements
+                    .insert(param.name, ChildElement::from(param.value));
+            }
+            items.push(item);
+        }
+
+        PredicateEditor {
+            items,
+            predicate_catalog,
+        }
+    }
+}
@@ -18,10 +18,27 @@ pub enum TreeTextInputMessage {
     ValueUpdate(String),
 }
 
+#[derive(Debug, Clone)]
+pub enum PredicateMessage {
+    AddEmptyItem,
+    ItemRemoved(String),
+    PredicateChanged(String, String),
+    ParameterChanged(String, String, Box<TreeInputMessage>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ListMessage {
+    AddEmptyItem,
+    ItemRemoved(String),
+    ItemChanged(String, String),
+}
+
 #[derive(Debug, Clone)]
 pub enum TreeInputMessage {
     EditableTree(TreeMessage),
     TextInput(TreeTextInputMessage),
+    Predicate(PredicateMessage),
+    List(ListMessage),
 }
 
 impl Into<TreeInputMessage> for TreeMessage {
@@ -36,6 +53,18 @@ impl Into<TreeInputMessage> for TreeTextInputMessage {
     }
 }
 
+impl From<PredicateMessage> for TreeInputMessage {
+    fn from(message: PredicateMessage) -> Self {
+        TreeInputMessage::Predicate(message)
+    }
+}
+
+impl From<ListMessage> for TreeInputMessage {
+    fn from(message: ListMessage) -> Self {
+        TreeInputMessage::List(message)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum TreeItemMessage {
     DirectoryRemoved,
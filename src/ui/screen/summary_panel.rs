@@ -0,0 +1,116 @@
+use iced::{
+    widget::{column, container, row, text, Column},
+    Element, Length,
+};
+
+use crate::{
+    core::summary::{format_millis, Summary},
+    ui::{custom_theme, widget::icon},
+    utils::string_manipulator::human_readable_size,
+};
+
+pub struct SummaryPanel {
+    summary: Summary,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Close,
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Close,
+}
+
+impl SummaryPanel {
+    pub fn new(summary: Summary) -> Self {
+        SummaryPanel { summary }
+    }
+
+    pub fn view(&self) -> iced::Element<'_, Message> {
+        let statuses = self
+            .summary
+            .statuses
+            .iter()
+            .map(|(status, count)| table_row(status.clone(), count.to_string()));
+        let folders = self.summary.folders.iter().map(|folder| {
+            table_row(
+                folder.name.clone(),
+                format!(
+                    "{} files, {}",
+                    folder.files,
+                    human_readable_size(folder.bytes)
+                ),
+            )
+        });
+        let failures = self
+            .summary
+            .failures
+            .iter()
+            .map(|(kind, count)| table_row(kind.clone(), count.to_string()));
+        let rules = self
+            .summary
+            .rules
+            .iter()
+            .map(|(rule, count)| table_row(rule.clone(), count.to_string()));
+        let stages = self
+            .summary
+            .stages
+            .iter()
+            .map(|stage| table_row(stage.name.clone(), format_millis(&stage.elapsed)));
+        let strategies = self
+            .summary
+            .slowest_strategies
+            .iter()
+            .map(|strategy| table_row(strategy.name.clone(), format_millis(&strategy.elapsed)));
+
+        container(
+            column![
+                row![
+                    text(format!("Summary ({} files)", self.summary.total_files()))
+                        .font(custom_theme::TextFont::bold())
+                        .size(24.0)
+                        .width(Length::Fill),
+                    crate::ui::widget::button::icon_button::icon_button(icon::DELETE)
+                        .on_press(Message::Close)
+                ]
+                .align_y(iced::Alignment::Center),
+                section("Status", statuses.collect()),
+                section("Folders", folders.collect()),
+                section("Failures", failures.collect()),
+                section("Rules", rules.collect()),
+                section("Stages", stages.collect()),
+                section("Slowest strategies", strategies.collect()),
+            ]
+            .spacing(16),
+        )
+        .padding(16)
+        .width(Length::Fill)
+        .into()
+    }
+
+    pub fn update(&mut self, message: Message) -> Option<Event> {
+        match message {
+            Message::Close => Some(Event::Close),
+        }
+    }
+}
+
+fn section<'a>(title: &'a str, rows: Vec<Element<'a, Message>>) -> Element<'a, Message> {
+    let content: Element<'a, Message> = if rows.is_empty() {
+        text("-").into()
+    } else {
+        Column::from_vec(rows).spacing(2).into()
+    };
+
+    column![text(title).font(custom_theme::TextFont::bold()), content]
+        .spacing(4)
+        .into()
+}
+
+fn table_row<'a>(label: String, value: String) -> Element<'a, Message> {
+    row![text(label).width(Length::Fill), text(value)]
+        .spacing(8)
+        .into()
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{core::parameter::StrategyParameter, sorting_strategies::catalog::all_catalog};
+use crate::{
+    core::parameter::StrategyParameter,
+    sorting_strategies::{catalog::all_catalog, predicate::all_predicates},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategyPayload {
@@ -8,6 +11,12 @@ pub struct StrategyPayload {
     pub parameters: Vec<Parameter>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PredicatePayload {
+    pub predicate_name: String,
+    pub parameters: Vec<Parameter>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ParameterValue {
     String(String),
@@ -16,6 +25,9 @@ pub enum ParameterValue {
     Boolean(bool),
     Strategy(StrategyPayload),
     Array(Vec<ParameterValue>),
+    /// Kept apart from `Array` so that an empty condition is still read back as predicates.
+    Predicates(Vec<PredicatePayload>),
+    Map(Vec<(String, String)>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,6 +59,26 @@ impl From<StrategyParameter> for ParameterValue {
             ),
             StrategyParameter::SingleString(value) => ParameterValue::String(value),
             StrategyParameter::Number(value) => ParameterValue::Integer(value),
+            StrategyParameter::Map(entries) => ParameterValue::Map(entries),
+            StrategyParameter::List(values) => {
+                ParameterValue::Array(values.into_iter().map(ParameterValue::String).collect())
+            }
+            StrategyParameter::Predicate(value) => ParameterValue::Predicates(
+                value
+                    .iter()
+                    .map(|p| PredicatePayload {
+                        predicate_name: p.name(),
+                        parameters: p
+                            .parameters()
+                            .iter()
+                            .map(|(k, v)| Parameter {
+                                name: k.clone(),
+                                value: v.clone().into(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            ),
         }
     }
 }
@@ -56,6 +88,34 @@ impl Into<StrategyParameter> for ParameterValue {
         match self {
             ParameterValue::String(value) => StrategyParameter::SingleString(value),
             ParameterValue::Integer(value) => StrategyParameter::Number(value),
+            ParameterValue::Map(entries) => StrategyParameter::Map(entries),
+            ParameterValue::Array(values)
+                if matches!(values.first(), Some(ParameterValue::String(_))) =>
+            {
+                StrategyParameter::List(
+                    values
+                        .into_iter()
+                        .filter_map(|value| match value {
+                            ParameterValue::String(value) => Some(value),
+                            _ => None,
+                        })
+                        .collect(),
+                )
+            }
+            ParameterValue::Predicates(payloads) => {
+                let mut predicates = vec![];
+
+                for p in payloads {
+                    if let Some(mut predicate) = all_predicates().get_predicate(&p.predicate_name) {
+                        for parameter in p.parameters {
+                            predicate.add_parameter(parameter.name, parameter.value.into());
+                        }
+                        predicates.push(predicate);
+                    }
+                }
+
+                StrategyParameter::Predicate(predicates)
+            }
             ParameterValue::Array(values) => {
                 let mut strategies = vec![];
 
@@ -75,3 +135,21 @@ impl Into<StrategyParameter> for ParameterValue {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_back_an_empty_condition_as_predicates() {
+        let value = ParameterValue::from(StrategyParameter::Predicate(vec![]));
+        let json = serde_json::to_string(&value).unwrap();
+        let parameter: StrategyParameter = serde_json::from_str::<ParameterValue>(&json)
+            .unwrap()
+            .into();
+
+        assert!(
+            matches!(parameter, StrategyParameter::Predicate(predicates) if predicates.is_empty())
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use iced::{
     advanced::{text, Widget},
-    widget::row,
+    widget::{column, row},
 };
 
 use crate::ui::{
@@ -35,6 +35,11 @@ where
 
 impl<'a, Message> Alert<'a, Message> {
     pub fn new(severity: AlertSeverity, message: String) -> Self {
+        Self::with_detail(severity, message, None)
+    }
+
+    /// Alert with a secondary, smaller line below the message.
+    pub fn with_detail(severity: AlertSeverity, message: String, detail: Option<String>) -> Self {
         let icon = match severity {
             AlertSeverity::Info => icon(icon::INFO),
             AlertSeverity::Warning => icon(icon::WARNING),
@@ -42,9 +47,14 @@ impl<'a, Message> Alert<'a, Message> {
         };
 
         let container = iced::widget::container::Container::new(
-            row![icon, iced::widget::text(message)]
-                .spacing(10)
-                .padding(10),
+            row![
+                icon,
+                column![iced::widget::text(message)]
+                    .push_maybe(detail.map(|detail| iced::widget::text(detail).size(12)))
+                    .spacing(2)
+            ]
+            .spacing(10)
+            .padding(10),
         )
         .style(move |_| {
             let mut style = iced::widget::container::Style::default();
@@ -170,3 +180,11 @@ where
 pub fn alert<'a, Message: Clone>(severity: AlertSeverity, message: String) -> Alert<'a, Message> {
     Alert::<Message>::new(severity, message)
 }
+
+pub fn alert_with_detail<'a, Message: Clone>(
+    severity: AlertSeverity,
+    message: String,
+    detail: Option<String>,
+) -> Alert<'a, Message> {
+    Alert::<Message>::with_detail(severity, message, detail)
+}
@@ -42,3 +42,21 @@ pub fn elipsis(content: String, max_char: usize, direction: ElispsisDirection) -
         content
     }
 }
+
+/// Format a byte count using binary units, e.g. `1536` gives `1.5 KiB`.
+pub fn human_readable_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
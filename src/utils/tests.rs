@@ -242,4 +242,19 @@ mod string_manipulator_tests {
             assert_eq!(value.len(), 0);
         }
     }
+
+    mod human_readable_size {
+        use crate::utils::string_manipulator::human_readable_size;
+
+        #[test]
+        fn test_bytes_are_displayed_without_decimals() {
+            assert_eq!(human_readable_size(512), "512 B");
+        }
+
+        #[test]
+        fn test_larger_sizes_use_binary_units() {
+            assert_eq!(human_readable_size(1536), "1.5 KiB");
+            assert_eq!(human_readable_size(3 * 1024 * 1024 * 1024), "3.0 GiB");
+        }
+    }
 }
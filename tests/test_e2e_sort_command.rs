@@ -42,7 +42,8 @@ pub mod tests_e2e_sort_command {
                 final_target_dir.clone()
             ),
             Some(true),
-        );
+        )
+        .expect("the sort command should succeed");
 
         let content_of_target_dir = fs::read_dir(target_dir.clone()).unwrap();
         assert_eq!(content_of_target_dir.count(), 2);
@@ -112,7 +113,8 @@ pub mod tests_e2e_sort_command {
                 final_output_dir.clone()
             ),
             Some(true),
-        );
+        )
+        .expect("the sort command should succeed");
 
         let content_of_target_dir = fs::read_dir(output_dir.clone()).unwrap();
         assert_eq!(content_of_target_dir.count(), 2);
@@ -183,7 +185,8 @@ pub mod tests_e2e_sort_command {
                 final_output_dir.clone()
             ),
             Some(true),
-        );
+        )
+        .expect("the sort command should succeed");
 
         let content_of_target_dir = fs::read_dir(output_dir.clone()).unwrap();
         assert_eq!(content_of_target_dir.count(), 1);
@@ -224,7 +227,6 @@ pub mod tests_e2e_sort_command {
     }
 
     #[test]
-    #[should_panic = "[ERROR] [Sort Command] input directory 'tests/rsc/sort/test_unknown/input_dir' don't exists"]
     fn test_input_directory_dont_exists() {
         // define input & output directory
         let common_dir = &Path::new("tests")
@@ -236,7 +238,7 @@ pub mod tests_e2e_sort_command {
 
         let final_input_dir = input_dir.clone().to_str().unwrap().to_string();
         let final_output_dir = output_dir.clone().to_str().unwrap().to_string();
-        handle(
+        let result = handle(
             format!(
                 "sort --stack year --stack month {} {}",
                 final_input_dir.clone(),
@@ -245,11 +247,15 @@ pub mod tests_e2e_sort_command {
             Some(true),
         );
 
-        teardown(common_dir);
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input directory 'tests/rsc/sort/test_unknown/input_dir' don't exists"
+        );
+        assert_eq!(err.exit_code(), 3);
     }
 
     #[test]
-    #[should_panic = "[ERROR] [Sort Command] output directory 'tests/rsc/sort/test_output_directory_dont_exists/output_dir' don't exists"]
     fn test_output_directory_dont_exists() {
         // set filenames
         let files = vec![
@@ -275,7 +281,7 @@ pub mod tests_e2e_sort_command {
 
         let final_input_dir = input_dir.clone().to_str().unwrap().to_string();
         let final_output_dir = output_dir.clone().to_str().unwrap().to_string();
-        handle(
+        let result = handle(
             format!(
                 "sort --stack year --stack month {} {}",
                 final_input_dir.clone(),
@@ -285,10 +291,16 @@ pub mod tests_e2e_sort_command {
         );
 
         teardown(common_dir);
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "output directory 'tests/rsc/sort/test_output_directory_dont_exists/output_dir' don't exists"
+        );
+        assert_eq!(err.exit_code(), 3);
     }
 
     #[test]
-    #[should_panic = "[ERROR] [Sort Command] 'tests/rsc/sort/test_input_isnt_a_directory/input_dir/file' isn't a directory"]
     fn test_input_isnt_a_directory() {
         // define input & output directory
         let common_dir = &Path::new("tests")
@@ -315,7 +327,7 @@ pub mod tests_e2e_sort_command {
         let final_input_dir = input_dir.clone().join("file").to_str().unwrap().to_string();
         let final_output_dir = output_dir.clone().to_str().unwrap().to_string();
 
-        handle(
+        let result = handle(
             format!(
                 "sort --stack year --stack month {} {}",
                 final_input_dir.clone(),
@@ -325,10 +337,16 @@ pub mod tests_e2e_sort_command {
         );
 
         teardown(common_dir);
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'tests/rsc/sort/test_input_isnt_a_directory/input_dir/file' isn't a directory"
+        );
+        assert_eq!(err.exit_code(), 3);
     }
 
     #[test]
-    #[should_panic = "[ERROR] [Sort Command] 'tests/rsc/sort/test_output_isnt_a_directory/output_dir/file' isn't a directory"]
     fn test_output_isnt_a_directory() {
         // set filenames
         let files = vec![
@@ -369,7 +387,7 @@ pub mod tests_e2e_sort_command {
             .to_str()
             .unwrap()
             .to_string();
-        handle(
+        let result = handle(
             format!(
                 "sort --stack year --stack month {} {}",
                 final_input_dir.clone(),
@@ -379,6 +397,13 @@ pub mod tests_e2e_sort_command {
         );
 
         teardown(common_dir);
+
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'tests/rsc/sort/test_output_isnt_a_directory/output_dir/file' isn't a directory"
+        );
+        assert_eq!(err.exit_code(), 3);
     }
 
     #[test]
@@ -414,7 +439,8 @@ pub mod tests_e2e_sort_command {
                 final_output_dir.clone()
             ),
             Some(false),
-        );
+        )
+        .expect("the sort command should succeed");
 
         assert_eq!(read_dir(input_dir).unwrap().count(), 3);
         assert_eq!(read_dir(output_dir).unwrap().count(), 0);
@@ -458,7 +484,8 @@ pub mod tests_e2e_sort_command {
                 final_output_dir.clone()
             ),
             Some(true),
-        );
+        )
+        .expect("the sort command should succeed");
 
         let content_of_target_dir = fs::read_dir(output_dir.clone()).unwrap();
         assert_eq!(content_of_target_dir.count(), 1);
@@ -521,7 +548,8 @@ pub mod tests_e2e_sort_command {
                 final_output_dir.clone()
             ),
             Some(true),
-        );
+        )
+        .expect("the sort command should succeed");
 
         assert!(!file_or_dir_exists(other_dir));
 
@@ -560,6 +588,167 @@ pub mod tests_e2e_sort_command {
         teardown(common_dir);
     }
 
+    #[test]
+    fn test_sort_from_config_file() {
+        let files = vec![
+            FileCreator::from("file_2022-02-22_F1BDD782"),
+            FileCreator::from("file_2023-10-20_9E387272"),
+            FileCreator::from("file_2023-10-21_6FC02130.tmp"),
+        ];
+
+        let base_dir = get_base_test_path();
+        let input_dir = base_dir.join("input_dir");
+        let output_dir = base_dir.join("output_dir");
+        clean_or_create_dir(input_dir.clone())
+            .expect("Should be able to clean or create directory before running test");
+        clean_or_create_dir(output_dir.clone())
+            .expect("Should be able to clean or create directory before running test");
+        generate_test_files(&input_dir, files).expect("Unable to generate the test files!");
+
+        let config_path = base_dir.join("rules.toml");
+        fs::write(
+            &config_path,
+            r#"
+input = "input_dir"
+output = "output_dir"
+
+[filters]
+exclude_extensions = ["tmp"]
+
+[[strategies]]
+name = "concat"
+parameters = { strategies = [{ name = "year" }, { name = "text", parameters = { value = "-" } }, { name = "month" }] }
+"#,
+        )
+        .expect("Unable to write the configuration file!");
+
+        handle(
+            format!("sort --config {}", config_path.to_str().unwrap()),
+            Some(true),
+        )
+        .expect("the sort command should succeed");
+
+        assert!(file_or_dir_exists(
+            output_dir
+                .join("2022-02_February")
+                .join("file_2022-02-22_F1BDD782")
+        ));
+        assert!(file_or_dir_exists(
+            output_dir
+                .join("2023-10_October")
+                .join("file_2023-10-20_9E387272")
+        ));
+        assert!(file_or_dir_exists(
+            input_dir.join("file_2023-10-21_6FC02130.tmp")
+        ));
+
+        teardown(&base_dir);
+    }
+
+    #[test]
+    fn test_sort_keeping_relative_path() {
+        let base_dir = get_base_test_path();
+        let input_dir = base_dir.join("input_dir");
+        let nested_dir = input_dir.join("projects").join("website");
+        let output_dir = base_dir.join("output_dir");
+        clean_or_create_dir(nested_dir.clone())
+            .expect("Should be able to clean or create directory before running test");
+        clean_or_create_dir(output_dir.clone())
+            .expect("Should be able to clean or create directory before running test");
+        generate_test_files(
+            &input_dir,
+            vec![FileCreator::from("file_2022-02-22_F1BDD782")],
+        )
+        .expect("Unable to generate the test files!");
+        generate_test_files(
+            &nested_dir,
+            vec![FileCreator::from("file_2023-10-20_9E387272")],
+        )
+        .expect("Unable to generate the test files!");
+
+        let config_path = base_dir.join("rules.toml");
+        fs::write(
+            &config_path,
+            r#"
+input = "input_dir"
+output = "output_dir"
+
+[[strategies]]
+name = "year"
+
+[[strategies]]
+name = "relative path"
+"#,
+        )
+        .expect("Unable to write the configuration file!");
+
+        handle(
+            format!("sort --config {}", config_path.to_str().unwrap()),
+            Some(true),
+        )
+        .expect("the sort command should succeed");
+
+        assert!(file_or_dir_exists(
+            output_dir.join("2022").join("file_2022-02-22_F1BDD782")
+        ));
+        assert!(file_or_dir_exists(
+            output_dir
+                .join("2023")
+                .join("projects")
+                .join("website")
+                .join("file_2023-10-20_9E387272")
+        ));
+
+        teardown(&base_dir);
+    }
+
+    #[test]
+    fn test_sort_into_batches() {
+        let base_dir = get_base_test_path();
+        let input_dir = base_dir.join("input_dir");
+        let output_dir = base_dir.join("output_dir");
+        clean_or_create_dir(input_dir.clone())
+            .expect("Should be able to clean or create directory before running test");
+        clean_or_create_dir(output_dir.join("0001"))
+            .expect("Should be able to clean or create directory before running test");
+        generate_test_files(
+            &output_dir.join("0001"),
+            vec![FileCreator::from("file_2021-01-01_00000000")],
+        )
+        .expect("Unable to generate the test files!");
+        generate_test_files(
+            &input_dir,
+            vec![
+                FileCreator::from("file_2022-02-22_F1BDD782"),
+                FileCreator::from("file_2022-10-20_6FC02130"),
+                FileCreator::from("file_2023-10-20_9E387272"),
+            ],
+        )
+        .expect("Unable to generate the test files!");
+
+        handle(
+            format!(
+                "sort --stack batch --parameter batch_size=2 {} {}",
+                input_dir.to_str().unwrap(),
+                output_dir.to_str().unwrap()
+            ),
+            Some(true),
+        )
+        .expect("the sort command should succeed");
+
+        assert!(file_or_dir_exists(
+            output_dir.join("0001").join("file_2022-02-22_F1BDD782")
+        ));
+        assert!(file_or_dir_exists(
+            output_dir.join("0002").join("file_2022-10-20_6FC02130")
+        ));
+        assert!(file_or_dir_exists(
+            output_dir.join("0002").join("file_2023-10-20_9E387272")
+        ));
+
+        teardown(&base_dir);
+    }
+
     fn teardown(target_dir: &std::path::PathBuf) {
         fs::remove_dir_all(target_dir).expect("Unable to teardown");
     }